@@ -26,6 +26,27 @@ C_API_HEADER_NAMES=(
   "utext"
   "utrans"
   "unorm2"
+  "uset"
+  "ucptrie"
+  "uregex"
+  "ubidi"
+  "uidna"
+  "uspoof"
+  "usearch"
+  "ucasemap"
+  "uformattedvalue"
+  "ureldatefmt"
+  "uldnames"
+  "unumberrangeformatter"
+  "udateintervalformat"
+  "ucurr"
+  "ulocdata"
+  "uscript"
+  "unumsys"
+  "ugender"
+  "ushape"
+  "ucnvsel"
+  "usprep"
 )
 
 # Locate the ICU headers.  icu-config is tried first, since a hand-built ICU
@@ -120,6 +120,24 @@ impl Default for Options {
     }
 }
 
+/// The options that a [Collator] actually uses, as reported by [Collator::resolved_options].
+///
+/// The locale may select the numeric and case first options through its `-u-kn` and `-u-kf`
+/// keywords, so these need not be the values given at construction.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale whose collation rules are used.
+    pub locale: String,
+    pub usage: options::Usage,
+    pub sensitivity: options::Sensitivity,
+    pub punctuation: options::Punctuation,
+    /// The collation type, such as "phonebk", or "default" for the standard collation of the
+    /// locale.
+    pub collation: String,
+    pub numeric: options::Numeric,
+    pub case_first: options::CaseFirst,
+}
+
 pub trait Collator {
     /// The type of error reported, if any.
     type Error: std::error::Error;
@@ -133,12 +151,46 @@ pub trait Collator {
         L: crate::Locale,
         Self: Sized;
 
-    /// Compares two strings according to the sort order of this [Collator].
+    /// Creates a new [Collator] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [Collator] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.Collator.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Compares two strings according to the sort order of this [Collator], and the
+    /// options that it was created with.
     ///
-    /// Returns 0 if `first` and `second` are equal.  Returns a negative value
-    /// if `first` is less, and a positive value of `second` is less.
-    fn compare<P, Q>(first: P, second: Q) -> i8
+    /// Returns [std::cmp::Ordering::Equal] if `first` and `second` are equal,
+    /// [std::cmp::Ordering::Less] if `first` is less, and [std::cmp::Ordering::Greater]
+    /// if `second` is less.
+    fn compare<P, Q>(&self, first: P, second: Q) -> std::cmp::Ordering
     where
         P: AsRef<str>,
         Q: AsRef<str>;
+
+    /// Returns the locale and the options that this [Collator] resolved to.
+    ///
+    /// The function implements [`Intl.Collator.prototype.resolvedOptions`][ro] from [ECMA
+    /// 402][ecma].
+    ///
+    ///    [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/resolvedOptions
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
 }
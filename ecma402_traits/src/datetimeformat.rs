@@ -265,6 +265,36 @@ impl Default for DateTimeFormatOptions {
     }
 }
 
+/// The options that a [DateTimeFormat] actually uses, as reported by
+/// [DateTimeFormat::resolved_options].
+///
+/// The calendar, numbering system and time zone are always known, since the locale supplies them
+/// if the options did not.  The date and time components are those that the chosen format
+/// displays, which need not be exactly the requested ones.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale whose data is used, such as "sr-Latn".
+    pub locale: String,
+    pub calendar: options::Calendar,
+    pub numbering_system: options::NumberingSystem,
+    /// The time zone, as an IANA time zone ID such as "America/Los_Angeles".
+    pub time_zone: options::TimeZone,
+    /// The hour cycle, if the format displays the hour.
+    pub hour_cycle: Option<options::HourCycle>,
+    pub date_style: Option<options::Style>,
+    pub time_style: Option<options::Style>,
+    pub fractional_second_digits: Option<num::NonZeroU8>,
+    pub weekday: Option<options::Weekday>,
+    pub era: Option<options::Era>,
+    pub year: Option<options::DisplaySize>,
+    pub month: Option<options::Month>,
+    pub day: Option<options::DisplaySize>,
+    pub hour: Option<options::DisplaySize>,
+    pub minute: Option<options::DisplaySize>,
+    pub second: Option<options::DisplaySize>,
+    pub time_zone_style: Option<options::TimeZoneStyle>,
+}
+
 use std::fmt;
 
 pub trait DateTimeFormat {
@@ -280,6 +310,29 @@ pub trait DateTimeFormat {
         L: crate::Locale,
         Self: Sized;
 
+    /// Creates a new [DateTimeFormat] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: DateTimeFormatOptions,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [DateTimeFormat] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.DateTimeFormat.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
     /// Formats `date` into the supplied standard `writer` [fmt::Write].
     ///
     /// The original [ECMA 402 function][ecma402fmt] returns a string.  This is likely the only
@@ -295,4 +348,27 @@ pub trait DateTimeFormat {
     fn format<W>(&self, date: f64, writer: &mut W) -> fmt::Result
     where
         W: fmt::Write;
+
+    /// Formats `date` into a sequence of typed parts, such as [PartType::Month] and
+    /// [PartType::Literal], which concatenate to the result of [DateTimeFormat::format].
+    ///
+    /// The function implements [`Intl.DateTimeFormat.prototype.formatToParts`][ftp] from
+    /// [ECMA 402][ecma].
+    ///
+    ///    [ftp]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    ///
+    ///    [PartType::Month]: crate::parts::PartType::Month
+    ///    [PartType::Literal]: crate::parts::PartType::Literal
+    fn format_to_parts(&self, date: f64) -> Result<Vec<crate::parts::Part>, Self::Error>;
+
+    /// Returns the locale, calendar, time zone and other options that this [DateTimeFormat]
+    /// resolved to.
+    ///
+    /// The function implements [`Intl.DateTimeFormat.prototype.resolvedOptions`][ro] from
+    /// [ECMA 402][ecma].
+    ///
+    ///    [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
 }
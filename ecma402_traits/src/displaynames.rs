@@ -83,6 +83,17 @@ impl Default for Options {
     }
 }
 
+/// The options that [DisplayNames] actually use, as reported by
+/// [DisplayNames::resolved_options].
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale in whose language the names are displayed.
+    pub locale: String,
+    pub style: options::Style,
+    pub in_type: options::Type,
+    pub fallback: options::Fallback,
+}
+
 /// Displays a region, language, script or currency using the language of
 /// a specific locale.
 pub trait DisplayNames {
@@ -98,6 +109,29 @@ pub trait DisplayNames {
         L: crate::Locale,
         Self: Sized;
 
+    /// Creates a new [DisplayNames] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [DisplayNames] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.DisplayNames.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
     /// Formats the information about the given locale in the language used by
     /// this [DisplayNames].
     ///
@@ -109,4 +143,13 @@ pub trait DisplayNames {
     where
         W: fmt::Write,
         L: crate::Locale;
+
+    /// Returns the locale and the options that these [DisplayNames] resolved to.
+    ///
+    /// The function implements [`Intl.DisplayNames.prototype.resolvedOptions`][ro] from [ECMA
+    /// 402][ecma].
+    ///
+    ///    [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
 }
@@ -60,3 +60,20 @@ pub mod relativetime;
 /// The [displaynames] mod contains all the needed implementation bits for `Intl.DisplayNames`.
 pub mod displaynames;
 
+/// A Rust implementation of ECMA 402 Segmenter API.
+///
+/// The [segmenter] mod contains all the needed implementation bits for `Intl.Segmenter`.
+pub mod segmenter;
+
+/// The options for locale negotiation.
+///
+/// The [negotiation] mod contains the options that choose how the locales that a user requests
+/// are matched against the supported ones, in `supported_locales_of` and `try_new_with_locales`.
+pub mod negotiation;
+
+/// The typed pieces of a formatted string.
+///
+/// The [parts] mod contains the results of the `format_to_parts` functions, which correspond to
+/// `formatToParts` in JavaScript.
+pub mod parts;
+
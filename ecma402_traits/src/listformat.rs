@@ -68,6 +68,16 @@ impl Default for Options {
     }
 }
 
+/// The options that a [Format] actually uses, as reported by [Format::resolved_options].
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale whose list patterns are used.  It is the requested
+    /// locale, or the locale that the implementation fell back to.
+    pub locale: String,
+    pub style: options::Style,
+    pub in_type: options::Type,
+}
+
 use std::fmt;
 
 /// The package workhorse: formats supplied pieces of text into an ergonomically formatted
@@ -75,10 +85,6 @@ use std::fmt;
 ///
 /// While ECMA 402 originally has functions under `Intl`, we probably want to
 /// obtain a separate factory from each implementor.
-///
-/// Purposely omitted:
-///
-/// - `supported_locales_of`.
 pub trait Format {
     /// The type of error reported, if any.
     type Error: std::error::Error;
@@ -92,6 +98,29 @@ pub trait Format {
         L: crate::Locale,
         Self: Sized;
 
+    /// Creates a new [Format] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [Format] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.ListFormat.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
     /// Formats `list` into the supplied standard `writer` [fmt::Write].
     ///
     /// The original [ECMA 402 function][ecma402fmt] returns a string.  This is likely the only
@@ -112,10 +141,34 @@ pub trait Format {
     ///
     /// > Note:
     /// > - Should there be a convenience method that prints to string specifically?
-    /// > - Do we need `format_into_parts`?
     fn format<I, L, W>(&self, list: L, writer: &mut W) -> fmt::Result
     where
         I: fmt::Display,
         L: IntoIterator<Item = I>,
         W: fmt::Write;
+
+    /// Formats `list` into a sequence of parts: one [PartType::Element] for each item of the
+    /// list, and [PartType::Literal] for the separators between them.
+    ///
+    /// The function implements [`Intl.ListFormat.prototype.formatToParts`][ftp] from [ECMA
+    /// 402][ecma].
+    ///
+    ///   [ftp]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/ListFormat/formatToParts
+    ///   [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    ///
+    ///   [PartType::Element]: crate::parts::PartType::Element
+    ///   [PartType::Literal]: crate::parts::PartType::Literal
+    fn format_to_parts<I, L>(&self, list: L) -> Result<Vec<crate::parts::Part>, Self::Error>
+    where
+        I: fmt::Display,
+        L: IntoIterator<Item = I>;
+
+    /// Returns the locale and the options that this [Format] resolved to.
+    ///
+    /// The function implements [`Intl.ListFormat.prototype.resolvedOptions`][ro] from [ECMA
+    /// 402][ecma].
+    ///
+    ///   [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/ListFormat/resolvedOptions
+    ///   [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
 }
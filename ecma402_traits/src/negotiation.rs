@@ -0,0 +1,71 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Each service has two functions that take a list of locales that a user requests, in order of
+//! preference, and match it against the locales that the service supports.  The negotiation
+//! [Options](crate::negotiation::Options) choose how a requested locale is matched.
+//!
+//! - `try_new_with_locales` creates the service for the first of the requested locales that is
+//!   supported.  If none is, it creates the service for the default locale.
+//! - `supported_locales_of` returns those of the requested locales that are supported, as BCP 47
+//!   language tags in the order requested.  It implements the `supportedLocalesOf` function of
+//!   the service from [ECMA 402][ecma].
+//!
+//! Services that support the same locales pick the same locale from the same list of requested
+//! locales.
+//!
+//!   [ecma]: https://tc39.es/ecma402/#sec-supportedlocales
+
+/// Contains the API configuration as prescribed by ECMA 402.
+///
+/// See [Options] for the contents of the options.
+pub mod options {
+    /// Chooses how a requested locale is matched to the locales that are supported.
+    #[derive(Eq, PartialEq, Debug, Clone)]
+    pub enum LocaleMatcher {
+        /// The Lookup algorithm of [BCP 47][bcp47]: a locale is supported if it, or what is left
+        /// of it after removing subtags from the end, is supported.  "de-CH" is supported if
+        /// "de" is.
+        ///
+        ///   [bcp47]: https://www.rfc-editor.org/rfc/rfc4647#section-3.4
+        Lookup,
+        /// A matching algorithm of the implementation's choosing, which matches at least as well
+        /// as [LocaleMatcher::Lookup].  It may, for example, find that "zh-TW" is served by
+        /// "zh-Hant".
+        BestFit,
+    }
+}
+
+/// The options for matching the locales that a user requests against the locales that an
+/// implementation supports.  They are the `localeMatcher` option of the ECMA 402 constructors
+/// and of `supportedLocalesOf`.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Options {
+    /// Selects a [options::LocaleMatcher].  If unset, defaults to
+    /// [options::LocaleMatcher::BestFit].
+    pub locale_matcher: options::LocaleMatcher,
+}
+
+/// Allows the use of `supported_locales_of(..., Default::default())`.
+impl Default for Options {
+    /// Gets the default values of [Options] if omitted at setup.  The default values are
+    /// prescribed by [ECMA 402][ecma].
+    ///
+    ///   [ecma]: https://tc39.es/ecma402/#sec-supportedlocales
+    fn default() -> Self {
+        Options {
+            locale_matcher: options::LocaleMatcher::BestFit,
+        }
+    }
+}
@@ -155,7 +155,7 @@ pub mod options {
     /// of units from the full list was selected for use in ECMAScript. Pairs of simple units can
     /// be concatenated with "-per-" to make a compound unit. There is no default value; if the
     /// style is "unit", the unit property must be provided.
-    #[derive(Debug, Clone)]
+    #[derive(Eq, PartialEq, Debug, Clone)]
     pub struct Unit(pub String);
 }
 
@@ -210,6 +210,34 @@ impl Default for Options {
     }
 }
 
+/// The options that a [NumberFormat] actually uses, as reported by
+/// [NumberFormat::resolved_options].
+///
+/// The locale is the one whose data the formatter uses, which may be a fallback of the requested
+/// locale.  The options that were left unset at construction have their default values for that
+/// locale.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale, such as "de-AT".
+    pub locale: String,
+    /// The numbering system of the digits, such as "latn".
+    pub numbering_system: options::NumberingSystem,
+    pub style: options::Style,
+    pub currency: Option<options::Currency>,
+    pub currency_display: options::CurrencyDisplay,
+    pub currency_sign: options::CurrencySign,
+    pub unit: Option<options::Unit>,
+    pub notation: options::Notation,
+    pub compact_display: Option<options::CompactDisplay>,
+    pub sign_display: options::SignDisplay,
+
+    pub minimum_integer_digits: u8,
+    pub minimum_fraction_digits: u8,
+    pub maximum_fraction_digits: u8,
+    pub minimum_significant_digits: u8,
+    pub maximum_significant_digits: u8,
+}
+
 /// Formats number based on the rules configured on initialization.
 pub trait NumberFormat {
     /// The type of error reported, if any.
@@ -224,6 +252,29 @@ pub trait NumberFormat {
         L: crate::Locale,
         Self: Sized;
 
+    /// Creates a new [NumberFormat] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [NumberFormat] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.NumberFormat.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
     /// Formats `number` into the supplied `writer`.
     ///
     /// The function implements [`Intl.NumberFormat`][nfmt] from [ECMA 402][ecma].
@@ -233,4 +284,26 @@ pub trait NumberFormat {
     fn format<W>(&self, number: f64, writer: &mut W) -> fmt::Result
     where
         W: fmt::Write;
+
+    /// Formats `number` into a sequence of typed parts, such as [PartType::Integer] and
+    /// [PartType::Currency], which concatenate to the result of [NumberFormat::format].
+    ///
+    /// The function implements [`Intl.NumberFormat.prototype.formatToParts`][ftp] from [ECMA
+    /// 402][ecma].
+    ///
+    ///    [ftp]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatToParts
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    ///
+    ///    [PartType::Integer]: crate::parts::PartType::Integer
+    ///    [PartType::Currency]: crate::parts::PartType::Currency
+    fn format_to_parts(&self, number: f64) -> Result<Vec<crate::parts::Part>, Self::Error>;
+
+    /// Returns the locale and the options that this [NumberFormat] resolved to.
+    ///
+    /// The function implements [`Intl.NumberFormat.prototype.resolvedOptions`][ro] from [ECMA
+    /// 402][ecma].
+    ///
+    ///    [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/resolvedOptions
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
 }
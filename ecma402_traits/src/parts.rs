@@ -0,0 +1,153 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+/// The type of a [Part] of a formatted string.
+///
+/// The types are those that the `formatToParts` functions of [ECMA 402][ecma] report.  Each
+/// formatter only reports the types that apply to it: a number formatter does not report a
+/// [PartType::Month], for example.
+///
+/// The [fmt::Display] implementation prints the type as spelled in JavaScript, for example
+/// "minusSign" for [PartType::MinusSign].
+///
+///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+pub enum PartType {
+    /// Text that the formatter adds around the values, such as spaces or the word "and".
+    Literal,
+
+    /// The integer digits of a number.
+    Integer,
+    /// The grouping separator of a number, such as "," in "1,000".
+    Group,
+    /// The decimal separator of a number.
+    Decimal,
+    /// The fraction digits of a number.
+    Fraction,
+    /// The minus sign of a number.
+    MinusSign,
+    /// The plus sign of a number.
+    PlusSign,
+    /// The percent sign.
+    PercentSign,
+    /// The currency symbol, code or name.
+    Currency,
+    /// The unit of a measure, such as "km".
+    Unit,
+    /// The compact notation suffix, such as "K" in "1.2K".
+    Compact,
+    /// The exponent separator of the scientific notation, such as "E".
+    ExponentSeparator,
+    /// The minus sign of the exponent.
+    ExponentMinusSign,
+    /// The integer digits of the exponent.
+    ExponentInteger,
+    /// The representation of infinity.
+    Infinity,
+    /// The representation of a value that is not a number.
+    Nan,
+
+    /// The era, such as "AD".
+    Era,
+    /// The year.
+    Year,
+    /// The related Gregorian year of a calendar that names its years, such as the Chinese
+    /// calendar.
+    RelatedYear,
+    /// The name of a year in a calendar that names its years.
+    YearName,
+    /// The month, as a number or a name.
+    Month,
+    /// The day of the month.
+    Day,
+    /// The day of the week, such as "Monday".
+    Weekday,
+    /// The period of the day, such as "AM" or "in the morning".
+    DayPeriod,
+    /// The hour.
+    Hour,
+    /// The minute.
+    Minute,
+    /// The second.
+    Second,
+    /// The fraction of the second.
+    FractionalSecond,
+    /// The name of the time zone.
+    TimeZoneName,
+
+    /// An element of a formatted list.
+    Element,
+}
+
+impl fmt::Display for PartType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PartType::Literal => "literal",
+            PartType::Integer => "integer",
+            PartType::Group => "group",
+            PartType::Decimal => "decimal",
+            PartType::Fraction => "fraction",
+            PartType::MinusSign => "minusSign",
+            PartType::PlusSign => "plusSign",
+            PartType::PercentSign => "percentSign",
+            PartType::Currency => "currency",
+            PartType::Unit => "unit",
+            PartType::Compact => "compact",
+            PartType::ExponentSeparator => "exponentSeparator",
+            PartType::ExponentMinusSign => "exponentMinusSign",
+            PartType::ExponentInteger => "exponentInteger",
+            PartType::Infinity => "infinity",
+            PartType::Nan => "nan",
+            PartType::Era => "era",
+            PartType::Year => "year",
+            PartType::RelatedYear => "relatedYear",
+            PartType::YearName => "yearName",
+            PartType::Month => "month",
+            PartType::Day => "day",
+            PartType::Weekday => "weekday",
+            PartType::DayPeriod => "dayPeriod",
+            PartType::Hour => "hour",
+            PartType::Minute => "minute",
+            PartType::Second => "second",
+            PartType::FractionalSecond => "fractionalSecond",
+            PartType::TimeZoneName => "timeZoneName",
+            PartType::Element => "element",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A piece of a formatted string, together with its type.
+///
+/// The parts of a formatted string, in order, concatenate to the string that `format` would
+/// have written.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Part {
+    /// The type of this part.
+    pub part_type: PartType,
+    /// The text of this part.
+    pub value: String,
+}
+
+impl Part {
+    /// Creates a new [Part] of the given type.
+    pub fn new(part_type: PartType, value: impl Into<String>) -> Self {
+        Part {
+            part_type,
+            value: value.into(),
+        }
+    }
+}
@@ -69,6 +69,22 @@ impl Default for Options {
     }
 }
 
+/// The options that [PluralRules] actually use, as reported by
+/// [PluralRules::resolved_options].
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale whose rules are used.
+    pub locale: String,
+    pub in_type: options::Type,
+    pub minimum_integer_digits: u8,
+    pub minimum_fraction_digits: u8,
+    pub maximum_fraction_digits: u8,
+    pub minimum_significant_digits: u8,
+    pub maximum_significant_digits: u8,
+    /// The plural classes that the rules can select, such as "one" and "other".
+    pub plural_categories: Vec<String>,
+}
+
 use std::fmt;
 
 /// Returns the plural class of each of the supplied number.
@@ -85,6 +101,29 @@ pub trait PluralRules {
         L: crate::Locale,
         Self: Sized;
 
+    /// Creates a new [PluralRules] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [PluralRules] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.PluralRules.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
     /// Formats the plural class of `number` into the supplied `writer`.
     ///
     /// The function implements [`Intl.PluralRules`][plr] from [ECMA 402][ecma].
@@ -94,4 +133,13 @@ pub trait PluralRules {
     fn select<W>(&self, number: f64, writer: &mut W) -> fmt::Result
     where
         W: fmt::Write;
+
+    /// Returns the locale, the options and the plural classes of these [PluralRules].
+    ///
+    /// The function implements [`Intl.PluralRules.prototype.resolvedOptions`][ro] from [ECMA
+    /// 402][ecma].
+    ///
+    ///    [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/resolvedOptions
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
 }
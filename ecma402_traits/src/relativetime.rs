@@ -64,6 +64,18 @@ impl Default for Options {
     }
 }
 
+/// The options that a [RelativeTimeFormat] actually uses, as reported by
+/// [RelativeTimeFormat::resolved_options].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale whose data is used.
+    pub locale: String,
+    pub style: options::Style,
+    pub numeric: options::Numeric,
+    /// The numbering system of the formatted numbers, such as "latn".
+    pub numbering_system: crate::numberformat::options::NumberingSystem,
+}
+
 pub trait RelativeTimeFormat {
     /// The type of the error reported, if any.
     type Error: std::error::Error;
@@ -77,6 +89,29 @@ pub trait RelativeTimeFormat {
         L: crate::Locale,
         Self: Sized;
 
+    /// Creates a new [RelativeTimeFormat] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [RelativeTimeFormat] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.RelativeTimeFormat.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
     /// Formats `days` into the supplied writer.
     ///
     /// A positive value means days in the future.  A negative value means days in the past.
@@ -89,4 +124,24 @@ pub trait RelativeTimeFormat {
     fn format<W>(&self, days: i32, writer: &mut W) -> fmt::Result
     where
         W: fmt::Write;
+
+    /// Formats `days` into a sequence of typed parts, in which the number is split as by
+    /// [crate::numberformat::NumberFormat::format_to_parts], and the surrounding text is
+    /// [crate::parts::PartType::Literal].
+    ///
+    /// The function implements [`Intl.RelativeTimeFormat.prototype.formatToParts`][ftp] from
+    /// [ECMA 402][ecma].
+    ///
+    /// [ftp]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    /// [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn format_to_parts(&self, days: i32) -> Result<Vec<crate::parts::Part>, Self::Error>;
+
+    /// Returns the locale and the options that this [RelativeTimeFormat] resolved to.
+    ///
+    /// The function implements [`Intl.RelativeTimeFormat.prototype.resolvedOptions`][ro] from
+    /// [ECMA 402][ecma].
+    ///
+    /// [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    /// [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
 }
@@ -0,0 +1,155 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Contains the API configuration as prescribed by ECMA 402.
+///
+/// The meaning of the options is the same as in the similarly named
+/// options in the JS version.
+///
+/// See [Options] for the contents of the options.  See the [Segmenter::try_new]
+/// for the use of the options.
+pub mod options {
+    /// Chooses the unit that text is split into.
+    #[derive(Eq, PartialEq, Debug, Clone)]
+    pub enum Granularity {
+        /// User-perceived characters, such as "e" followed by a combining accent.
+        Grapheme,
+        /// Words, and the spaces and punctuation between them.
+        Word,
+        /// Sentences.
+        Sentence,
+    }
+}
+
+/// The options set by the user at construction time.  Provides as a "bag of options" since we
+/// don't expect any implementations to be attached to this struct.
+///
+/// The default values of all the options are prescribed by the [TC39 report][tc39].
+///
+///   [tc39]: https://tc39.es/ecma402/#sec-intl-segmenter-constructor
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Options {
+    /// Selects a [options::Granularity].  If unset, defaults to
+    /// [options::Granularity::Grapheme].
+    pub granularity: options::Granularity,
+}
+
+/// Allows the use of `segmenter::Segmenter::try_new(..., Default::default())`.
+impl Default for Options {
+    /// Gets the default values of [Options] if omitted at setup.  The
+    /// default values are prescribed by the [TC39 report][tc39].
+    ///
+    ///   [tc39]: https://tc39.es/ecma402/#sec-intl-segmenter-constructor
+    fn default() -> Self {
+        Options {
+            granularity: options::Granularity::Grapheme,
+        }
+    }
+}
+
+/// The options that a [Segmenter] actually uses, as reported by [Segmenter::resolved_options].
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ResolvedOptions {
+    /// The BCP 47 language tag of the locale whose segmentation rules are used.
+    pub locale: String,
+    pub granularity: options::Granularity,
+}
+
+/// One segment of the segmented text: the data of a JavaScript `Segments` iteration.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Segment<'a> {
+    /// The text of the segment.
+    pub segment: &'a str,
+    /// The byte index in `input` at which the segment starts.  JavaScript gives this index in
+    /// UTF-16 code units instead.
+    pub index: usize,
+    /// The whole of the segmented text.
+    pub input: &'a str,
+    /// For [options::Granularity::Word], whether the segment is a word, number or the like,
+    /// rather than spaces or punctuation.  `None` for the other granularities.
+    pub is_word_like: Option<bool>,
+}
+
+/// The segments of a text, as returned by [Segmenter::segment].  Iterating yields the segments
+/// in order.
+pub trait Segments<'a>: Iterator<Item = Segment<'a>> {
+    /// The type of error reported, if any.
+    type Error: std::error::Error;
+
+    /// Returns the segment that contains the byte at `index`, or `None` if `index` is past the
+    /// end of the text.  This does not affect the iteration.
+    ///
+    /// The function implements [`containing`][cnt] of the JavaScript `Segments`.
+    ///
+    ///   [cnt]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/segment/Segments/containing
+    fn containing(&self, index: usize) -> Result<Option<Segment<'a>>, Self::Error>;
+}
+
+/// Splits text into graphemes, words or sentences.
+pub trait Segmenter {
+    /// The type of error reported, if any.
+    type Error: std::error::Error;
+
+    /// The segments of a text.
+    type Segments<'a>: Segments<'a, Error = Self::Error>;
+
+    /// Creates a new [Segmenter].
+    ///
+    /// Creation may fail, for example, if the locale-specific data is not loaded, or if
+    /// the supplied options are inconsistent.
+    fn try_new<L>(l: L, opts: Options) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        Self: Sized;
+
+    /// Creates a new [Segmenter] for the first of the `requested` locales that is supported, as
+    /// described in [crate::negotiation].
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: crate::negotiation::Options,
+        opts: Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Returns those of the `requested` locales that [Segmenter] supports, as described in
+    /// [crate::negotiation].  Implements `Intl.Segmenter.supportedLocalesOf`.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: crate::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: crate::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized;
+
+    /// Splits `input` into segments of the granularity that this [Segmenter] was created with.
+    ///
+    /// The function implements [`Intl.Segmenter.prototype.segment`][seg] from [ECMA 402][ecma].
+    ///
+    ///   [seg]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/segment
+    ///   [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn segment<'a>(&self, input: &'a str) -> Result<Self::Segments<'a>, Self::Error>;
+
+    /// Returns the locale and the options that this [Segmenter] resolved to.
+    ///
+    /// The function implements [`Intl.Segmenter.prototype.resolvedOptions`][ro] from [ECMA
+    /// 402][ecma].
+    ///
+    ///   [ro]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/resolvedOptions
+    ///   [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error>;
+}
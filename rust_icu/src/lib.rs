@@ -29,17 +29,38 @@
 //! | -------- | -------- |
 //! | rust_icu_common | icu::common |
 //! | rust_icu_sys | icu::sys |
+//! | rust_icu_ubidi | icu::bidi |
 //! | rust_icu_ubrk | brk |
 //! | rust_icu_ucal | icu::cal |
+//! | rust_icu_ucasemap | icu::casemap |
+//! | rust_icu_ucnvsel | icu::cnvsel |
 //! | rust_icu_ucol | icu::col |
+//! | rust_icu_ucptrie | icu::cptrie |
+//! | rust_icu_ucurr | icu::curr |
 //! | rust_icu_udat | icu::dat |
 //! | rust_icu_udata | icu::data |
+//! | rust_icu_udtitvfmt | icu::dtitvfmt |
 //! | rust_icu_uenum | icu::enums |
+//! | rust_icu_uformattedvalue | icu::formattedvalue |
+//! | rust_icu_ugender | icu::gender |
+//! | rust_icu_uidna | icu::idna |
+//! | rust_icu_uldnames | icu::ldnames |
 //! | rust_icu_ulistformatter | icu::listformatter |
 //! | rust_icu_uloc | icu::loc |
+//! | rust_icu_ulocdata | icu::locdata |
 //! | rust_icu_umsg | icu::msg |
 //! | rust_icu_unorm | unorm |
+//! | rust_icu_unumberrangeformatter | icu::numrf |
+//! | rust_icu_unumsys | icu::numsys |
+//! | rust_icu_uregex | icu::regex |
+//! | rust_icu_ureldatefmt | icu::reldatefmt |
 //! | rust_icu_ures | ures |
+//! | rust_icu_uscript | icu::script |
+//! | rust_icu_usearch | icu::search |
+//! | rust_icu_uset | icu::set |
+//! | rust_icu_ushape | icu::shape |
+//! | rust_icu_uspoof | icu::spoof |
+//! | rust_icu_usprep | icu::sprep |
 //! | rust_icu_ustring | icu::string |
 //! | rust_icu_utext | text |
 //! | rust_icu_utrans | trans |
@@ -60,3 +81,24 @@ pub use rust_icu_ustring as string;
 pub use rust_icu_utext as text;
 pub use rust_icu_utrans as trans;
 pub use rust_icu_unorm2 as norm;
+pub use rust_icu_uset as set;
+pub use rust_icu_ucptrie as cptrie;
+pub use rust_icu_uregex as regex;
+pub use rust_icu_ubidi as bidi;
+pub use rust_icu_uidna as idna;
+pub use rust_icu_uspoof as spoof;
+pub use rust_icu_usearch as search;
+pub use rust_icu_ucasemap as casemap;
+pub use rust_icu_uformattedvalue as formattedvalue;
+pub use rust_icu_ureldatefmt as reldatefmt;
+pub use rust_icu_uldnames as ldnames;
+pub use rust_icu_unumberrangeformatter as numrf;
+pub use rust_icu_udtitvfmt as dtitvfmt;
+pub use rust_icu_ucurr as curr;
+pub use rust_icu_ulocdata as locdata;
+pub use rust_icu_uscript as script;
+pub use rust_icu_unumsys as numsys;
+pub use rust_icu_ugender as gender;
+pub use rust_icu_ushape as shape;
+pub use rust_icu_ucnvsel as cnvsel;
+pub use rust_icu_usprep as sprep;
@@ -404,6 +404,103 @@ macro_rules! generalized_fallible_setter{
     )
 }
 
+/// Defines a set of bit flags, for ICU functions that take or return a bitmask of values.
+///
+/// The set gets named constants for the flags, set operations, and a `Debug` implementation
+/// that lists the names of the flags in the set.
+///
+/// ICU mostly defines such flags as preprocessor macros or in anonymous enums, which bindgen does
+/// not generate bindings for.  The flag values are thus copied from the ICU headers.
+///
+/// Example:
+///
+/// ```rust ignore
+/// flag_set! {
+///     /// The options.
+///     Options {
+///         /// The first option.
+///         FIRST = 0x1,
+///         /// The second option.
+///         SECOND = 0x2,
+///     }
+/// }
+/// // ...
+/// let options = Options::FIRST | Options::SECOND;
+/// assert!(options.contains(Options::SECOND));
+/// ```
+#[macro_export]
+macro_rules! flag_set {
+    ($(#[$meta:meta])* $name:ident { $($(#[$flag_meta:meta])* $flag:ident = $value:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name(u32);
+
+        impl $name {
+            $(
+                $(#[$flag_meta])*
+                pub const $flag: $name = $name($value);
+            )*
+
+            /// Returns the set of flags from the underlying ICU bitmask.  Unknown bits are kept.
+            pub const fn from_bits(bits: u32) -> Self {
+                $name(bits)
+            }
+
+            /// Returns the underlying ICU bitmask.
+            pub const fn bits(self) -> u32 {
+                self.0
+            }
+
+            /// Returns true if no flag is set.
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Returns true if all flags in `other` are also set in `self`.
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl ::std::ops::BitOr for $name {
+            type Output = Self;
+            fn bitor(self, other: Self) -> Self {
+                $name(self.0 | other.0)
+            }
+        }
+
+        impl ::std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+        }
+
+        impl ::std::ops::BitAnd for $name {
+            type Output = Self;
+            fn bitand(self, other: Self) -> Self {
+                $name(self.0 & other.0)
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let mut names: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+                let mut rest = self.0;
+                $(
+                    if $name::$flag.0 != 0 && self.contains($name::$flag) {
+                        names.push(::std::string::ToString::to_string(::std::stringify!($flag)));
+                        rest &= !$name::$flag.0;
+                    }
+                )*
+                if rest != 0 {
+                    names.push(::std::format!("{:#x}", rest));
+                }
+                ::std::write!(f, "{}({})", ::std::stringify!($name), names.join(" | "))
+            }
+        }
+    };
+}
+
 /// Used to simulate an array of C-style strings.
 #[derive(Debug)]
 pub struct CStringVec {
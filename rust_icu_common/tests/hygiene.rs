@@ -6,3 +6,11 @@ struct Type {
 }
 
 ::rust_icu_common::simple_drop_impl!(Type, umsg_close);
+
+::rust_icu_common::flag_set! {
+    /// Flags.
+    Flags {
+        /// The first flag.
+        FIRST = 0x1,
+    }
+}
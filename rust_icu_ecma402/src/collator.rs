@@ -0,0 +1,542 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implements the traits found in [ecma402_traits::collator].
+
+use ecma402_traits;
+use rust_icu_common as common;
+use rust_icu_sys as usys;
+use rust_icu_ucol as ucol;
+use rust_icu_uloc as uloc;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+/// Implements [ecma402_traits::collator::Collator] using ICU as the underlying
+/// implementing library.
+pub struct Collator {
+    // The internal representation of the collator.
+    rep: ucol::UCollator,
+    // Kept for reporting the resolved options, along with what `rep` reports.
+    usage: ecma402_traits::collator::options::Usage,
+}
+
+pub(crate) mod internal {
+    use ecma402_traits::collator::options;
+    use rust_icu_sys as usys;
+    use rust_icu_uloc as uloc;
+
+    // Selects the collation for the usage through the "co" keyword of the locale.  ECMA-402
+    // does not allow the locale to select the "search" and "standard" collations, so those are
+    // removed for sorting.
+    pub fn with_usage(locale: uloc::ULoc, usage: &options::Usage) -> uloc::ULoc {
+        let mut locale = uloc::ULocMut::from(locale);
+        match usage {
+            options::Usage::Search => {
+                locale.set_unicode_keyvalue("co", "search");
+            }
+            options::Usage::Sort => {
+                if let Some(co) = locale.remove_unicode_keyvalue("co") {
+                    if co != "search" && co != "standard" {
+                        locale.set_unicode_keyvalue("co", &co);
+                    }
+                }
+            }
+        }
+        uloc::ULoc::from(locale)
+    }
+
+    // Converts the trait style sensitivity to the ICU collation strength.  The case
+    // sensitivity is the primary strength with the case level turned on.
+    pub fn to_icu_strength(sensitivity: &options::Sensitivity) -> usys::UCollationStrength {
+        match sensitivity {
+            options::Sensitivity::Base => usys::UColAttributeValue::UCOL_PRIMARY,
+            options::Sensitivity::Accent => usys::UColAttributeValue::UCOL_SECONDARY,
+            options::Sensitivity::Case => usys::UColAttributeValue::UCOL_PRIMARY,
+            options::Sensitivity::Variant => usys::UColAttributeValue::UCOL_TERTIARY,
+        }
+    }
+
+    // Converts the trait style sensitivity to the ICU case level attribute value.
+    pub fn to_icu_case_level(sensitivity: &options::Sensitivity) -> usys::UColAttributeValue {
+        match sensitivity {
+            options::Sensitivity::Case => usys::UColAttributeValue::UCOL_ON,
+            _ => usys::UColAttributeValue::UCOL_OFF,
+        }
+    }
+
+    // Converts the trait style punctuation to the ICU alternate handling attribute value, or
+    // `None` to keep the default of the locale.
+    pub fn to_icu_alternate_handling(
+        punctuation: &options::Punctuation,
+    ) -> Option<usys::UColAttributeValue> {
+        match punctuation {
+            options::Punctuation::Ignore => Some(usys::UColAttributeValue::UCOL_SHIFTED),
+            options::Punctuation::Honor => None,
+        }
+    }
+
+    // Converts the trait style numeric to the ICU numeric collation attribute value, or
+    // `None` to keep the "kn" keyword of the locale.
+    pub fn to_icu_numeric(numeric: &options::Numeric) -> Option<usys::UColAttributeValue> {
+        match numeric {
+            options::Numeric::Use => Some(usys::UColAttributeValue::UCOL_ON),
+            options::Numeric::Ignore => None,
+        }
+    }
+
+    // Converts the trait style case first to the ICU case first attribute value, or `None` to
+    // keep the "kf" keyword of the locale.
+    pub fn to_icu_case_first(case_first: &options::CaseFirst) -> Option<usys::UColAttributeValue> {
+        match case_first {
+            options::CaseFirst::Upper => Some(usys::UColAttributeValue::UCOL_UPPER_FIRST),
+            options::CaseFirst::Lower => Some(usys::UColAttributeValue::UCOL_LOWER_FIRST),
+            options::CaseFirst::False => None,
+        }
+    }
+
+    // Converts the ICU collation strength and case level back into the trait style sensitivity.
+    pub fn from_icu_strength(
+        strength: usys::UCollationStrength,
+        case_level: usys::UColAttributeValue,
+    ) -> options::Sensitivity {
+        use usys::UColAttributeValue::*;
+        match (strength, case_level) {
+            (UCOL_PRIMARY, UCOL_ON) => options::Sensitivity::Case,
+            (UCOL_PRIMARY, _) => options::Sensitivity::Base,
+            (UCOL_SECONDARY, _) => options::Sensitivity::Accent,
+            _ => options::Sensitivity::Variant,
+        }
+    }
+
+    // Finds the collation type that ECMA-402 reports for the "co" keyword of `locale`.  The
+    // search collation is selected by the usage, and so is not reported as the collation.
+    pub fn collation_type(locale: &uloc::ULoc) -> Result<String, rust_icu_common::Error> {
+        Ok(match locale.unicode_keyword_value("co")? {
+            Some(co) if co != "search" && co != "standard" => co,
+            _ => "default".to_owned(),
+        })
+    }
+}
+
+impl ecma402_traits::collator::Collator for Collator {
+    type Error = common::Error;
+
+    /// Creates a new [Collator].
+    ///
+    /// The `-u-co`, `-u-kn` and `-u-kf` keywords of the locale are honored.  Since the trait
+    /// options can not be left unset, [Numeric::Ignore][ecma402_traits::collator::options::Numeric::Ignore]
+    /// and [CaseFirst::False][ecma402_traits::collator::options::CaseFirst::False] keep
+    /// whatever the locale selects, while the other values of these options override the
+    /// locale.
+    fn try_new<L>(l: L, opts: ecma402_traits::collator::Options) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        Self: Sized,
+    {
+        let locale = uloc::ULoc::try_from(&format!("{}", l)[..])?;
+        let locale = internal::with_usage(locale, &opts.usage);
+        let mut rep = ucol::UCollator::try_from(locale.label())?;
+        rep.set_strength(internal::to_icu_strength(&opts.sensitivity));
+        rep.set_attribute(
+            usys::UColAttribute::UCOL_CASE_LEVEL,
+            internal::to_icu_case_level(&opts.sensitivity),
+        )?;
+        if let Some(value) = internal::to_icu_alternate_handling(&opts.punctuation) {
+            rep.set_attribute(usys::UColAttribute::UCOL_ALTERNATE_HANDLING, value)?;
+        }
+        if let Some(value) = internal::to_icu_numeric(&opts.numeric) {
+            rep.set_attribute(usys::UColAttribute::UCOL_NUMERIC_COLLATION, value)?;
+        }
+        if let Some(value) = internal::to_icu_case_first(&opts.case_first) {
+            rep.set_attribute(usys::UColAttribute::UCOL_CASE_FIRST, value)?;
+        }
+        Ok(Collator {
+            rep,
+            usage: opts.usage,
+        })
+    }
+
+    /// Creates a new [Collator] for the locale that [crate::negotiation::resolve_locale] picks
+    /// from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: ecma402_traits::collator::Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_collation_locales()?,
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [Collator] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_collation_locales()?,
+            requested,
+            &opts,
+        )
+    }
+
+    /// Compares `first` and `second`.
+    ///
+    /// The function implements [`Intl.Collator.prototype.compare`][cmp] from [ECMA 402][ecma].
+    ///
+    ///    [cmp]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/compare
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn compare<P, Q>(&self, first: P, second: Q) -> Ordering
+    where
+        P: AsRef<str>,
+        Q: AsRef<str>,
+    {
+        self.rep
+            .strcoll_utf8(first, second)
+            .expect("rust strings are always valid UTF-8")
+    }
+
+    /// Returns the resolved options, as read back from the collator.  The locale and the
+    /// collation type are those of the rules that the collator loaded.
+    fn resolved_options(&self) -> Result<ecma402_traits::collator::ResolvedOptions, Self::Error> {
+        use ecma402_traits::collator::options;
+        use usys::UColAttribute::*;
+        use usys::UColAttributeValue::*;
+        let valid = self
+            .rep
+            .get_locale_by_type(usys::ULocDataLocaleType::ULOC_VALID_LOCALE)?;
+        let valid = uloc::ULoc::try_from(&valid[..])?;
+        let punctuation = match self.rep.get_attribute(UCOL_ALTERNATE_HANDLING)? {
+            UCOL_SHIFTED => options::Punctuation::Ignore,
+            _ => options::Punctuation::Honor,
+        };
+        let numeric = match self.rep.get_attribute(UCOL_NUMERIC_COLLATION)? {
+            UCOL_ON => options::Numeric::Use,
+            _ => options::Numeric::Ignore,
+        };
+        let case_first = match self.rep.get_attribute(UCOL_CASE_FIRST)? {
+            UCOL_UPPER_FIRST => options::CaseFirst::Upper,
+            UCOL_LOWER_FIRST => options::CaseFirst::Lower,
+            _ => options::CaseFirst::False,
+        };
+        Ok(ecma402_traits::collator::ResolvedOptions {
+            locale: crate::resolved::to_language_tag(valid.label())?,
+            usage: self.usage.clone(),
+            sensitivity: internal::from_icu_strength(
+                self.rep.get_strength(),
+                self.rep.get_attribute(UCOL_CASE_LEVEL)?,
+            ),
+            punctuation,
+            collation: internal::collation_type(&valid)?,
+            numeric,
+            case_first,
+        })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use ecma402_traits::collator;
+    use ecma402_traits::collator::Collator;
+
+    fn sorted(collator: &super::Collator, words: &[&'static str]) -> Vec<&'static str> {
+        let mut words = words.to_vec();
+        words.sort_by(|a, b| collator.compare(a, b));
+        words
+    }
+
+    #[test]
+    fn sort_per_locale() -> Result<(), common::Error> {
+        #[derive(Debug, Clone)]
+        struct TestCase {
+            locale: &'static str,
+            opts: collator::Options,
+            words: Vec<&'static str>,
+            expected: Vec<&'static str>,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "en-US",
+                opts: Default::default(),
+                words: vec!["b", "A", "a", "B"],
+                expected: vec!["a", "A", "b", "B"],
+            },
+            TestCase {
+                locale: "en-US",
+                opts: collator::Options {
+                    case_first: collator::options::CaseFirst::Upper,
+                    ..Default::default()
+                },
+                words: vec!["b", "a", "B", "A"],
+                expected: vec!["A", "a", "B", "b"],
+            },
+            TestCase {
+                locale: "en-US-u-kf-upper",
+                opts: Default::default(),
+                words: vec!["b", "a", "B", "A"],
+                expected: vec!["A", "a", "B", "b"],
+            },
+            TestCase {
+                locale: "en-US-u-kf-upper",
+                opts: collator::Options {
+                    case_first: collator::options::CaseFirst::Lower,
+                    ..Default::default()
+                },
+                words: vec!["b", "a", "B", "A"],
+                expected: vec!["a", "A", "b", "B"],
+            },
+            TestCase {
+                locale: "en-US",
+                opts: Default::default(),
+                words: vec!["10", "9", "1"],
+                expected: vec!["1", "10", "9"],
+            },
+            TestCase {
+                locale: "en-US",
+                opts: collator::Options {
+                    numeric: collator::options::Numeric::Use,
+                    ..Default::default()
+                },
+                words: vec!["10", "9", "1"],
+                expected: vec!["1", "9", "10"],
+            },
+            TestCase {
+                locale: "en-US-u-kn",
+                opts: Default::default(),
+                words: vec!["10", "9", "1"],
+                expected: vec!["1", "9", "10"],
+            },
+            TestCase {
+                locale: "sv-SE",
+                opts: Default::default(),
+                words: vec!["ö", "z", "a"],
+                expected: vec!["a", "z", "ö"],
+            },
+            TestCase {
+                locale: "de-DE",
+                opts: Default::default(),
+                words: vec!["ö", "z", "a"],
+                expected: vec!["a", "ö", "z"],
+            },
+        ];
+        for test in tests {
+            let locale = crate::Locale::FromULoc(uloc::ULoc::try_from(test.locale)?);
+            let collator = super::Collator::try_new(locale, test.opts.clone())?;
+            let actual = sorted(&collator, &test.words);
+            assert_eq!(test.expected, actual, "for test case: {:?}", &test);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn compare_with_options() -> Result<(), common::Error> {
+        use collator::options::{Punctuation, Sensitivity, Usage};
+        #[derive(Debug, Clone)]
+        struct TestCase {
+            opts: collator::Options,
+            first: &'static str,
+            second: &'static str,
+            expected: Ordering,
+        }
+        let with_sensitivity = |sensitivity| collator::Options {
+            sensitivity,
+            ..Default::default()
+        };
+        let tests = vec![
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Base),
+                first: "a",
+                second: "á",
+                expected: Ordering::Equal,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Base),
+                first: "a",
+                second: "A",
+                expected: Ordering::Equal,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Base),
+                first: "a",
+                second: "b",
+                expected: Ordering::Less,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Accent),
+                first: "a",
+                second: "á",
+                expected: Ordering::Less,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Accent),
+                first: "a",
+                second: "A",
+                expected: Ordering::Equal,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Case),
+                first: "a",
+                second: "á",
+                expected: Ordering::Equal,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Case),
+                first: "a",
+                second: "A",
+                expected: Ordering::Less,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Variant),
+                first: "a",
+                second: "á",
+                expected: Ordering::Less,
+            },
+            TestCase {
+                opts: with_sensitivity(Sensitivity::Variant),
+                first: "a",
+                second: "A",
+                expected: Ordering::Less,
+            },
+            TestCase {
+                opts: Default::default(),
+                first: "co-op",
+                second: "coop",
+                expected: Ordering::Less,
+            },
+            TestCase {
+                opts: collator::Options {
+                    punctuation: Punctuation::Ignore,
+                    ..Default::default()
+                },
+                first: "co-op",
+                second: "coop",
+                expected: Ordering::Equal,
+            },
+            TestCase {
+                opts: collator::Options {
+                    usage: Usage::Search,
+                    sensitivity: Sensitivity::Base,
+                    ..Default::default()
+                },
+                first: "résumé",
+                second: "RESUME",
+                expected: Ordering::Equal,
+            },
+        ];
+        for test in tests {
+            let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("en-US")?);
+            let collator = super::Collator::try_new(locale, test.opts.clone())?;
+            assert_eq!(
+                test.expected,
+                collator.compare(test.first, test.second),
+                "for test case: {:?}",
+                &test
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn collation_keyword_for_usage() -> Result<(), common::Error> {
+        let locale = uloc::ULoc::try_from("de-u-co-phonebk")?;
+        let sort = internal::with_usage(locale.clone(), &collator::options::Usage::Sort);
+        assert_eq!(
+            Some("phonebk".to_string()),
+            sort.unicode_keyword_value("co")?
+        );
+        let search = internal::with_usage(locale, &collator::options::Usage::Search);
+        assert_eq!(
+            Some("search".to_string()),
+            search.unicode_keyword_value("co")?
+        );
+
+        let locale = uloc::ULoc::try_from("de-u-co-search")?;
+        let sort = internal::with_usage(locale, &collator::options::Usage::Sort);
+        assert_eq!(None, sort.unicode_keyword_value("co")?);
+        Ok(())
+    }
+
+    #[test]
+    fn resolved_options() -> Result<(), common::Error> {
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("de-AT-u-co-phonebk-kn")?);
+        let opts = collator::Options {
+            sensitivity: collator::options::Sensitivity::Case,
+            punctuation: collator::options::Punctuation::Ignore,
+            ..Default::default()
+        };
+        let collator = super::Collator::try_new(locale, opts)?;
+        assert_eq!(
+            collator::ResolvedOptions {
+                locale: "de-AT".into(),
+                usage: collator::options::Usage::Sort,
+                sensitivity: collator::options::Sensitivity::Case,
+                punctuation: collator::options::Punctuation::Ignore,
+                collation: "phonebk".into(),
+                // Selected by the locale, not the options.
+                numeric: collator::options::Numeric::Use,
+                case_first: collator::options::CaseFirst::False,
+            },
+            collator.resolved_options()?
+        );
+
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("sv-SE")?);
+        let opts = collator::Options {
+            usage: collator::options::Usage::Search,
+            sensitivity: collator::options::Sensitivity::Base,
+            ..Default::default()
+        };
+        let resolved = super::Collator::try_new(locale, opts)?.resolved_options()?;
+        assert_eq!("sv", resolved.locale);
+        assert_eq!(collator::options::Usage::Search, resolved.usage);
+        assert_eq!(collator::options::Sensitivity::Base, resolved.sensitivity);
+        assert_eq!("default", resolved.collation);
+        Ok(())
+    }
+
+    #[test]
+    fn locale_negotiation() -> Result<(), common::Error> {
+        let requested = || {
+            ["de-AT-u-co-phonebk", "sv"]
+                .iter()
+                .map(|l| crate::Locale::FromULoc(uloc::ULoc::for_language_tag(l).unwrap()))
+        };
+        assert_eq!(
+            vec!["de-AT-u-co-phonebk", "sv"],
+            super::Collator::supported_locales_of(requested(), Default::default())?
+        );
+        let collator = super::Collator::try_new_with_locales(
+            requested(),
+            Default::default(),
+            Default::default(),
+        )?;
+        let resolved = collator.resolved_options()?;
+        assert_eq!("de-AT", resolved.locale);
+        assert_eq!("phonebk", resolved.collation);
+        Ok(())
+    }
+}
@@ -15,22 +15,34 @@
 //! Implements the traits found in [ecma402_traits::datetimeformat].
 
 use ecma402_traits;
+use ecma402_traits::datetimeformat::{options, DateTimeFormatOptions, ResolvedOptions};
+use ecma402_traits::parts;
 use rust_icu_common as common;
+use rust_icu_sys as usys;
+use rust_icu_ucal as ucal;
 use rust_icu_udat as udat;
 use rust_icu_uloc as uloc;
 use rust_icu_ustring as ustring;
-use std::{convert::TryFrom, fmt};
+use std::{convert::TryFrom, fmt, num};
 
 #[derive(Debug)]
 pub struct DateTimeFormat {
     // The internal representation of date-time formatting.
     rep: udat::UDateFormat,
+    // What `rep` was made from, and the options that chose it, from which the resolved options
+    // are found.  An empty `tz_id` is the default time zone.
+    locale: uloc::ULoc,
+    tz_id: String,
+    pattern: String,
+    opts: DateTimeFormatOptions,
 }
 
 pub(crate) mod internal {
     use ecma402_traits::datetimeformat::DateTimeFormatOptions;
     use ecma402_traits::datetimeformat::options;
+    use ecma402_traits::parts::PartType;
     use rust_icu_common as common;
+    use rust_icu_sys as usys;
     use rust_icu_ustring as ustring;
     use rust_icu_uloc as uloc;
     use std::convert::TryFrom;
@@ -204,6 +216,83 @@ pub(crate) mod internal {
         let loc = uloc::ULoc::from(locale);
         loc
     }
+
+    /// Splits a date format pattern into its fields, as runs of the same pattern letter, and
+    /// skips the quoted literal text.  For example, "EEEE, d 'de' MMMM" has the fields
+    /// `[('E', 4), ('d', 1), ('M', 4)]`.
+    pub fn pattern_fields(pattern: &str) -> Vec<(char, usize)> {
+        let mut fields: Vec<(char, usize)> = vec![];
+        let mut quoted = false;
+        // Whether the previous character was a pattern letter.
+        let mut in_field = false;
+        for c in pattern.chars() {
+            if c == '\'' {
+                quoted = !quoted;
+                in_field = false;
+                continue;
+            }
+            if quoted || !c.is_ascii_alphabetic() {
+                in_field = false;
+                continue;
+            }
+            match fields.last_mut() {
+                Some((letter, count)) if in_field && *letter == c => *count += 1,
+                _ => fields.push((c, 1)),
+            }
+            in_field = true;
+        }
+        fields
+    }
+
+    /// Reports the ICU names of Coordinated Universal Time as "UTC", which is the only name
+    /// that ECMA 402 gives it.
+    pub fn canonical_time_zone(tz_id: String) -> String {
+        match tz_id.as_str() {
+            "Etc/UTC" | "Etc/GMT" | "GMT" => "UTC".to_owned(),
+            _ => tz_id,
+        }
+    }
+
+    /// Converts an ICU date format field into the type of the part that it formats.  The
+    /// fields that ECMA 402 has no part type for, such as the week of the year, are literal.
+    pub fn to_part_type(field: i32) -> PartType {
+        use usys::UDateFormatField::*;
+        let types = [
+            (UDAT_ERA_FIELD, PartType::Era),
+            (UDAT_YEAR_FIELD, PartType::Year),
+            (UDAT_EXTENDED_YEAR_FIELD, PartType::Year),
+            (UDAT_YEAR_NAME_FIELD, PartType::YearName),
+            (UDAT_RELATED_YEAR_FIELD, PartType::RelatedYear),
+            (UDAT_MONTH_FIELD, PartType::Month),
+            (UDAT_STANDALONE_MONTH_FIELD, PartType::Month),
+            (UDAT_DATE_FIELD, PartType::Day),
+            (UDAT_DAY_OF_WEEK_FIELD, PartType::Weekday),
+            (UDAT_DOW_LOCAL_FIELD, PartType::Weekday),
+            (UDAT_STANDALONE_DAY_FIELD, PartType::Weekday),
+            (UDAT_AM_PM_FIELD, PartType::DayPeriod),
+            (UDAT_AM_PM_MIDNIGHT_NOON_FIELD, PartType::DayPeriod),
+            (UDAT_FLEXIBLE_DAY_PERIOD_FIELD, PartType::DayPeriod),
+            (UDAT_HOUR_OF_DAY1_FIELD, PartType::Hour),
+            (UDAT_HOUR_OF_DAY0_FIELD, PartType::Hour),
+            (UDAT_HOUR1_FIELD, PartType::Hour),
+            (UDAT_HOUR0_FIELD, PartType::Hour),
+            (UDAT_MINUTE_FIELD, PartType::Minute),
+            (UDAT_SECOND_FIELD, PartType::Second),
+            (UDAT_FRACTIONAL_SECOND_FIELD, PartType::FractionalSecond),
+            (UDAT_TIMEZONE_FIELD, PartType::TimeZoneName),
+            (UDAT_TIMEZONE_RFC_FIELD, PartType::TimeZoneName),
+            (UDAT_TIMEZONE_GENERIC_FIELD, PartType::TimeZoneName),
+            (UDAT_TIMEZONE_SPECIAL_FIELD, PartType::TimeZoneName),
+            (UDAT_TIMEZONE_LOCALIZED_GMT_OFFSET_FIELD, PartType::TimeZoneName),
+            (UDAT_TIMEZONE_ISO_FIELD, PartType::TimeZoneName),
+            (UDAT_TIMEZONE_ISO_LOCAL_FIELD, PartType::TimeZoneName),
+        ];
+        types
+            .iter()
+            .find(|(f, _)| *f as i32 == field)
+            .map(|(_, part_type)| *part_type)
+            .unwrap_or(PartType::Literal)
+    }
 }
 
 impl ecma402_traits::datetimeformat::DateTimeFormat for DateTimeFormat {
@@ -215,7 +304,7 @@ impl ecma402_traits::datetimeformat::DateTimeFormat for DateTimeFormat {
     /// or if the supplied options are inconsistent.
     fn try_new<L>(
         l: L,
-        opts: ecma402_traits::datetimeformat::DateTimeFormatOptions,
+        opts: DateTimeFormatOptions,
     ) -> Result<Self, Self::Error>
     where
         L: ecma402_traits::Locale,
@@ -230,10 +319,48 @@ impl ecma402_traits::datetimeformat::DateTimeFormat for DateTimeFormat {
 
         // The correct timezone ID comes from the resulting locale.
         let tz_id = locale.keyword_value("timezone")?.or(Some("".to_owned())).unwrap();
-        let tz_id = ustring::UChar::try_from(&tz_id[..])?;
 
-        let rep = udat::UDateFormat::new_with_pattern(&locale, &tz_id, &pattern)?;
-        Ok(DateTimeFormat { rep })
+        let rep = udat::UDateFormat::new_with_pattern(
+            &locale, &ustring::UChar::try_from(&tz_id[..])?, &pattern)?;
+        let pattern = String::try_from(&pattern)?;
+        Ok(DateTimeFormat { rep, locale, tz_id, pattern, opts })
+    }
+
+    /// Creates a new [DateTimeFormat] for the locale that
+    /// [crate::negotiation::resolve_locale] picks from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: DateTimeFormatOptions,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_locales(),
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [DateTimeFormat] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_locales(),
+            requested,
+            &opts,
+        )
     }
 
     /// Formats `date` into the supplied `writer`.
@@ -251,6 +378,110 @@ impl ecma402_traits::datetimeformat::DateTimeFormat for DateTimeFormat {
         let result = self.rep.format(date).map_err(|e| e.into())?;
         write!(writer, "{}", result)
     }
+
+    /// Formats `date` into typed parts, from the fields that `udat_formatForFields` reports.
+    fn format_to_parts(&self, date: f64) -> Result<Vec<parts::Part>, Self::Error> {
+        let (text, fields) = self.rep.format_for_fields_ustring(date)?;
+        let fields: Vec<_> = fields
+            .map(|f| {
+                let range = f.begin_index as usize..f.past_end_index as usize;
+                (internal::to_part_type(f.field_type), range)
+            })
+            .collect();
+        Ok(crate::parts::from_fields(&text, &fields))
+    }
+
+    /// Returns the resolved options.  The date and time components, and the hour cycle, are
+    /// read back from the pattern that the pattern generator chose for the requested ones.
+    fn resolved_options(&self) -> Result<ResolvedOptions, Self::Error> {
+        // ICU does not record the locale of formats made from a pattern, so this asks a
+        // style-based format for the same locale instead.
+        let styled = udat::UDateFormat::new_with_styles(
+            usys::UDateFormatStyle::UDAT_DEFAULT,
+            usys::UDateFormatStyle::UDAT_DEFAULT,
+            &self.locale,
+            &ustring::UChar::try_from(&self.tz_id[..])?,
+        )?;
+        let locale = crate::resolved::to_language_tag(
+            &styled.get_locale_by_type(usys::ULocDataLocaleType::ULOC_VALID_LOCALE)?)?;
+
+        let tz_id = match self.tz_id.as_str() {
+            "" => ucal::get_default_time_zone()?,
+            tz_id => tz_id.to_owned(),
+        };
+        let cal = ucal::UCalendar::new(
+            &tz_id, self.locale.label(), usys::UCalendarType::UCAL_DEFAULT)?;
+        let calendar = cal.get_type()?;
+        let calendar = uloc::to_unicode_locale_type("calendar", &calendar).unwrap_or(calendar);
+
+        let mut resolved = ResolvedOptions {
+            locale,
+            calendar: options::Calendar(calendar),
+            numbering_system: crate::resolved::numbering_system(&self.locale)?.as_str().into(),
+            time_zone: options::TimeZone(internal::canonical_time_zone(cal.get_time_zone_id()?)),
+            hour_cycle: None,
+            date_style: self.opts.date_style.clone(),
+            time_style: self.opts.time_style.clone(),
+            fractional_second_digits: None,
+            weekday: None,
+            era: None,
+            year: None,
+            month: None,
+            day: None,
+            hour: None,
+            minute: None,
+            second: None,
+            time_zone_style: None,
+        };
+        let size = |count| match count {
+            1 => options::DisplaySize::Numeric,
+            _ => options::DisplaySize::TwoDigit,
+        };
+        for (letter, count) in internal::pattern_fields(&self.pattern) {
+            match letter {
+                'G' => resolved.era = Some(match count {
+                    1..=3 => options::Era::Short,
+                    4 => options::Era::Long,
+                    _ => options::Era::Narrow,
+                }),
+                'y' => resolved.year = Some(match count {
+                    2 => options::DisplaySize::TwoDigit,
+                    _ => options::DisplaySize::Numeric,
+                }),
+                'M' | 'L' => resolved.month = Some(match count {
+                    1 => options::Month::Numeric,
+                    2 => options::Month::TwoDigit,
+                    3 => options::Month::Short,
+                    4 => options::Month::Long,
+                    _ => options::Month::Narrow,
+                }),
+                'd' => resolved.day = Some(size(count)),
+                'E' | 'c' => resolved.weekday = Some(match count {
+                    4 => options::Weekday::Long,
+                    5 => options::Weekday::Narrow,
+                    _ => options::Weekday::Short,
+                }),
+                'h' | 'H' | 'K' | 'k' => {
+                    resolved.hour = Some(size(count));
+                    resolved.hour_cycle = Some(match letter {
+                        'h' => options::HourCycle::H12,
+                        'H' => options::HourCycle::H23,
+                        'K' => options::HourCycle::H11,
+                        _ => options::HourCycle::H24,
+                    });
+                }
+                'm' => resolved.minute = Some(size(count)),
+                's' => resolved.second = Some(size(count)),
+                'S' => resolved.fractional_second_digits = num::NonZeroU8::new(count as u8),
+                'z' | 'O' | 'v' | 'V' => resolved.time_zone_style = Some(match count {
+                    4 => options::TimeZoneStyle::Long,
+                    _ => options::TimeZoneStyle::Short,
+                }),
+                _ => {}
+            }
+        }
+        Ok(resolved)
+    }
 }
 
 #[cfg(test)]
@@ -393,4 +624,90 @@ mod testing {
         }
         Ok(())
     }
+
+    #[test]
+    fn date_time_format_to_parts() -> Result<(), common::Error> {
+        use ecma402_traits::parts::{Part, PartType::*};
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("en-US")?);
+        let formatter = super::DateTimeFormat::try_new(locale, DateTimeFormatOptions{
+            time_zone: Some("UTC".into()),
+            weekday: Some(options::Weekday::Long),
+            year: Some(options::DisplaySize::Numeric),
+            month: Some(options::Month::Long),
+            day: Some(options::DisplaySize::Numeric),
+            ..Default::default()
+        })?;
+        let expected = vec![
+            Part::new(Weekday, "Thursday"),
+            Part::new(Literal, ", "),
+            Part::new(Month, "January"),
+            Part::new(Literal, " "),
+            Part::new(Day, "1"),
+            Part::new(Literal, ", "),
+            Part::new(Year, "1970"),
+        ];
+        assert_eq!(expected, formatter.format_to_parts(10000_f64)?);
+
+        // The parts make up the string that format writes.
+        let mut formatted = String::new();
+        formatter.format(10000_f64, &mut formatted).unwrap();
+        let joined: String = formatter.format_to_parts(10000_f64)?
+            .into_iter().map(|p| p.value).collect();
+        assert_eq!(formatted, joined);
+        Ok(())
+    }
+
+    #[test]
+    fn pattern_fields() {
+        assert_eq!(
+            vec![('E', 4), ('d', 1), ('M', 4), ('y', 1)],
+            internal::pattern_fields("EEEE, d 'de' MMMM 'de' y"));
+        assert_eq!(
+            vec![('h', 1), ('m', 2), ('a', 1)],
+            internal::pattern_fields("h:mm 'o''clock' a"));
+        assert_eq!(vec![('H', 2), ('m', 2)], internal::pattern_fields("HH''mm"));
+    }
+
+    #[test]
+    fn resolved_options() -> Result<(), common::Error> {
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("th-TH")?);
+        let formatter = super::DateTimeFormat::try_new(locale, DateTimeFormatOptions{
+            time_zone: Some(options::TimeZone("uslax".to_owned())),
+            year: Some(options::DisplaySize::Numeric),
+            month: Some(options::Month::Long),
+            day: Some(options::DisplaySize::Numeric),
+            hour: Some(options::DisplaySize::Numeric),
+            hour_cycle: Some(options::HourCycle::H23),
+            minute: Some(options::DisplaySize::TwoDigit),
+            ..Default::default()
+        })?;
+        let resolved = formatter.resolved_options()?;
+        assert_eq!("th-TH", resolved.locale);
+        // The Thai locale defaults to the Buddhist calendar.
+        assert_eq!(options::Calendar::from("buddhist"), resolved.calendar);
+        assert_eq!(options::NumberingSystem::from("latn"), resolved.numbering_system);
+        assert_eq!(options::TimeZone::from("America/Los_Angeles"), resolved.time_zone);
+        assert_eq!(Some(options::HourCycle::H23), resolved.hour_cycle);
+        assert_eq!(Some(options::Month::Long), resolved.month);
+        // The Thai pattern pads the hour to two digits.
+        assert_eq!(Some(options::DisplaySize::TwoDigit), resolved.hour);
+        assert_eq!(None, resolved.weekday);
+        assert_eq!(None, resolved.second);
+
+        // There is no data for German in Canada, so it falls back to plain German.
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("de-CA-u-ca-japanese")?);
+        let formatter = super::DateTimeFormat::try_new(locale, DateTimeFormatOptions{
+            time_zone: Some("UTC".into()),
+            era: Some(options::Era::Long),
+            year: Some(options::DisplaySize::Numeric),
+            ..Default::default()
+        })?;
+        let resolved = formatter.resolved_options()?;
+        assert_eq!("de", resolved.locale);
+        assert_eq!(options::Calendar::from("japanese"), resolved.calendar);
+        assert_eq!(options::TimeZone::from("UTC"), resolved.time_zone);
+        assert_eq!(Some(options::Era::Long), resolved.era);
+        assert_eq!(None, resolved.hour_cycle);
+        Ok(())
+    }
 }
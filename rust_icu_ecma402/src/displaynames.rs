@@ -0,0 +1,308 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implements the traits found in [ecma402_traits::displaynames].
+
+use ecma402_traits;
+use ecma402_traits::displaynames;
+use ecma402_traits::displaynames::options;
+use rust_icu_common as common;
+use rust_icu_sys as usys;
+use rust_icu_uldnames as uldnames;
+use rust_icu_uloc as uloc;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Implements [displaynames::DisplayNames] using ICU as the underlying implementing library.
+#[derive(Debug)]
+pub struct DisplayNames {
+    rep: uldnames::ULocaleDisplayNames,
+    in_type: options::Type,
+    // Kept for reporting the resolved options.
+    locale: uloc::ULoc,
+    style: options::Style,
+    fallback: options::Fallback,
+}
+
+pub(crate) mod internal {
+    use ecma402_traits::displaynames::options;
+    use rust_icu_sys as usys;
+
+    // Converts the Style param from ECMA-402 into ICU.  ICU has no narrow names, so the
+    // narrow style uses the short names.
+    pub fn to_icu_length(style: &options::Style) -> usys::UDisplayContext {
+        use options::Style;
+        use usys::UDisplayContext;
+        match style {
+            Style::Long => UDisplayContext::UDISPCTX_LENGTH_FULL,
+            Style::Short | Style::Narrow => UDisplayContext::UDISPCTX_LENGTH_SHORT,
+        }
+    }
+
+    // Converts the Fallback param from ECMA-402 into ICU.
+    pub fn to_icu_substitute(fallback: &options::Fallback) -> usys::UDisplayContext {
+        use options::Fallback;
+        use usys::UDisplayContext;
+        match fallback {
+            Fallback::Code => UDisplayContext::UDISPCTX_SUBSTITUTE,
+            Fallback::None => UDisplayContext::UDISPCTX_NO_SUBSTITUTE,
+        }
+    }
+}
+
+impl DisplayNames {
+    // Looks up the name of the part of `locale` that is selected by the type option.  Returns
+    // `Ok(None)` if the locale does not have that part.
+    fn display_name(&self, locale: &uloc::ULoc) -> Result<Option<String>, common::Error> {
+        use options::Type;
+        let name = match self.in_type {
+            Type::Language => Some(self.rep.locale_display_name(locale.label())?),
+            Type::Region => match locale.country() {
+                Some(region) => Some(self.rep.region_display_name(&region)?),
+                None => None,
+            },
+            Type::Script => match locale.script() {
+                Some(script) => Some(self.rep.script_display_name(&script)?),
+                None => None,
+            },
+            Type::Currency => match locale.keyword_value("currency")? {
+                Some(currency) => Some(self.rep.key_value_display_name("currency", &currency)?),
+                None => None,
+            },
+        };
+        Ok(name)
+    }
+}
+
+impl displaynames::DisplayNames for DisplayNames {
+    type Error = common::Error;
+
+    /// Creates a new [DisplayNames], from a [ecma402_traits::Locale] and
+    /// [displaynames::Options].
+    ///
+    /// Languages are displayed with dialect names, such as "British English", as is the
+    /// default in ECMA-402.
+    fn try_new<L>(l: L, opts: displaynames::Options) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        Self: Sized,
+    {
+        let locale = format!("{}", l);
+        let rep = uldnames::ULocaleDisplayNames::try_new_with_contexts(
+            &locale,
+            &[
+                usys::UDisplayContext::UDISPCTX_DIALECT_NAMES,
+                internal::to_icu_length(&opts.style),
+                internal::to_icu_substitute(&opts.fallback),
+            ],
+        )?;
+        Ok(DisplayNames {
+            rep,
+            in_type: opts.in_type,
+            locale: uloc::ULoc::try_from(&locale[..])?,
+            style: opts.style,
+            fallback: opts.fallback,
+        })
+    }
+
+    /// Creates a new [DisplayNames] for the locale that [crate::negotiation::resolve_locale] picks
+    /// from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: displaynames::Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_locales(),
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [DisplayNames] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_locales(),
+            requested,
+            &opts,
+        )
+    }
+
+    /// Formats the part of `locale` selected by the type option into the supplied writer.
+    ///
+    /// The region and script are those given in `locale`, and the currency is that of its
+    /// `cu` keyword, so that "en-u-cu-eur" yields "Euro".  Nothing is written if `locale` has
+    /// no such part, or if there is no name for it and the fallback option is
+    /// [options::Fallback::None].
+    fn format<W, L>(&self, locale: L, writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+        L: ecma402_traits::Locale,
+    {
+        let locale = format!("{}", locale);
+        let locale = uloc::ULoc::try_from(&locale[..]).map_err(|e| e.into())?;
+        match self.display_name(&locale) {
+            Ok(Some(name)) => write!(writer, "{}", name),
+            Ok(None) => Ok(()),
+            // ICU reports a missing name this way when it may not substitute the code.
+            Err(common::Error::Sys(usys::UErrorCode::U_ILLEGAL_ARGUMENT_ERROR)) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the resolved options.  The locale is that of the locale data bundle that the
+    /// names are looked up in first, which depends on the type option.
+    fn resolved_options(&self) -> Result<displaynames::ResolvedOptions, Self::Error> {
+        let tree = match self.in_type {
+            options::Type::Language | options::Type::Script => crate::resolved::LANGUAGE_TREE,
+            options::Type::Region => crate::resolved::REGION_TREE,
+            options::Type::Currency => crate::resolved::CURRENCY_TREE,
+        };
+        Ok(displaynames::ResolvedOptions {
+            locale: crate::resolved::data_locale(tree, &self.locale)?,
+            style: self.style.clone(),
+            in_type: self.in_type.clone(),
+            fallback: self.fallback.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use ecma402_traits::displaynames::DisplayNames;
+
+    #[test]
+    fn test_formatting_table() {
+        use options::{Fallback, Style, Type};
+        #[derive(Debug)]
+        struct TestCase {
+            locale: &'static str,
+            style: Style,
+            in_type: Type,
+            fallback: Fallback,
+            input: Vec<&'static str>,
+            expected: Vec<&'static str>,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "en-US",
+                style: Style::Long,
+                in_type: Type::Region,
+                fallback: Fallback::Code,
+                input: vec!["en-US", "de-CH", "fr", "und-XY"],
+                expected: vec!["United States", "Switzerland", "", "XY"],
+            },
+            TestCase {
+                locale: "en-US",
+                style: Style::Short,
+                in_type: Type::Region,
+                fallback: Fallback::Code,
+                input: vec!["en-US", "en-GB"],
+                expected: vec!["US", "UK"],
+            },
+            TestCase {
+                locale: "en-US",
+                style: Style::Long,
+                in_type: Type::Region,
+                fallback: Fallback::None,
+                input: vec!["en-US", "und-XY"],
+                expected: vec!["United States", ""],
+            },
+            TestCase {
+                locale: "en-US",
+                style: Style::Long,
+                in_type: Type::Language,
+                fallback: Fallback::Code,
+                input: vec!["en-GB", "de", "pt-BR"],
+                expected: vec!["British English", "German", "Brazilian Portuguese"],
+            },
+            TestCase {
+                locale: "de-DE",
+                style: Style::Long,
+                in_type: Type::Script,
+                fallback: Fallback::Code,
+                input: vec!["sr-Cyrl", "zh-Hant-TW", "de"],
+                expected: vec!["Kyrillisch", "Traditionell", ""],
+            },
+            TestCase {
+                locale: "en-US",
+                style: Style::Long,
+                in_type: Type::Currency,
+                fallback: Fallback::Code,
+                input: vec!["en-u-cu-eur", "ja-JP-u-cu-jpy", "en-US"],
+                expected: vec!["Euro", "Japanese Yen", ""],
+            },
+        ];
+        for test in tests {
+            let locale =
+                crate::Locale::FromULoc(uloc::ULoc::try_from(test.locale).expect("locale exists"));
+            let opts = displaynames::Options {
+                style: test.style.clone(),
+                in_type: test.in_type.clone(),
+                fallback: test.fallback.clone(),
+            };
+            let names = super::DisplayNames::try_new(locale, opts).expect("has display names");
+            let actual: Vec<String> = test
+                .input
+                .iter()
+                .map(|input| {
+                    let input = crate::Locale::FromULoc(
+                        uloc::ULoc::for_language_tag(input).expect("locale exists"),
+                    );
+                    let mut result = String::new();
+                    names.format(input, &mut result).expect("formatting worked");
+                    result
+                })
+                .collect();
+            assert_eq!(test.expected, actual, "for test case: {:?}", &test);
+        }
+    }
+
+    #[test]
+    fn test_resolved_options() {
+        use options::{Fallback, Style, Type};
+        let opts = displaynames::Options {
+            style: Style::Short,
+            in_type: Type::Script,
+            fallback: Fallback::None,
+        };
+        // There is no data for French in Germany, so it falls back to plain French.
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("fr-DE").expect("locale exists"));
+        let names = super::DisplayNames::try_new(locale, opts).expect("has display names");
+        assert_eq!(
+            displaynames::ResolvedOptions {
+                locale: "fr".into(),
+                style: Style::Short,
+                in_type: Type::Script,
+                fallback: Fallback::None,
+            },
+            names.resolved_options().expect("has resolved options")
+        );
+    }
+}
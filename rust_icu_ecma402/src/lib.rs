@@ -34,6 +34,36 @@ pub mod numberformat;
 /// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat
 pub mod datetimeformat;
 
+/// Implements ECMA-402 [`Intl.RelativeTimeFormat`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+pub mod relativetime;
+
+/// Implements ECMA-402 [`Intl.DisplayNames`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+pub mod displaynames;
+
+/// Implements ECMA-402 [`Intl.Collator`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/Collator
+pub mod collator;
+
+/// Implements ECMA-402 [`Intl.Segmenter`][link].
+///
+/// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/Segmenter
+pub mod segmenter;
+
+/// Matches requested locales against the supported ones, for the `supported_locales_of` and
+/// `try_new_with_locales` functions.
+pub mod negotiation;
+
+// Builds the typed parts that the `format_to_parts` functions return.
+mod parts;
+
+// Finds what the `resolved_options` functions report about the locale.
+mod resolved;
+
 pub enum Locale {
     FromULoc(ULoc),
 }
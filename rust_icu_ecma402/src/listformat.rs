@@ -16,14 +16,22 @@
 
 use ecma402_traits;
 use ecma402_traits::listformat;
+use ecma402_traits::parts;
 use rust_icu_common as common;
+use rust_icu_sys as usys;
 use rust_icu_ulistformatter as ulfmt;
+use rust_icu_uloc as uloc;
+use rust_icu_ustring as ustring;
+use std::convert::TryFrom;
 use std::fmt;
 
 /// Implements [listformat::Format] using ICU as the underlying implementing library.
 #[derive(Debug)]
 pub struct Format {
     rep: ulfmt::UListFormatter,
+    // Kept for reporting the resolved options.
+    locale: uloc::ULoc,
+    opts: listformat::Options,
 }
 
 pub(crate) mod internal {
@@ -64,7 +72,45 @@ impl listformat::Format for Format {
         let width = internal::to_icu_width(&opts.style);
         let in_type = internal::to_icu_type(&opts.in_type);
         let rep = ulfmt::UListFormatter::try_new_styled(&locale, in_type, width)?;
-        Ok(Format { rep })
+        let locale = uloc::ULoc::try_from(&locale[..])?;
+        Ok(Format { rep, locale, opts })
+    }
+
+    /// Creates a new [Format] for the locale that [crate::negotiation::resolve_locale] picks
+    /// from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: listformat::Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_locales(),
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [Format] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_locales(),
+            requested,
+            &opts,
+        )
     }
 
     /// Formats the given string.
@@ -81,6 +127,44 @@ impl listformat::Format for Format {
         let result = self.rep.format(&refs[..]).map_err(|e| e.into())?;
         write!(f, "{}", result)
     }
+
+    /// Formats the given list into parts, from the element and literal spans of the formatted
+    /// list.
+    fn format_to_parts<I, L>(&self, list: L) -> Result<Vec<parts::Part>, Self::Error>
+    where
+        I: fmt::Display,
+        L: IntoIterator<Item = I>,
+    {
+        let list_str: Vec<String> = list.into_iter().map(|e| format!("{}", e)).collect();
+        let refs: Vec<&str> = list_str.iter().map(|e| e.as_str()).collect();
+        let result = self.rep.format_to_result(&refs[..])?;
+        let value = result.as_value()?;
+        let fields: Vec<_> = value
+            .fields_in(usys::UFieldCategory::UFIELD_CATEGORY_LIST)?
+            .into_iter()
+            .map(|f| {
+                let part_type =
+                    if f.field == usys::UListFormatterField::ULISTFMT_ELEMENT_FIELD as i32 {
+                        parts::PartType::Element
+                    } else {
+                        parts::PartType::Literal
+                    };
+                (part_type, f.range)
+            })
+            .collect();
+        let text = ustring::UChar::try_from(&value)?;
+        Ok(crate::parts::from_fields(&text, &fields))
+    }
+
+    /// Returns the resolved options.  The list formatter does not report its locale, so the
+    /// locale is that of the locale data bundle that its patterns come from.
+    fn resolved_options(&self) -> Result<listformat::ResolvedOptions, Self::Error> {
+        Ok(listformat::ResolvedOptions {
+            locale: crate::resolved::data_locale(crate::resolved::MAIN_TREE, &self.locale)?,
+            style: self.opts.style.clone(),
+            in_type: self.opts.in_type.clone(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -212,4 +296,88 @@ mod testing {
             );
         }
     }
+
+    #[test]
+    fn test_format_to_parts() {
+        use ecma402_traits::parts::{Part, PartType::*};
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("en-US").expect("locale exists"));
+        let formatter = super::Format::try_new(locale, listformat::Options::default())
+            .expect("has list format");
+        let actual = formatter
+            .format_to_parts(["Huey", "Dewey", "Louie"])
+            .expect("formatting worked");
+        assert_eq!(
+            vec![
+                Part::new(Element, "Huey"),
+                Part::new(Literal, ", "),
+                Part::new(Element, "Dewey"),
+                Part::new(Literal, ", and "),
+                Part::new(Element, "Louie"),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resolved_options() {
+        use listformat::options::{Style, Type};
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("sr-RS").expect("locale exists"));
+        let opts = listformat::Options {
+            style: Style::Short,
+            in_type: Type::Disjunction,
+        };
+        let formatter = super::Format::try_new(locale, opts).expect("has list format");
+        assert_eq!(
+            listformat::ResolvedOptions {
+                // The data for Serbia is in the Cyrillic script.
+                locale: "sr-Cyrl-RS".into(),
+                style: Style::Short,
+                in_type: Type::Disjunction,
+            },
+            formatter.resolved_options().expect("has resolved options")
+        );
+
+        // There is no data for German in Canada, so it falls back to plain German.
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("de-CA").expect("locale exists"));
+        let formatter = super::Format::try_new(locale, listformat::Options::default())
+            .expect("has list format");
+        assert_eq!(
+            "de",
+            formatter
+                .resolved_options()
+                .expect("has resolved options")
+                .locale
+        );
+    }
+
+    #[test]
+    fn test_locale_negotiation() {
+        use ecma402_traits::negotiation;
+        let requested = || {
+            ["tlh", "sr-ME", "en-US"]
+                .iter()
+                .map(|l| crate::Locale::FromULoc(uloc::ULoc::for_language_tag(l).unwrap()))
+        };
+        assert_eq!(
+            vec!["sr-ME", "en-US"],
+            super::Format::supported_locales_of(requested(), Default::default()).unwrap()
+        );
+        let formatter = super::Format::try_new_with_locales(
+            requested(),
+            Default::default(),
+            Default::default(),
+        )
+        .expect("has list format");
+        let mut result = String::new();
+        formatter.format(["a", "b", "c"], &mut result).unwrap();
+        assert_eq!("a, b i c", result);
+
+        let lookup = negotiation::Options {
+            locale_matcher: negotiation::options::LocaleMatcher::Lookup,
+        };
+        assert_eq!(
+            vec!["sr-ME", "en-US"],
+            super::Format::supported_locales_of(requested(), lookup).unwrap()
+        );
+    }
 }
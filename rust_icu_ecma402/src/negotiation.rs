@@ -0,0 +1,285 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Matches the locales that a user requests against the locales that a service supports.
+//!
+//! The [LocaleMatcher::Lookup] matcher is the Lookup algorithm of BCP 47.  The
+//! [LocaleMatcher::BestFit] matcher is the ICU locale matcher, through `uloc_acceptLanguage`,
+//! which also takes the likely script and region of the locales into account.  It compares the
+//! locales with their likely subtags added, so "en-Latn-US" already matches "en-US", and the
+//! locales need not be minimized with `uloc_minimizeSubtags` first.
+//!
+//! The locale that [resolve_locale] picks is the first requested locale that is supported,
+//! rather than the supported locale that it matched.  Services that support the same languages
+//! thus pick the same locale from a list of preferences, even if one of them has data for more
+//! regions than the other.
+
+use ecma402_traits::negotiation::{options::LocaleMatcher, Options};
+use rust_icu_common as common;
+use rust_icu_ubrk as ubrk;
+use rust_icu_ucol as ucol;
+use rust_icu_uloc as uloc;
+use rust_icu_unum as unum;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/// Returns the locales that have locale data, for the services that do not list their own.
+pub fn available_locales() -> Vec<uloc::ULoc> {
+    uloc::ULoc::get_available_locales()
+}
+
+/// Returns the locales that have collation rules.
+pub fn available_collation_locales() -> Result<Vec<uloc::ULoc>, common::Error> {
+    ucol::get_available_locales()?
+        .map(|locale| uloc::ULoc::try_from(&locale?[..]))
+        .collect()
+}
+
+/// Returns the locales that have number formatting data.
+pub fn available_number_locales() -> Result<Vec<uloc::ULoc>, common::Error> {
+    unum::available_iter()
+        .map(|locale| uloc::ULoc::try_from(&locale[..]))
+        .collect()
+}
+
+/// Returns the locales that have text boundary rules.
+pub fn available_break_locales() -> Vec<uloc::ULoc> {
+    ubrk::UBreakIterator::available_locales().collect()
+}
+
+/// Finds, by the Lookup algorithm of BCP 47, the locale in `available` that serves `locale`: the
+/// longest of the base name of `locale` and its truncations that is available.
+///
+/// Implements `BestAvailableLocale` from ECMA 402.
+pub fn best_available_locale(available: &[uloc::ULoc], locale: &uloc::ULoc) -> Option<uloc::ULoc> {
+    let labels: HashSet<&str> = available.iter().map(|l| l.label()).collect();
+    let base = locale.clone().base_name();
+    let mut candidate = base.label();
+    while !candidate.is_empty() {
+        if labels.contains(candidate) {
+            return uloc::ULoc::try_from(candidate).ok();
+        }
+        // Removes the last subtag.  Locales with a variant but no region, such as "de__POSIX",
+        // have an empty subtag in between.
+        candidate = match candidate.rfind('_') {
+            Some(pos) => candidate[..pos].trim_end_matches('_'),
+            None => "",
+        };
+    }
+    None
+}
+
+/// Finds the locale in `available` that ICU matches best to `locale`, if any.
+///
+/// ICU may match a locale to one in another language, such as "tlh" to "en".  Such a match does
+/// not count, since the language of `locale` is not supported.
+pub fn best_fit_locale(
+    available: &[uloc::ULoc],
+    locale: &uloc::ULoc,
+) -> Result<Option<uloc::ULoc>, common::Error> {
+    let (matched, _) =
+        uloc::accept_language(vec![locale.clone().base_name()], available.iter().cloned())?;
+    match matched {
+        Some(matched)
+            if matched.add_likely_subtags()?.language()
+                == locale.add_likely_subtags()?.language() =>
+        {
+            Ok(Some(matched))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Returns the locale in `available` that serves `locale` when matched by `matcher`, if any.
+pub fn match_locale(
+    available: &[uloc::ULoc],
+    locale: &uloc::ULoc,
+    matcher: &LocaleMatcher,
+) -> Result<Option<uloc::ULoc>, common::Error> {
+    match matcher {
+        LocaleMatcher::Lookup => Ok(best_available_locale(available, locale)),
+        LocaleMatcher::BestFit => best_fit_locale(available, locale),
+    }
+}
+
+/// Returns those of the `requested` locales that are supported by the `available` ones, in the
+/// order requested.
+///
+/// Implements `SupportedLocales` from ECMA 402.
+pub fn supported_locales(
+    available: &[uloc::ULoc],
+    requested: &[uloc::ULoc],
+    opts: &Options,
+) -> Result<Vec<uloc::ULoc>, common::Error> {
+    let mut supported = vec![];
+    for locale in requested {
+        if match_locale(available, locale, &opts.locale_matcher)?.is_some() {
+            supported.push(locale.clone());
+        }
+    }
+    Ok(supported)
+}
+
+/// Picks the locale to create a service with: the first of the `requested` locales that is
+/// supported by the `available` ones, or the default locale if none is.
+///
+/// A supported locale is picked as requested, along with its keywords, so that the service finds
+/// the region specific data that it has.  The exception is a best fit match in another script,
+/// such as Latin Serbian for Serbian when only the former is available.  It picks the matched
+/// locale, with the requested keywords, as the service would otherwise use the requested script.
+pub fn resolve_locale(
+    available: &[uloc::ULoc],
+    requested: &[uloc::ULoc],
+    opts: &Options,
+) -> Result<uloc::ULoc, common::Error> {
+    for locale in requested {
+        let matched = match match_locale(available, locale, &opts.locale_matcher)? {
+            Some(matched) => matched,
+            None => continue,
+        };
+        if locale.add_likely_subtags()?.script() == matched.add_likely_subtags()?.script() {
+            return Ok(locale.clone());
+        }
+        let keywords = locale.label().find('@').map(|pos| &locale.label()[pos..]);
+        return uloc::ULoc::try_from(&format!("{}{}", matched, keywords.unwrap_or(""))[..]);
+    }
+    Ok(uloc::get_default())
+}
+
+/// Converts the `requested` locales, given in any [ecma402_traits::Locale] form, into ICU
+/// locales.
+pub(crate) fn to_ulocs<L, I>(requested: I) -> Result<Vec<uloc::ULoc>, common::Error>
+where
+    L: ecma402_traits::Locale,
+    I: IntoIterator<Item = L>,
+{
+    requested
+        .into_iter()
+        .map(|l| uloc::ULoc::try_from(&format!("{}", l)[..]))
+        .collect()
+}
+
+/// Returns those of the `requested` locales that are supported by the `available` ones, as BCP 47
+/// language tags.  This is what the `supported_locales_of` functions return.
+pub(crate) fn supported_language_tags<L, I>(
+    available: &[uloc::ULoc],
+    requested: I,
+    opts: &Options,
+) -> Result<Vec<String>, common::Error>
+where
+    L: ecma402_traits::Locale,
+    I: IntoIterator<Item = L>,
+{
+    supported_locales(available, &to_ulocs(requested)?, opts)?
+        .iter()
+        .map(|locale| locale.to_language_tag(false))
+        .collect()
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    fn ulocs(locales: &[&str]) -> Vec<uloc::ULoc> {
+        locales
+            .iter()
+            .map(|l| uloc::ULoc::for_language_tag(l).expect("locale exists"))
+            .collect()
+    }
+
+    fn opts(locale_matcher: LocaleMatcher) -> Options {
+        Options { locale_matcher }
+    }
+
+    #[test]
+    fn lookup() -> Result<(), common::Error> {
+        let available = ulocs(&["en", "de", "de-AT", "zh-Hant"]);
+        let requested = ulocs(&["de-AT-u-co-phonebk", "en-Latn-US", "zh-TW", "fr"]);
+        assert_eq!(
+            ulocs(&["de-AT-u-co-phonebk", "en-Latn-US"]),
+            supported_locales(&available, &requested, &opts(LocaleMatcher::Lookup))?
+        );
+        assert_eq!(
+            Some(uloc::ULoc::try_from("de_AT")?),
+            best_available_locale(&available, &requested[0])
+        );
+        assert_eq!(
+            Some(uloc::ULoc::try_from("en")?),
+            best_available_locale(&available, &requested[1])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn best_fit() -> Result<(), common::Error> {
+        let available = ulocs(&["en", "sr-Latn", "zh-Hant"]);
+        // Lookup finds neither of "zh" and "sr", but the likely scripts of these locales are
+        // available.
+        let requested = ulocs(&["zh-TW", "sr-ME", "ja"]);
+        assert_eq!(
+            ulocs(&["zh-TW", "sr-ME"]),
+            supported_locales(&available, &requested, &opts(LocaleMatcher::BestFit))?
+        );
+        assert_eq!(
+            Vec::<uloc::ULoc>::new(),
+            supported_locales(&available, &requested, &opts(LocaleMatcher::Lookup))?
+        );
+        // Locales with redundant subtags match those without.
+        let available = ulocs(&["en", "en-US", "zh-TW"]);
+        assert_eq!(
+            Some(uloc::ULoc::try_from("en_US")?),
+            best_fit_locale(&available, &uloc::ULoc::for_language_tag("en-Latn-US")?)?
+        );
+        assert_eq!(
+            Some(uloc::ULoc::try_from("zh_TW")?),
+            best_fit_locale(&available, &uloc::ULoc::for_language_tag("zh-Hant-TW")?)?
+        );
+        // Matches to other languages do not count.
+        assert_eq!(
+            None,
+            best_fit_locale(&available_locales(), &uloc::ULoc::try_from("tlh")?)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolve() -> Result<(), common::Error> {
+        let available = ulocs(&["en", "sr-Latn", "zh-Hant"]);
+        let best_fit = opts(LocaleMatcher::BestFit);
+        assert_eq!(
+            uloc::ULoc::for_language_tag("zh-TW-u-nu-hanidec")?,
+            resolve_locale(&available, &ulocs(&["ja", "zh-TW-u-nu-hanidec"]), &best_fit)?
+        );
+        // The Serbian of Serbia is in the Cyrillic script, which is not available.
+        assert_eq!(
+            uloc::ULoc::for_language_tag("sr-Latn-u-nu-latn")?,
+            resolve_locale(&available, &ulocs(&["sr-RS-u-nu-latn"]), &best_fit)?
+        );
+        assert_eq!(
+            uloc::get_default(),
+            resolve_locale(&available, &ulocs(&["ja"]), &best_fit)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn available() -> Result<(), common::Error> {
+        let en = uloc::ULoc::try_from("en")?;
+        assert!(available_locales().contains(&en));
+        assert!(available_collation_locales()?.contains(&en));
+        assert!(available_number_locales()?.contains(&en));
+        assert!(available_break_locales().contains(&en));
+        Ok(())
+    }
+}
@@ -15,22 +15,96 @@
 //! Implements the traits found in [ecma402_traits::numberformat].
 
 use {
-    ecma402_traits, rust_icu_common as common, rust_icu_unumberformatter as unumf,
-    std::convert::TryInto, std::fmt,
+    ecma402_traits, ecma402_traits::numberformat, ecma402_traits::parts,
+    rust_icu_common as common, rust_icu_uloc as uloc, rust_icu_unumberformatter as unumf,
+    rust_icu_ustring as ustring,
+    std::convert::TryFrom, std::convert::TryInto, std::fmt,
 };
 
 #[derive(Debug)]
 pub struct NumberFormat {
     // The internal representation of number formatting.
     rep: unumf::UNumberFormatter,
+    // The requested locale and options, from which the resolved options are found.
+    locale: uloc::ULoc,
+    opts: numberformat::Options,
 }
 
 pub(crate) mod internal {
     use {
         ecma402_traits::numberformat, ecma402_traits::numberformat::options,
-        rust_icu_common as common,
+        ecma402_traits::parts::PartType, rust_icu_common as common,
     };
 
+    // The values of `UNumberFormatFields`, which is not among the generated bindings.
+    const UNUM_INTEGER_FIELD: i32 = 0;
+    const UNUM_FRACTION_FIELD: i32 = 1;
+    const UNUM_DECIMAL_SEPARATOR_FIELD: i32 = 2;
+    const UNUM_EXPONENT_SYMBOL_FIELD: i32 = 3;
+    const UNUM_EXPONENT_SIGN_FIELD: i32 = 4;
+    const UNUM_EXPONENT_FIELD: i32 = 5;
+    const UNUM_GROUPING_SEPARATOR_FIELD: i32 = 6;
+    const UNUM_CURRENCY_FIELD: i32 = 7;
+    const UNUM_PERCENT_FIELD: i32 = 8;
+    const UNUM_PERMILL_FIELD: i32 = 9;
+    const UNUM_SIGN_FIELD: i32 = 10;
+    const UNUM_MEASURE_UNIT_FIELD: i32 = 11;
+    const UNUM_COMPACT_FIELD: i32 = 12;
+
+    /// Converts an ICU number field into the type of the part that it formats.  The sign, and
+    /// whether the integer part is a number at all, depend on the formatted `number`.
+    pub fn to_part_type(field: i32, number: f64) -> PartType {
+        match field {
+            UNUM_INTEGER_FIELD if number.is_nan() => PartType::Nan,
+            UNUM_INTEGER_FIELD if number.is_infinite() => PartType::Infinity,
+            UNUM_INTEGER_FIELD => PartType::Integer,
+            UNUM_FRACTION_FIELD => PartType::Fraction,
+            UNUM_DECIMAL_SEPARATOR_FIELD => PartType::Decimal,
+            UNUM_EXPONENT_SYMBOL_FIELD => PartType::ExponentSeparator,
+            UNUM_EXPONENT_SIGN_FIELD => PartType::ExponentMinusSign,
+            UNUM_EXPONENT_FIELD => PartType::ExponentInteger,
+            UNUM_GROUPING_SEPARATOR_FIELD => PartType::Group,
+            UNUM_CURRENCY_FIELD => PartType::Currency,
+            UNUM_PERCENT_FIELD | UNUM_PERMILL_FIELD => PartType::PercentSign,
+            UNUM_SIGN_FIELD if number.is_sign_negative() => PartType::MinusSign,
+            UNUM_SIGN_FIELD => PartType::PlusSign,
+            UNUM_MEASURE_UNIT_FIELD => PartType::Unit,
+            UNUM_COMPACT_FIELD => PartType::Compact,
+            _ => PartType::Literal,
+        }
+    }
+
+    /// The digit options, with the defaults filled in.
+    pub struct Digits {
+        pub minimum_integer: u8,
+        pub minimum_fraction: u8,
+        pub maximum_fraction: u8,
+        pub minimum_significant: u8,
+        pub maximum_significant: u8,
+    }
+
+    /// Fills in the defaults of the digit options that `opts` leaves unset.
+    pub fn digits_from(opts: &numberformat::Options) -> Digits {
+        let minimum_integer = opts.minimum_integer_digits.unwrap_or(1);
+        // TODO: this should match the list at:
+        // https://www.currency-iso.org/en/home/tables/table-a1.html
+        let minimum_fraction = opts.minimum_fraction_digits.unwrap_or(match opts.style {
+            options::Style::Currency => 2,
+            _ => 0,
+        });
+        let maximum_fraction = opts.maximum_fraction_digits.unwrap_or(match opts.style {
+            options::Style::Currency => std::cmp::max(2, minimum_fraction),
+            _ => 3,
+        });
+        Digits {
+            minimum_integer,
+            minimum_fraction,
+            maximum_fraction,
+            minimum_significant: opts.minimum_significant_digits.unwrap_or(1),
+            maximum_significant: opts.maximum_significant_digits.unwrap_or(21),
+        }
+    }
+
     /// Produces a [skeleton][skel] that corresponds to the given option.
     ///
     /// The conversion may fail if the options are malformed, for example request currency
@@ -144,27 +218,15 @@ pub(crate) mod internal {
             }
         }
 
-        let minimum_integer_digits = opts.minimum_integer_digits.unwrap_or(1);
-        // TODO: this should match the list at:
-        // https://www.currency-iso.org/en/home/tables/table-a1.html
-        let minimum_fraction_digits = opts.minimum_fraction_digits.unwrap_or(match opts.style {
-            options::Style::Currency => 2,
-            _ => 0,
-        });
-        let maximum_fraction_digits = opts.maximum_fraction_digits.unwrap_or(match opts.style {
-            options::Style::Currency => std::cmp::max(2, minimum_fraction_digits),
-            _ => 3,
-        });
-        let minimum_significant_digits = opts.minimum_significant_digits.unwrap_or(1);
-        let maximum_significant_digits = opts.maximum_significant_digits.unwrap_or(21);
+        let digits = digits_from(opts);
 
         // TODO: add skeleton items for min and max integer, fraction and significant digits.
-        skel.push(integer_digits(minimum_integer_digits as usize));
+        skel.push(integer_digits(digits.minimum_integer as usize));
         skel.push(fraction_digits(
-            minimum_fraction_digits as usize,
-            maximum_fraction_digits as usize,
-            minimum_significant_digits as usize,
-            maximum_significant_digits as usize,
+            digits.minimum_fraction as usize,
+            digits.maximum_fraction as usize,
+            digits.minimum_significant as usize,
+            digits.maximum_significant as usize,
         ));
 
         Ok(skel.iter().map(|s| format!("{} ", s)).collect())
@@ -228,7 +290,45 @@ impl ecma402_traits::numberformat::NumberFormat for NumberFormat {
         let locale = format!("{}", l);
         let skeleton: String = internal::skeleton_from(&opts)?;
         let rep = unumf::UNumberFormatter::try_new(&skeleton, &locale)?;
-        Ok(NumberFormat { rep })
+        let locale = uloc::ULoc::try_from(&locale[..])?;
+        Ok(NumberFormat { rep, locale, opts })
+    }
+
+    /// Creates a new [NumberFormat] for the locale that [crate::negotiation::resolve_locale] picks
+    /// from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: numberformat::Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_number_locales()?,
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [NumberFormat] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_number_locales()?,
+            requested,
+            &opts,
+        )
     }
 
     /// Formats the plural class of `number` into the supplied `writer`.
@@ -245,6 +345,49 @@ impl ecma402_traits::numberformat::NumberFormat for NumberFormat {
         let result_str: String = result.try_into().map_err(|e: common::Error| e.into())?;
         write!(writer, "{}", result_str)
     }
+
+    /// Formats `number` into typed parts, from the field positions of the formatted result.
+    fn format_to_parts(&self, number: f64) -> Result<Vec<parts::Part>, Self::Error> {
+        let result = self.rep.format_double(number)?;
+        let fields: Vec<_> = result
+            .try_field_iter()?
+            .map(|f| {
+                let range = f.begin_index as usize..f.past_end_index as usize;
+                (internal::to_part_type(f.field_type, number), range)
+            })
+            .collect();
+        let text: ustring::UChar = result.try_into()?;
+        Ok(crate::parts::from_fields(&text, &fields))
+    }
+
+    /// Returns the resolved options.  The number formatter does not report its locale, so the
+    /// locale is that of the locale data that the number symbols come from.
+    fn resolved_options(&self) -> Result<numberformat::ResolvedOptions, Self::Error> {
+        let locale = crate::resolved::data_locale(crate::resolved::MAIN_TREE, &self.locale)?;
+        let numbering_system = match self.opts.numbering_system {
+            Some(ref n) => n.clone(),
+            None => crate::resolved::numbering_system(&self.locale)?.as_str().into(),
+        };
+        let digits = internal::digits_from(&self.opts);
+        let opts = self.opts.clone();
+        Ok(numberformat::ResolvedOptions {
+            locale,
+            numbering_system,
+            style: opts.style,
+            currency: opts.currency,
+            currency_display: opts.currency_display,
+            currency_sign: opts.currency_sign,
+            unit: opts.unit,
+            notation: opts.notation,
+            compact_display: opts.compact_display,
+            sign_display: opts.sign_display,
+            minimum_integer_digits: digits.minimum_integer,
+            minimum_fraction_digits: digits.minimum_fraction,
+            maximum_fraction_digits: digits.maximum_fraction,
+            minimum_significant_digits: digits.minimum_significant,
+            maximum_significant_digits: digits.maximum_significant,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -343,4 +486,132 @@ mod testing {
             );
         }
     }
+
+    #[test]
+    fn formatting_to_parts() {
+        use ecma402_traits::parts::{Part, PartType::*};
+        #[derive(Debug, Clone)]
+        struct TestCase {
+            locale: &'static str,
+            opts: numberformat::Options,
+            number: f64,
+            expected: Vec<Part>,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "de-DE",
+                opts: numberformat::Options {
+                    style: numberformat::options::Style::Currency,
+                    currency: Some("EUR".into()),
+                    ..Default::default()
+                },
+                number: -1234.5,
+                expected: vec![
+                    Part::new(MinusSign, "-"),
+                    Part::new(Integer, "1"),
+                    Part::new(Group, "."),
+                    Part::new(Integer, "234"),
+                    Part::new(Decimal, ","),
+                    Part::new(Fraction, "50"),
+                    Part::new(Literal, "\u{a0}"),
+                    Part::new(Currency, "€"),
+                ],
+            },
+            TestCase {
+                locale: "en-US",
+                opts: numberformat::Options {
+                    style: numberformat::options::Style::Percent,
+                    ..Default::default()
+                },
+                // The skeleton does not scale percentages, so this is 25 rather than 0.25.
+                number: 25.0,
+                expected: vec![Part::new(Integer, "25"), Part::new(PercentSign, "%")],
+            },
+            TestCase {
+                locale: "en-US",
+                opts: numberformat::Options {
+                    notation: numberformat::options::Notation::Scientific,
+                    ..Default::default()
+                },
+                number: 0.00123,
+                expected: vec![
+                    Part::new(Integer, "1"),
+                    Part::new(Decimal, "."),
+                    Part::new(Fraction, "23"),
+                    Part::new(ExponentSeparator, "E"),
+                    Part::new(ExponentMinusSign, "-"),
+                    Part::new(ExponentInteger, "3"),
+                ],
+            },
+            TestCase {
+                locale: "en-US",
+                opts: Default::default(),
+                number: f64::INFINITY,
+                expected: vec![Part::new(Infinity, "∞")],
+            },
+        ];
+        for test in tests {
+            let locale = crate::Locale::FromULoc(uloc::ULoc::try_from(test.locale).unwrap());
+            let format =
+                crate::numberformat::NumberFormat::try_new(locale, test.clone().opts).unwrap();
+            let actual = format
+                .format_to_parts(test.number)
+                .unwrap_or_else(|e| panic!("formatting succeeded for: {:?}: {:?}", &test, e));
+            assert_eq!(test.expected, actual, "for test case: {:?}", &test);
+        }
+    }
+
+    #[test]
+    fn resolved_options() {
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("de-CH-u-nu-arab").unwrap());
+        let opts = numberformat::Options {
+            style: numberformat::options::Style::Currency,
+            currency: Some("CHF".into()),
+            ..Default::default()
+        };
+        let format = crate::numberformat::NumberFormat::try_new(locale, opts).unwrap();
+        let resolved = format.resolved_options().unwrap();
+        assert_eq!("de-CH", resolved.locale);
+        assert_eq!(numberformat::options::NumberingSystem::from("arab"), resolved.numbering_system);
+        assert_eq!(Some("CHF".into()), resolved.currency);
+        assert_eq!(1, resolved.minimum_integer_digits);
+        assert_eq!(2, resolved.minimum_fraction_digits);
+        assert_eq!(2, resolved.maximum_fraction_digits);
+
+        // There is no data for German in Canada, so it falls back to plain German.
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("de-CA").unwrap());
+        let format =
+            crate::numberformat::NumberFormat::try_new(locale, Default::default()).unwrap();
+        let resolved = format.resolved_options().unwrap();
+        assert_eq!("de", resolved.locale);
+        assert_eq!(numberformat::options::NumberingSystem::from("latn"), resolved.numbering_system);
+        assert_eq!(3, resolved.maximum_fraction_digits);
+    }
+
+    #[test]
+    fn locale_negotiation() {
+        use ecma402_traits::negotiation;
+        let requested = || {
+            ["tlh", "de-CH", "fr"]
+                .iter()
+                .map(|l| crate::Locale::FromULoc(uloc::ULoc::for_language_tag(l).unwrap()))
+        };
+        let lookup = negotiation::Options {
+            locale_matcher: negotiation::options::LocaleMatcher::Lookup,
+        };
+        assert_eq!(
+            vec!["de-CH", "fr"],
+            crate::numberformat::NumberFormat::supported_locales_of(requested(), lookup.clone())
+                .unwrap()
+        );
+        let format = crate::numberformat::NumberFormat::try_new_with_locales(
+            requested(),
+            lookup,
+            Default::default(),
+        )
+        .unwrap();
+        let mut result = String::new();
+        format.format(1234.5, &mut result).unwrap();
+        assert_eq!("1’234.5", result);
+    }
 }
@@ -0,0 +1,153 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builds the [ecma402_traits::parts] of formatted strings from the fields that ICU reports.
+
+use ecma402_traits::parts::{Part, PartType};
+use rust_icu_ustring as ustring;
+use std::ops::Range;
+use std::slice;
+
+/// Splits `text` into parts, given the typed `fields` that ICU reported for it.  The field
+/// ranges are in UTF-16 code units.
+///
+/// ICU fields may nest: the grouping separators of a number are within its integer field, for
+/// example.  Each code unit belongs to the innermost field that covers it, and consecutive
+/// code units of the same field make up one part.  The text that no field covers is literal.
+/// Unpaired surrogates in `text` are replaced by U+FFFD, as parts are Rust strings.
+pub(crate) fn from_fields(text: &ustring::UChar, fields: &[(PartType, Range<usize>)]) -> Vec<Part> {
+    // Unsafety note: `text` owns `text.len()` initialized code units.
+    let units = unsafe { slice::from_raw_parts(text.as_c_ptr(), text.len()) };
+
+    // The positions at which the innermost field may change.
+    let mut boundaries: Vec<usize> = fields
+        .iter()
+        .flat_map(|(_, range)| vec![range.start, range.end])
+        .chain(vec![0, units.len()])
+        .filter(|&boundary| boundary <= units.len())
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    let mut by_start: Vec<usize> = (0..fields.len()).collect();
+    by_start.sort_by_key(|&index| fields[index].1.start);
+
+    // Sweeps the boundaries, tracking the fields that cover the code units between each two of
+    // them, and groups the code units by their innermost field.
+    let mut runs: Vec<(Option<usize>, Range<usize>)> = vec![];
+    let mut active: Vec<usize> = vec![];
+    let mut next = 0;
+    for window in boundaries.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        active.retain(|&index| fields[index].1.end > start);
+        while next < by_start.len() && fields[by_start[next]].1.start <= start {
+            if fields[by_start[next]].1.end > start {
+                active.push(by_start[next]);
+            }
+            next += 1;
+        }
+        let innermost = active
+            .iter()
+            .copied()
+            .min_by_key(|&index| (fields[index].1.len(), index));
+        match runs.last_mut() {
+            Some((field, range)) if *field == innermost => range.end = end,
+            _ => runs.push((innermost, start..end)),
+        }
+    }
+
+    let mut parts: Vec<Part> = vec![];
+    for (field, range) in runs {
+        let part_type = field
+            .map(|index| fields[index].0)
+            .unwrap_or(PartType::Literal);
+        let value = String::from_utf16_lossy(&units[range]);
+        match parts.last_mut() {
+            // Fields that map to literals merge with the literal text next to them.
+            Some(last) if last.part_type == PartType::Literal && part_type == PartType::Literal => {
+                last.value.push_str(&value)
+            }
+            _ => parts.push(Part::new(part_type, value)),
+        }
+    }
+    parts
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use rust_icu_common as common;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn nested_and_uncovered_fields() -> Result<(), common::Error> {
+        let text = ustring::UChar::try_from("-1,234 €")?;
+        let fields = vec![
+            (PartType::MinusSign, 0..1),
+            (PartType::Integer, 1..6),
+            (PartType::Group, 2..3),
+            (PartType::Currency, 7..8),
+        ];
+        assert_eq!(
+            vec![
+                Part::new(PartType::MinusSign, "-"),
+                Part::new(PartType::Integer, "1"),
+                Part::new(PartType::Group, ","),
+                Part::new(PartType::Integer, "234"),
+                Part::new(PartType::Literal, " "),
+                Part::new(PartType::Currency, "€"),
+            ],
+            from_fields(&text, &fields)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn adjacent_fields_of_the_same_type() -> Result<(), common::Error> {
+        let text = ustring::UChar::try_from("ab, c")?;
+        let fields = vec![
+            (PartType::Element, 0..1),
+            (PartType::Element, 1..2),
+            (PartType::Literal, 2..3),
+            (PartType::Element, 4..5),
+        ];
+        assert_eq!(
+            vec![
+                Part::new(PartType::Element, "a"),
+                Part::new(PartType::Element, "b"),
+                Part::new(PartType::Literal, ", "),
+                Part::new(PartType::Element, "c"),
+            ],
+            from_fields(&text, &fields)
+        );
+        assert_eq!(
+            Vec::<Part>::new(),
+            from_fields(&ustring::UChar::try_from("")?, &[])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unpaired_surrogates() {
+        let text = ustring::UChar::from(vec![0x31, 0xd800, 0x32]);
+        let fields = vec![(PartType::Integer, 0..1), (PartType::Integer, 2..3)];
+        assert_eq!(
+            vec![
+                Part::new(PartType::Integer, "1"),
+                Part::new(PartType::Literal, "\u{fffd}"),
+                Part::new(PartType::Integer, "2"),
+            ],
+            from_fields(&text, &fields)
+        );
+    }
+}
@@ -16,7 +16,9 @@
 
 use ecma402_traits;
 use rust_icu_common as common;
+use rust_icu_uloc as uloc;
 use rust_icu_upluralrules as uplr;
+use std::convert::TryFrom;
 use std::fmt;
 
 /// Implements [ecma402_traits::pluralrules::PluralRules] using ICU as the underlying
@@ -24,6 +26,9 @@ use std::fmt;
 pub struct PluralRules {
     // The internal representation of rules.
     rep: uplr::UPluralRules,
+    // Kept for reporting the resolved options.
+    locale: uloc::ULoc,
+    opts: ecma402_traits::pluralrules::Options,
 }
 
 pub(crate) mod internal {
@@ -37,6 +42,15 @@ pub(crate) mod internal {
             options::Type::Cardinal => usys::UPluralType::UPLURAL_TYPE_CARDINAL,
         }
     }
+
+    // The plural categories in the order that ECMA 402 lists them.  Any other category, which
+    // CLDR does not have today, goes last.
+    pub fn category_rank(category: &str) -> usize {
+        ["zero", "one", "two", "few", "many", "other"]
+            .iter()
+            .position(|c| *c == category)
+            .unwrap_or(usize::MAX)
+    }
 }
 
 impl ecma402_traits::pluralrules::PluralRules for PluralRules {
@@ -56,7 +70,45 @@ impl ecma402_traits::pluralrules::PluralRules for PluralRules {
         let locale = format!("{}", l);
         let style_type = internal::to_icu_type(&opts.in_type);
         let rep = uplr::UPluralRules::try_new_styled(&locale, style_type)?;
-        Ok(PluralRules { rep })
+        let locale = uloc::ULoc::try_from(&locale[..])?;
+        Ok(PluralRules { rep, locale, opts })
+    }
+
+    /// Creates a new [PluralRules] for the locale that [crate::negotiation::resolve_locale] picks
+    /// from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: ecma402_traits::pluralrules::Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_locales(),
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [PluralRules] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_locales(),
+            requested,
+            &opts,
+        )
     }
 
     /// Formats the plural class of `number` into the supplied `writer`.
@@ -72,6 +124,29 @@ impl ecma402_traits::pluralrules::PluralRules for PluralRules {
         let result = self.rep.select(number).map_err(|e| e.into())?;
         write!(writer, "{}", result)
     }
+
+    /// Returns the resolved options.  The locale is the one that the rules are listed for in
+    /// the plural rules data, and the digit options are reported as they were given.
+    fn resolved_options(
+        &self,
+    ) -> Result<ecma402_traits::pluralrules::ResolvedOptions, Self::Error> {
+        let mut plural_categories = self
+            .rep
+            .get_keywords()?
+            .collect::<Result<Vec<String>, common::Error>>()?;
+        plural_categories.sort_by_key(|c| internal::category_rank(c));
+        let opts = self.opts.clone();
+        Ok(ecma402_traits::pluralrules::ResolvedOptions {
+            locale: crate::resolved::plural_rules_locale(&self.locale)?,
+            in_type: opts.in_type,
+            minimum_integer_digits: opts.minimum_integer_digits,
+            minimum_fraction_digits: opts.minimum_fraction_digits,
+            maximum_fraction_digits: opts.maximum_fraction_digits,
+            minimum_significant_digits: opts.minimum_significant_digits,
+            maximum_significant_digits: opts.maximum_significant_digits,
+            plural_categories,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -140,4 +215,30 @@ mod testing {
         }
         Ok(())
     }
+
+    #[test]
+    fn resolved_options() -> Result<(), common::Error> {
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("sr_RS")?);
+        let plr = super::PluralRules::try_new(locale, Default::default())?;
+        let resolved = plr.resolved_options()?;
+        // Serbian has the same plural rules in every region.
+        assert_eq!("sr", resolved.locale);
+        assert_eq!(pluralrules::options::Type::Cardinal, resolved.in_type);
+        assert_eq!(3, resolved.maximum_fraction_digits);
+        assert_eq!(vec!["one", "few", "other"], resolved.plural_categories);
+
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("en-US")?);
+        let opts = pluralrules::Options {
+            in_type: pluralrules::options::Type::Ordinal,
+            ..Default::default()
+        };
+        let plr = super::PluralRules::try_new(locale, opts)?;
+        let resolved = plr.resolved_options()?;
+        assert_eq!(pluralrules::options::Type::Ordinal, resolved.in_type);
+        assert_eq!(
+            vec!["one", "two", "few", "other"],
+            resolved.plural_categories
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,346 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implements the traits found in [ecma402_traits::relativetime].
+
+use ecma402_traits;
+use ecma402_traits::parts;
+use ecma402_traits::relativetime;
+use ecma402_traits::relativetime::options;
+use rust_icu_common as common;
+use rust_icu_sys as usys;
+use rust_icu_uloc as uloc;
+use rust_icu_ureldatefmt as ureldatefmt;
+use rust_icu_ustring as ustring;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Implements [relativetime::RelativeTimeFormat] using ICU as the underlying implementing
+/// library.
+#[derive(Debug)]
+pub struct RelativeTimeFormat {
+    rep: ureldatefmt::URelativeDateTimeFormatter,
+    numeric: options::Numeric,
+    // Kept for reporting the resolved options.
+    locale: uloc::ULoc,
+    style: options::Style,
+}
+
+pub(crate) mod internal {
+    use ecma402_traits::relativetime::options;
+    use rust_icu_sys as usys;
+
+    // Converts the Style param from ECMA-402 into ICU.
+    pub fn to_icu_width(style: &options::Style) -> usys::UDateRelativeDateTimeFormatterStyle {
+        use options::Style;
+        use usys::UDateRelativeDateTimeFormatterStyle;
+        match style {
+            Style::Long => UDateRelativeDateTimeFormatterStyle::UDAT_STYLE_LONG,
+            Style::Short => UDateRelativeDateTimeFormatterStyle::UDAT_STYLE_SHORT,
+            Style::Narrow => UDateRelativeDateTimeFormatterStyle::UDAT_STYLE_NARROW,
+        }
+    }
+}
+
+impl RelativeTimeFormat {
+    /// Formats `value` of the given `unit` into the supplied writer, for example -3 hours
+    /// as "3 hours ago".
+    ///
+    /// A positive value means the future.  A negative value means the past.
+    pub fn format_unit<W>(
+        &self,
+        value: f64,
+        unit: usys::URelativeDateTimeUnit,
+        writer: &mut W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        let result = match self.numeric {
+            options::Numeric::Always => self.rep.format_numeric(value, unit),
+            options::Numeric::Auto => self.rep.format(value, unit),
+        }
+        .map_err(|e| e.into())?;
+        write!(writer, "{}", result)
+    }
+
+    /// Formats `value` of the given `unit` into typed parts, for example -3 hours as the
+    /// integer "3" and the literal " hours ago".
+    pub fn format_unit_to_parts(
+        &self,
+        value: f64,
+        unit: usys::URelativeDateTimeUnit,
+    ) -> Result<Vec<parts::Part>, common::Error> {
+        let result = match self.numeric {
+            options::Numeric::Always => self.rep.format_numeric_to_result(value, unit),
+            options::Numeric::Auto => self.rep.format_to_result(value, unit),
+        }?;
+        let formatted = result.as_value()?;
+        let fields: Vec<_> = formatted
+            .fields_in(usys::UFieldCategory::UFIELD_CATEGORY_NUMBER)?
+            .into_iter()
+            .map(|f| {
+                let part_type = crate::numberformat::internal::to_part_type(f.field, value);
+                (part_type, f.range)
+            })
+            .collect();
+        let text = ustring::UChar::try_from(&formatted)?;
+        Ok(crate::parts::from_fields(&text, &fields))
+    }
+}
+
+impl relativetime::RelativeTimeFormat for RelativeTimeFormat {
+    type Error = common::Error;
+
+    /// Creates a new [RelativeTimeFormat], from a [ecma402_traits::Locale] and
+    /// [relativetime::Options].
+    fn try_new<L>(l: L, opts: relativetime::Options) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        Self: Sized,
+    {
+        let locale = format!("{}", l);
+        let rep = ureldatefmt::URelativeDateTimeFormatter::try_new(
+            &locale,
+            internal::to_icu_width(&opts.style),
+            usys::UDisplayContext::UDISPCTX_CAPITALIZATION_NONE,
+        )?;
+        Ok(RelativeTimeFormat {
+            rep,
+            numeric: opts.numeric,
+            locale: uloc::ULoc::try_from(&locale[..])?,
+            style: opts.style,
+        })
+    }
+
+    /// Creates a new [RelativeTimeFormat] for the locale that [crate::negotiation::resolve_locale] picks
+    /// from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: relativetime::Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_number_locales()?,
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [RelativeTimeFormat] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_number_locales()?,
+            requested,
+            &opts,
+        )
+    }
+
+    /// Formats `days` into the supplied writer.
+    fn format<W>(&self, days: i32, writer: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        self.format_unit(
+            days.into(),
+            usys::URelativeDateTimeUnit::UDAT_REL_UNIT_DAY,
+            writer,
+        )
+    }
+
+    /// Formats `days` into typed parts.
+    fn format_to_parts(&self, days: i32) -> Result<Vec<parts::Part>, Self::Error> {
+        self.format_unit_to_parts(days.into(), usys::URelativeDateTimeUnit::UDAT_REL_UNIT_DAY)
+    }
+
+    /// Returns the resolved options.  The locale is that of the locale data bundle that the
+    /// relative time patterns come from.
+    fn resolved_options(&self) -> Result<relativetime::ResolvedOptions, Self::Error> {
+        Ok(relativetime::ResolvedOptions {
+            locale: crate::resolved::data_locale(crate::resolved::MAIN_TREE, &self.locale)?,
+            style: self.style.clone(),
+            numeric: self.numeric.clone(),
+            numbering_system: crate::resolved::numbering_system(&self.locale)?
+                .as_str()
+                .into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use ecma402_traits::relativetime::RelativeTimeFormat;
+    use rust_icu_uloc as uloc;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_formatting_table() {
+        use options::{Numeric, Style};
+        #[derive(Debug)]
+        struct TestCase {
+            locale: &'static str,
+            numeric: Numeric,
+            style: Style,
+            days: Vec<i32>,
+            expected: Vec<&'static str>,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "en-US",
+                numeric: Numeric::Auto,
+                style: Style::Long,
+                days: vec![-2, -1, 0, 1, 2],
+                expected: vec!["2 days ago", "yesterday", "today", "tomorrow", "in 2 days"],
+            },
+            TestCase {
+                locale: "en-US",
+                numeric: Numeric::Always,
+                style: Style::Long,
+                days: vec![-1, 0, 1],
+                expected: vec!["1 day ago", "in 0 days", "in 1 day"],
+            },
+            TestCase {
+                locale: "en-US",
+                numeric: Numeric::Always,
+                style: Style::Short,
+                days: vec![-3, 3],
+                expected: vec!["3 days ago", "in 3 days"],
+            },
+            TestCase {
+                locale: "de-DE",
+                numeric: Numeric::Auto,
+                style: Style::Long,
+                days: vec![-2, -1, 1, 2, 3],
+                expected: vec![
+                    "vorgestern",
+                    "gestern",
+                    "morgen",
+                    "übermorgen",
+                    "in 3 Tagen",
+                ],
+            },
+            TestCase {
+                locale: "sr-RS",
+                numeric: Numeric::Always,
+                style: Style::Long,
+                days: vec![-1, 5],
+                expected: vec!["пре 1 дана", "за 5 дана"],
+            },
+        ];
+        for test in tests {
+            let locale =
+                crate::Locale::FromULoc(uloc::ULoc::try_from(test.locale).expect("locale exists"));
+            let opts = relativetime::Options {
+                numeric: test.numeric.clone(),
+                style: test.style.clone(),
+            };
+            let formatter =
+                super::RelativeTimeFormat::try_new(locale, opts).expect("has relative format");
+            let actual: Vec<String> = test
+                .days
+                .iter()
+                .map(|days| {
+                    let mut result = String::new();
+                    formatter
+                        .format(*days, &mut result)
+                        .expect("formatting worked");
+                    result
+                })
+                .collect();
+            assert_eq!(test.expected, actual, "for test case: {:?}", &test);
+        }
+    }
+
+    #[test]
+    fn test_format_unit() {
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("en-US").unwrap());
+        let formatter =
+            super::RelativeTimeFormat::try_new(locale, relativetime::Options::default()).unwrap();
+        let mut result = String::new();
+        formatter
+            .format_unit(
+                -3.0,
+                usys::URelativeDateTimeUnit::UDAT_REL_UNIT_HOUR,
+                &mut result,
+            )
+            .unwrap();
+        assert_eq!("3 hours ago", result);
+    }
+
+    #[test]
+    fn test_format_to_parts() {
+        use ecma402_traits::parts::{Part, PartType::*};
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("en-US").unwrap());
+        let formatter =
+            super::RelativeTimeFormat::try_new(locale, relativetime::Options::default()).unwrap();
+        assert_eq!(
+            vec![
+                Part::new(Literal, "in "),
+                Part::new(Integer, "3"),
+                Part::new(Literal, " days")
+            ],
+            formatter.format_to_parts(3).unwrap()
+        );
+        assert_eq!(
+            vec![Part::new(Literal, "tomorrow")],
+            formatter.format_to_parts(1).unwrap()
+        );
+        assert_eq!(
+            vec![
+                Part::new(Integer, "1"),
+                Part::new(Group, ","),
+                Part::new(Integer, "000"),
+                Part::new(Literal, " hours ago"),
+            ],
+            formatter
+                .format_unit_to_parts(-1000.0, usys::URelativeDateTimeUnit::UDAT_REL_UNIT_HOUR)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resolved_options() {
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from("ar-EG").unwrap());
+        let opts = relativetime::Options {
+            numeric: options::Numeric::Always,
+            style: options::Style::Narrow,
+        };
+        let formatter = super::RelativeTimeFormat::try_new(locale, opts).unwrap();
+        assert_eq!(
+            relativetime::ResolvedOptions {
+                locale: "ar-EG".into(),
+                style: options::Style::Narrow,
+                numeric: options::Numeric::Always,
+                // Egyptian Arabic uses the Arabic-Indic digits.
+                numbering_system: "arab".into(),
+            },
+            formatter.resolved_options().unwrap()
+        );
+    }
+}
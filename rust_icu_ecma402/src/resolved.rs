@@ -0,0 +1,99 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Finds the locales and the locale defaults that the `resolved_options` functions report.
+
+use rust_icu_common as common;
+use rust_icu_sys as usys;
+use rust_icu_uloc as uloc;
+use rust_icu_unumsys as unumsys;
+use rust_icu_ures as ures;
+use std::convert::TryFrom;
+
+/// Converts the locale that an ICU service reports through `get_locale_by_type` into a BCP 47
+/// language tag.  The keywords are dropped, since the resolved options report them separately.
+pub(crate) fn to_language_tag(icu_locale: &str) -> Result<String, common::Error> {
+    uloc::ULoc::try_from(icu_locale)?
+        .base_name()
+        .to_language_tag(false)
+}
+
+/// The ICU data tree of the number, list and relative time formats.
+pub(crate) const MAIN_TREE: Option<&str> = None;
+/// The ICU data tree of the language and script names.
+pub(crate) const LANGUAGE_TREE: Option<&str> = Some("ICUDATA-lang");
+/// The ICU data tree of the region names.
+pub(crate) const REGION_TREE: Option<&str> = Some("ICUDATA-region");
+/// The ICU data tree of the currency names.
+pub(crate) const CURRENCY_TREE: Option<&str> = Some("ICUDATA-curr");
+
+/// Returns the most specific of `locale` and its fallbacks that the ICU data tree `tree` has a
+/// bundle for, as a BCP 47 language tag.
+///
+/// This is for the services that do not report their own locale.  They load their data from
+/// the bundle of `tree` that this opens, so its locale is theirs.
+pub(crate) fn data_locale(
+    tree: Option<&str>,
+    locale: &uloc::ULoc,
+) -> Result<String, common::Error> {
+    let bundle = ures::UResourceBundle::try_new(tree, locale.clone().base_name().label())?;
+    to_language_tag(&bundle.get_locale_by_type(usys::ULocDataLocaleType::ULOC_ACTUAL_LOCALE)?)
+}
+
+/// Returns the most specific of `locale` and its parents that has plural rules, as a BCP 47
+/// language tag.  The plural rules are not in a locale tree, but in one table that maps
+/// locales to rule sets, which is searched the same way as ICU's plural rules do.
+pub(crate) fn plural_rules_locale(locale: &uloc::ULoc) -> Result<String, common::Error> {
+    let locales = ures::UResourceBundle::try_new_direct(None, "plurals")?.get_by_key("locales")?;
+    let mut name = locale.clone().base_name().label().to_string();
+    while !name.is_empty() {
+        if locales.get_string_by_key(&name).is_ok() {
+            return to_language_tag(&name);
+        }
+        name.truncate(name.rfind('_').unwrap_or(0));
+    }
+    Ok("und".to_string())
+}
+
+/// Returns the name of the numbering system that `locale` selects, such as "latn".
+pub(crate) fn numbering_system(locale: &uloc::ULoc) -> Result<String, common::Error> {
+    Ok(unumsys::UNumberingSystem::try_new(locale)?.name())
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn locales() -> Result<(), common::Error> {
+        assert_eq!("sv", to_language_tag("sv@collation=search")?);
+        assert_eq!("und", to_language_tag("root")?);
+        let locale = uloc::ULoc::try_from("de-AT-u-nu-arab")?;
+        assert_eq!("de-AT", data_locale(MAIN_TREE, &locale)?);
+        assert_eq!("arab", numbering_system(&locale)?);
+        // There is no data for German in Canada.
+        let locale = uloc::ULoc::try_from("de-CA")?;
+        assert_eq!("de", data_locale(MAIN_TREE, &locale)?);
+        assert_eq!("de", data_locale(LANGUAGE_TREE, &locale)?);
+        assert_eq!("de", data_locale(REGION_TREE, &locale)?);
+        assert_eq!("de", data_locale(CURRENCY_TREE, &locale)?);
+        // German plural rules are the same everywhere.
+        assert_eq!("de", plural_rules_locale(&uloc::ULoc::try_from("de-AT")?)?);
+        assert_eq!(
+            "pt-PT",
+            plural_rules_locale(&uloc::ULoc::try_from("pt-PT")?)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implements the traits found in [ecma402_traits::segmenter].
+
+use ecma402_traits;
+use ecma402_traits::segmenter::{options, Segment};
+use rust_icu_common as common;
+use rust_icu_sys as usys;
+use rust_icu_ubrk as ubrk;
+use rust_icu_uloc as uloc;
+use rust_icu_ustring as ustring;
+use std::convert::TryFrom;
+
+/// Implements [ecma402_traits::segmenter::Segmenter] using ICU as the underlying
+/// implementing library.
+pub struct Segmenter {
+    // A break iterator over the empty text, which is cloned for each text to segment.
+    rep: ubrk::UBreakIterator,
+    granularity: options::Granularity,
+}
+
+/// Implements [ecma402_traits::segmenter::Segments] using ICU as the underlying implementing
+/// library.
+pub struct Segments<'a> {
+    input: &'a str,
+    // Finds the boundaries of the segments that the iteration yields.
+    iter: ubrk::UBreakIterator,
+    // Finds the boundaries of the segments that `containing` returns, so that the lookups do
+    // not move `iter`.
+    lookup: ubrk::UBreakIterator,
+    // The byte index in `input` of each UTF-16 code unit of the text that ICU segments, and of
+    // the end of the text.
+    offsets: Vec<usize>,
+    // The UTF-16 index of the start of the next segment that the iteration yields.
+    start: i32,
+    // Whether the segments report being word-like.
+    words: bool,
+}
+
+pub(crate) mod internal {
+    use ecma402_traits::segmenter::options;
+    use rust_icu_sys as usys;
+
+    // Converts the trait style granularity to the ICU break iterator type.
+    pub fn to_icu_type(granularity: &options::Granularity) -> usys::UBreakIteratorType {
+        match granularity {
+            options::Granularity::Grapheme => usys::UBreakIteratorType::UBRK_CHARACTER,
+            options::Granularity::Word => usys::UBreakIteratorType::UBRK_WORD,
+            options::Granularity::Sentence => usys::UBreakIteratorType::UBRK_SENTENCE,
+        }
+    }
+
+    // Returns the byte index in `input` of each UTF-16 code unit of `input`, and of the end of
+    // `input`.  Both halves of a surrogate pair map to the start of their character.
+    pub fn utf16_offsets(input: &str) -> Vec<usize> {
+        let mut offsets = Vec::with_capacity(input.len() + 1);
+        for (index, c) in input.char_indices() {
+            for _ in 0..c.len_utf16() {
+                offsets.push(index);
+            }
+        }
+        offsets.push(input.len());
+        offsets
+    }
+
+    // Tells whether the word break rule status `status` is that of a word, number or the like,
+    // rather than of spaces or punctuation.
+    pub fn is_word_like(status: i32) -> bool {
+        status >= usys::UWordBreak::UBRK_WORD_NONE_LIMIT as i32
+    }
+}
+
+impl<'a> Segments<'a> {
+    // Makes the segment between the UTF-16 indexes `start` and `end`, with the rule status of
+    // the boundary at `end`.
+    fn make_segment(&self, start: i32, end: i32, status: i32) -> Segment<'a> {
+        let index = self.offsets[start as usize];
+        Segment {
+            segment: &self.input[index..self.offsets[end as usize]],
+            index,
+            input: self.input,
+            is_word_like: match self.words {
+                true => Some(internal::is_word_like(status)),
+                false => None,
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    /// Returns the next segment of the text.
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.iter.next()?;
+        let start = std::mem::replace(&mut self.start, end);
+        Some(self.make_segment(start, end, self.iter.get_rule_status()))
+    }
+}
+
+impl<'a> ecma402_traits::segmenter::Segments<'a> for Segments<'a> {
+    type Error = common::Error;
+
+    /// Returns the segment that contains the byte at `index`.  An `index` within a multi-byte
+    /// character finds the segment of that character.
+    fn containing(&self, index: usize) -> Result<Option<Segment<'a>>, Self::Error> {
+        if index >= self.input.len() {
+            return Ok(None);
+        }
+        let unit = self.offsets.partition_point(|&offset| offset <= index) - 1;
+        let end = self.lookup.following(unit as i32);
+        // The rule status is that of the boundary that `following` found, so it is read before
+        // `preceding` moves the iterator.
+        let status = self.lookup.get_rule_status();
+        let start = self.lookup.preceding(end);
+        Ok(Some(self.make_segment(start, end, status)))
+    }
+}
+
+impl ecma402_traits::segmenter::Segmenter for Segmenter {
+    type Error = common::Error;
+    type Segments<'a> = Segments<'a>;
+
+    /// Creates a new [Segmenter].
+    ///
+    /// The `-u-ss` keyword of the locale, which suppresses sentence breaks after abbreviations,
+    /// is honored.
+    fn try_new<L>(l: L, opts: ecma402_traits::segmenter::Options) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        Self: Sized,
+    {
+        let locale = uloc::ULoc::try_from(&format!("{}", l)[..])?;
+        let empty = ustring::UChar::try_from("")?;
+        let rep = ubrk::UBreakIterator::try_new_ustring(
+            internal::to_icu_type(&opts.granularity),
+            &locale,
+            &empty,
+        )?;
+        Ok(Segmenter {
+            rep,
+            granularity: opts.granularity,
+        })
+    }
+
+    /// Creates a new [Segmenter] for the locale that [crate::negotiation::resolve_locale] picks
+    /// from the `requested` ones.
+    fn try_new_with_locales<L, I>(
+        requested: I,
+        negotiation: ecma402_traits::negotiation::Options,
+        opts: ecma402_traits::segmenter::Options,
+    ) -> Result<Self, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        let locale = crate::negotiation::resolve_locale(
+            &crate::negotiation::available_break_locales(),
+            &crate::negotiation::to_ulocs(requested)?,
+            &negotiation,
+        )?;
+        Self::try_new(crate::Locale::FromULoc(locale), opts)
+    }
+
+    /// Returns those of the `requested` locales that [Segmenter] supports.
+    fn supported_locales_of<L, I>(
+        requested: I,
+        opts: ecma402_traits::negotiation::Options,
+    ) -> Result<Vec<String>, Self::Error>
+    where
+        L: ecma402_traits::Locale,
+        I: IntoIterator<Item = L>,
+        Self: Sized,
+    {
+        crate::negotiation::supported_language_tags(
+            &crate::negotiation::available_break_locales(),
+            requested,
+            &opts,
+        )
+    }
+
+    /// Splits `input` into segments.
+    ///
+    /// ICU segments UTF-16 text, so `input` is converted up front, and the segment boundaries
+    /// are converted back into byte indexes in `input`.
+    ///
+    /// The function implements [`Intl.Segmenter.prototype.segment`][seg] from [ECMA 402][ecma].
+    ///
+    ///    [seg]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/segment
+    ///    [ecma]: https://www.ecma-international.org/publications/standards/Ecma-402.htm
+    fn segment<'a>(&self, input: &'a str) -> Result<Self::Segments<'a>, Self::Error> {
+        let text = ustring::UChar::try_from(input)?;
+        let mut iter = self.rep.safe_clone()?;
+        iter.set_text_ustring(&text)?;
+        let mut lookup = self.rep.safe_clone()?;
+        lookup.set_text_ustring(&text)?;
+        Ok(Segments {
+            input,
+            start: iter.first(),
+            iter,
+            lookup,
+            offsets: internal::utf16_offsets(input),
+            words: self.granularity == options::Granularity::Word,
+        })
+    }
+
+    /// Returns the resolved options.  The locale is that of the break rules that the segmenter
+    /// loaded.
+    fn resolved_options(&self) -> Result<ecma402_traits::segmenter::ResolvedOptions, Self::Error> {
+        let valid = self
+            .rep
+            .get_locale_by_type(usys::ULocDataLocaleType::ULOC_VALID_LOCALE)?;
+        Ok(ecma402_traits::segmenter::ResolvedOptions {
+            locale: crate::resolved::to_language_tag(&valid)?,
+            granularity: self.granularity.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use ecma402_traits::segmenter;
+    use ecma402_traits::segmenter::{Segmenter, Segments};
+
+    fn segmenter(locale: &str, granularity: options::Granularity) -> super::Segmenter {
+        let locale = crate::Locale::FromULoc(uloc::ULoc::try_from(locale).expect("locale"));
+        super::Segmenter::try_new(locale, segmenter::Options { granularity }).expect("segmenter")
+    }
+
+    fn segment(text: &'static str, index: usize, is_word_like: Option<bool>) -> Segment<'static> {
+        Segment {
+            segment: text,
+            index,
+            input: "",
+            is_word_like,
+        }
+    }
+
+    #[test]
+    fn segment_per_granularity() -> Result<(), common::Error> {
+        #[derive(Debug, Clone)]
+        struct TestCase {
+            locale: &'static str,
+            granularity: options::Granularity,
+            input: &'static str,
+            expected: Vec<(&'static str, usize, Option<bool>)>,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "en-US",
+                granularity: options::Granularity::Word,
+                input: "Hello, wörld 42!",
+                expected: vec![
+                    ("Hello", 0, Some(true)),
+                    (",", 5, Some(false)),
+                    (" ", 6, Some(false)),
+                    ("wörld", 7, Some(true)),
+                    (" ", 13, Some(false)),
+                    ("42", 14, Some(true)),
+                    ("!", 16, Some(false)),
+                ],
+            },
+            TestCase {
+                locale: "en-US",
+                granularity: options::Granularity::Grapheme,
+                input: "e\u{301}👍🏽a",
+                expected: vec![("e\u{301}", 0, None), ("👍🏽", 3, None), ("a", 11, None)],
+            },
+            TestCase {
+                locale: "en-US",
+                granularity: options::Granularity::Sentence,
+                input: "Mr. Smith left. He was late!",
+                expected: vec![
+                    ("Mr. ", 0, None),
+                    ("Smith left. ", 4, None),
+                    ("He was late!", 16, None),
+                ],
+            },
+            TestCase {
+                locale: "en-US",
+                granularity: options::Granularity::Word,
+                input: "",
+                expected: vec![],
+            },
+        ];
+        for test in tests {
+            let segmenter = segmenter(test.locale, test.granularity.clone());
+            let actual: Vec<Segment> = segmenter.segment(test.input)?.collect();
+            let expected: Vec<Segment> = test
+                .expected
+                .iter()
+                .map(|&(text, index, is_word_like)| Segment {
+                    input: test.input,
+                    ..segment(text, index, is_word_like)
+                })
+                .collect();
+            assert_eq!(expected, actual, "for test case: {:?}", &test);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn containing() -> Result<(), common::Error> {
+        let segmenter = segmenter("en-US", options::Granularity::Word);
+        let input = "Grüße, Welt";
+        let mut segments = segmenter.segment(input)?;
+        let grusse = Some(Segment {
+            input,
+            ..segment("Grüße", 0, Some(true))
+        });
+        assert_eq!(grusse, segments.containing(0)?);
+        // Byte 3 is within "ü".
+        assert_eq!(grusse, segments.containing(3)?);
+        assert_eq!(
+            Some(Segment {
+                input,
+                ..segment(",", 7, Some(false))
+            }),
+            segments.containing(7)?
+        );
+        assert_eq!(
+            Some(Segment {
+                input,
+                ..segment("Welt", 9, Some(true))
+            }),
+            segments.containing(12)?
+        );
+        assert_eq!(None, segments.containing(input.len())?);
+        // The lookups do not affect the iteration.
+        assert_eq!(grusse, segments.next());
+        Ok(())
+    }
+
+    #[test]
+    fn resolved_options() -> Result<(), common::Error> {
+        let segmenter = segmenter("de-AT", options::Granularity::Sentence);
+        assert_eq!(
+            segmenter::ResolvedOptions {
+                locale: "de".to_owned(),
+                granularity: options::Granularity::Sentence,
+            },
+            segmenter.resolved_options()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn locale_negotiation() -> Result<(), common::Error> {
+        use ecma402_traits::negotiation;
+        let requested = vec!["tlh", "de-AT"]
+            .into_iter()
+            .map(|l| crate::Locale::FromULoc(uloc::ULoc::for_language_tag(l).expect("locale")));
+        assert_eq!(
+            vec!["de-AT"],
+            super::Segmenter::supported_locales_of(requested.clone(), Default::default())?
+        );
+        let segmenter = super::Segmenter::try_new_with_locales(
+            requested,
+            negotiation::Options {
+                locale_matcher: negotiation::options::LocaleMatcher::Lookup,
+            },
+            segmenter::Options {
+                granularity: options::Granularity::Word,
+            },
+        )?;
+        let words: Vec<&str> = segmenter
+            .segment("Guten Tag")?
+            .filter(|s| s.is_word_like == Some(true))
+            .map(|s| s.segment)
+            .collect();
+        assert_eq!(vec!["Guten", "Tag"], words);
+        Ok(())
+    }
+}
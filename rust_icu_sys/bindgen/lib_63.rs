@@ -2619,6 +2619,288 @@ impl Default for UCharIterator {
 }
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum USetSpanCondition {
+    USET_SPAN_NOT_CONTAINED = 0,
+    USET_SPAN_CONTAINED = 1,
+    USET_SPAN_SIMPLE = 2,
+    USET_SPAN_CONDITION_COUNT = 3,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq)]
+pub struct USerializedSet {
+    pub array: *const u16,
+    pub bmpLength: i32,
+    pub length: i32,
+    pub staticArray: [u16; 8usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of USerializedSet"][::std::mem::size_of::<USerializedSet>() - 32usize];
+    ["Alignment of USerializedSet"][::std::mem::align_of::<USerializedSet>() - 8usize];
+    ["Offset of field: USerializedSet::array"]
+        [::std::mem::offset_of!(USerializedSet, array) - 0usize];
+    ["Offset of field: USerializedSet::bmpLength"]
+        [::std::mem::offset_of!(USerializedSet, bmpLength) - 8usize];
+    ["Offset of field: USerializedSet::length"]
+        [::std::mem::offset_of!(USerializedSet, length) - 12usize];
+    ["Offset of field: USerializedSet::staticArray"]
+        [::std::mem::offset_of!(USerializedSet, staticArray) - 16usize];
+};
+impl Default for USerializedSet {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn uset_openEmpty_63() -> *mut USet;
+}
+unsafe extern "C" {
+    pub fn uset_open_63(start: UChar32, end: UChar32) -> *mut USet;
+}
+unsafe extern "C" {
+    pub fn uset_openPattern_63(
+        pattern: *const UChar,
+        patternLength: i32,
+        ec: *mut UErrorCode,
+    ) -> *mut USet;
+}
+unsafe extern "C" {
+    pub fn uset_openPatternOptions_63(
+        pattern: *const UChar,
+        patternLength: i32,
+        options: u32,
+        ec: *mut UErrorCode,
+    ) -> *mut USet;
+}
+unsafe extern "C" {
+    pub fn uset_close_63(set: *mut USet);
+}
+unsafe extern "C" {
+    pub fn uset_clone_63(set: *const USet) -> *mut USet;
+}
+unsafe extern "C" {
+    pub fn uset_isFrozen_63(set: *const USet) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_freeze_63(set: *mut USet);
+}
+unsafe extern "C" {
+    pub fn uset_cloneAsThawed_63(set: *const USet) -> *mut USet;
+}
+unsafe extern "C" {
+    pub fn uset_set_63(set: *mut USet, start: UChar32, end: UChar32);
+}
+unsafe extern "C" {
+    pub fn uset_applyPattern_63(
+        set: *mut USet,
+        pattern: *const UChar,
+        patternLength: i32,
+        options: u32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_applyIntPropertyValue_63(
+        set: *mut USet,
+        prop: UProperty,
+        value: i32,
+        ec: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uset_applyPropertyAlias_63(
+        set: *mut USet,
+        prop: *const UChar,
+        propLength: i32,
+        value: *const UChar,
+        valueLength: i32,
+        ec: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uset_resemblesPattern_63(pattern: *const UChar, patternLength: i32, pos: i32) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_toPattern_63(
+        set: *const USet,
+        result: *mut UChar,
+        resultCapacity: i32,
+        escapeUnprintable: UBool,
+        ec: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_add_63(set: *mut USet, c: UChar32);
+}
+unsafe extern "C" {
+    pub fn uset_addAll_63(set: *mut USet, additionalSet: *const USet);
+}
+unsafe extern "C" {
+    pub fn uset_addRange_63(set: *mut USet, start: UChar32, end: UChar32);
+}
+unsafe extern "C" {
+    pub fn uset_addString_63(set: *mut USet, str: *const UChar, strLen: i32);
+}
+unsafe extern "C" {
+    pub fn uset_addAllCodePoints_63(set: *mut USet, str: *const UChar, strLen: i32);
+}
+unsafe extern "C" {
+    pub fn uset_remove_63(set: *mut USet, c: UChar32);
+}
+unsafe extern "C" {
+    pub fn uset_removeRange_63(set: *mut USet, start: UChar32, end: UChar32);
+}
+unsafe extern "C" {
+    pub fn uset_removeString_63(set: *mut USet, str: *const UChar, strLen: i32);
+}
+unsafe extern "C" {
+    pub fn uset_removeAll_63(set: *mut USet, removeSet: *const USet);
+}
+unsafe extern "C" {
+    pub fn uset_retain_63(set: *mut USet, start: UChar32, end: UChar32);
+}
+unsafe extern "C" {
+    pub fn uset_retainAll_63(set: *mut USet, retain: *const USet);
+}
+unsafe extern "C" {
+    pub fn uset_compact_63(set: *mut USet);
+}
+unsafe extern "C" {
+    pub fn uset_complement_63(set: *mut USet);
+}
+unsafe extern "C" {
+    pub fn uset_complementAll_63(set: *mut USet, complement: *const USet);
+}
+unsafe extern "C" {
+    pub fn uset_clear_63(set: *mut USet);
+}
+unsafe extern "C" {
+    pub fn uset_closeOver_63(set: *mut USet, attributes: i32);
+}
+unsafe extern "C" {
+    pub fn uset_removeAllStrings_63(set: *mut USet);
+}
+unsafe extern "C" {
+    pub fn uset_isEmpty_63(set: *const USet) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_contains_63(set: *const USet, c: UChar32) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_containsRange_63(set: *const USet, start: UChar32, end: UChar32) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_containsString_63(set: *const USet, str: *const UChar, strLen: i32) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_indexOf_63(set: *const USet, c: UChar32) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_charAt_63(set: *const USet, charIndex: i32) -> UChar32;
+}
+unsafe extern "C" {
+    pub fn uset_size_63(set: *const USet) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_getItemCount_63(set: *const USet) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_getItem_63(
+        set: *const USet,
+        itemIndex: i32,
+        start: *mut UChar32,
+        end: *mut UChar32,
+        str: *mut UChar,
+        strCapacity: i32,
+        ec: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_containsAll_63(set1: *const USet, set2: *const USet) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_containsAllCodePoints_63(set: *const USet, str: *const UChar, strLen: i32)
+        -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_containsNone_63(set1: *const USet, set2: *const USet) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_containsSome_63(set1: *const USet, set2: *const USet) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_span_63(
+        set: *const USet,
+        s: *const UChar,
+        length: i32,
+        spanCondition: USetSpanCondition,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_spanBack_63(
+        set: *const USet,
+        s: *const UChar,
+        length: i32,
+        spanCondition: USetSpanCondition,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_spanUTF8_63(
+        set: *const USet,
+        s: *const ::std::os::raw::c_char,
+        length: i32,
+        spanCondition: USetSpanCondition,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_spanBackUTF8_63(
+        set: *const USet,
+        s: *const ::std::os::raw::c_char,
+        length: i32,
+        spanCondition: USetSpanCondition,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_equals_63(set1: *const USet, set2: *const USet) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_serialize_63(
+        set: *const USet,
+        dest: *mut u16,
+        destCapacity: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_getSerializedSet_63(
+        fillSet: *mut USerializedSet,
+        src: *const u16,
+        srcLength: i32,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_setSerializedToOne_63(fillSet: *mut USerializedSet, c: UChar32);
+}
+unsafe extern "C" {
+    pub fn uset_serializedContains_63(set: *const USerializedSet, c: UChar32) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uset_getSerializedRangeCount_63(set: *const USerializedSet) -> i32;
+}
+unsafe extern "C" {
+    pub fn uset_getSerializedRange_63(
+        set: *const USerializedSet,
+        rangeIndex: i32,
+        pStart: *mut UChar32,
+        pEnd: *mut UChar32,
+    ) -> UBool;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
 pub enum UNormalization2Mode {
     UNORM2_COMPOSE = 0,
     UNORM2_DECOMPOSE = 1,
@@ -2773,6 +3055,288 @@ pub enum UNormalizationMode {
     UNORM_FCD = 6,
     UNORM_MODE_COUNT = 7,
 }
+impl UScriptCode {
+    pub const USCRIPT_UCAS: UScriptCode = UScriptCode::USCRIPT_CANADIAN_ABORIGINAL;
+}
+impl UScriptCode {
+    pub const USCRIPT_MANDAEAN: UScriptCode = UScriptCode::USCRIPT_MANDAIC;
+}
+impl UScriptCode {
+    pub const USCRIPT_MEROITIC: UScriptCode = UScriptCode::USCRIPT_MEROITIC_HIEROGLYPHS;
+}
+impl UScriptCode {
+    pub const USCRIPT_PHONETIC_POLLARD: UScriptCode = UScriptCode::USCRIPT_MIAO;
+}
+impl UScriptCode {
+    pub const USCRIPT_DUPLOYAN_SHORTAND: UScriptCode = UScriptCode::USCRIPT_DUPLOYAN;
+}
+impl UScriptCode {
+    pub const USCRIPT_SINDHI: UScriptCode = UScriptCode::USCRIPT_KHUDAWADI;
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UScriptCode {
+    USCRIPT_INVALID_CODE = -1,
+    USCRIPT_COMMON = 0,
+    USCRIPT_INHERITED = 1,
+    USCRIPT_ARABIC = 2,
+    USCRIPT_ARMENIAN = 3,
+    USCRIPT_BENGALI = 4,
+    USCRIPT_BOPOMOFO = 5,
+    USCRIPT_CHEROKEE = 6,
+    USCRIPT_COPTIC = 7,
+    USCRIPT_CYRILLIC = 8,
+    USCRIPT_DESERET = 9,
+    USCRIPT_DEVANAGARI = 10,
+    USCRIPT_ETHIOPIC = 11,
+    USCRIPT_GEORGIAN = 12,
+    USCRIPT_GOTHIC = 13,
+    USCRIPT_GREEK = 14,
+    USCRIPT_GUJARATI = 15,
+    USCRIPT_GURMUKHI = 16,
+    USCRIPT_HAN = 17,
+    USCRIPT_HANGUL = 18,
+    USCRIPT_HEBREW = 19,
+    USCRIPT_HIRAGANA = 20,
+    USCRIPT_KANNADA = 21,
+    USCRIPT_KATAKANA = 22,
+    USCRIPT_KHMER = 23,
+    USCRIPT_LAO = 24,
+    USCRIPT_LATIN = 25,
+    USCRIPT_MALAYALAM = 26,
+    USCRIPT_MONGOLIAN = 27,
+    USCRIPT_MYANMAR = 28,
+    USCRIPT_OGHAM = 29,
+    USCRIPT_OLD_ITALIC = 30,
+    USCRIPT_ORIYA = 31,
+    USCRIPT_RUNIC = 32,
+    USCRIPT_SINHALA = 33,
+    USCRIPT_SYRIAC = 34,
+    USCRIPT_TAMIL = 35,
+    USCRIPT_TELUGU = 36,
+    USCRIPT_THAANA = 37,
+    USCRIPT_THAI = 38,
+    USCRIPT_TIBETAN = 39,
+    USCRIPT_CANADIAN_ABORIGINAL = 40,
+    USCRIPT_YI = 41,
+    USCRIPT_TAGALOG = 42,
+    USCRIPT_HANUNOO = 43,
+    USCRIPT_BUHID = 44,
+    USCRIPT_TAGBANWA = 45,
+    USCRIPT_BRAILLE = 46,
+    USCRIPT_CYPRIOT = 47,
+    USCRIPT_LIMBU = 48,
+    USCRIPT_LINEAR_B = 49,
+    USCRIPT_OSMANYA = 50,
+    USCRIPT_SHAVIAN = 51,
+    USCRIPT_TAI_LE = 52,
+    USCRIPT_UGARITIC = 53,
+    USCRIPT_KATAKANA_OR_HIRAGANA = 54,
+    USCRIPT_BUGINESE = 55,
+    USCRIPT_GLAGOLITIC = 56,
+    USCRIPT_KHAROSHTHI = 57,
+    USCRIPT_SYLOTI_NAGRI = 58,
+    USCRIPT_NEW_TAI_LUE = 59,
+    USCRIPT_TIFINAGH = 60,
+    USCRIPT_OLD_PERSIAN = 61,
+    USCRIPT_BALINESE = 62,
+    USCRIPT_BATAK = 63,
+    USCRIPT_BLISSYMBOLS = 64,
+    USCRIPT_BRAHMI = 65,
+    USCRIPT_CHAM = 66,
+    USCRIPT_CIRTH = 67,
+    USCRIPT_OLD_CHURCH_SLAVONIC_CYRILLIC = 68,
+    USCRIPT_DEMOTIC_EGYPTIAN = 69,
+    USCRIPT_HIERATIC_EGYPTIAN = 70,
+    USCRIPT_EGYPTIAN_HIEROGLYPHS = 71,
+    USCRIPT_KHUTSURI = 72,
+    USCRIPT_SIMPLIFIED_HAN = 73,
+    USCRIPT_TRADITIONAL_HAN = 74,
+    USCRIPT_PAHAWH_HMONG = 75,
+    USCRIPT_OLD_HUNGARIAN = 76,
+    USCRIPT_HARAPPAN_INDUS = 77,
+    USCRIPT_JAVANESE = 78,
+    USCRIPT_KAYAH_LI = 79,
+    USCRIPT_LATIN_FRAKTUR = 80,
+    USCRIPT_LATIN_GAELIC = 81,
+    USCRIPT_LEPCHA = 82,
+    USCRIPT_LINEAR_A = 83,
+    USCRIPT_MANDAIC = 84,
+    USCRIPT_MAYAN_HIEROGLYPHS = 85,
+    USCRIPT_MEROITIC_HIEROGLYPHS = 86,
+    USCRIPT_NKO = 87,
+    USCRIPT_ORKHON = 88,
+    USCRIPT_OLD_PERMIC = 89,
+    USCRIPT_PHAGS_PA = 90,
+    USCRIPT_PHOENICIAN = 91,
+    USCRIPT_MIAO = 92,
+    USCRIPT_RONGORONGO = 93,
+    USCRIPT_SARATI = 94,
+    USCRIPT_ESTRANGELO_SYRIAC = 95,
+    USCRIPT_WESTERN_SYRIAC = 96,
+    USCRIPT_EASTERN_SYRIAC = 97,
+    USCRIPT_TENGWAR = 98,
+    USCRIPT_VAI = 99,
+    USCRIPT_VISIBLE_SPEECH = 100,
+    USCRIPT_CUNEIFORM = 101,
+    USCRIPT_UNWRITTEN_LANGUAGES = 102,
+    USCRIPT_UNKNOWN = 103,
+    USCRIPT_CARIAN = 104,
+    USCRIPT_JAPANESE = 105,
+    USCRIPT_LANNA = 106,
+    USCRIPT_LYCIAN = 107,
+    USCRIPT_LYDIAN = 108,
+    USCRIPT_OL_CHIKI = 109,
+    USCRIPT_REJANG = 110,
+    USCRIPT_SAURASHTRA = 111,
+    USCRIPT_SIGN_WRITING = 112,
+    USCRIPT_SUNDANESE = 113,
+    USCRIPT_MOON = 114,
+    USCRIPT_MEITEI_MAYEK = 115,
+    USCRIPT_IMPERIAL_ARAMAIC = 116,
+    USCRIPT_AVESTAN = 117,
+    USCRIPT_CHAKMA = 118,
+    USCRIPT_KOREAN = 119,
+    USCRIPT_KAITHI = 120,
+    USCRIPT_MANICHAEAN = 121,
+    USCRIPT_INSCRIPTIONAL_PAHLAVI = 122,
+    USCRIPT_PSALTER_PAHLAVI = 123,
+    USCRIPT_BOOK_PAHLAVI = 124,
+    USCRIPT_INSCRIPTIONAL_PARTHIAN = 125,
+    USCRIPT_SAMARITAN = 126,
+    USCRIPT_TAI_VIET = 127,
+    USCRIPT_MATHEMATICAL_NOTATION = 128,
+    USCRIPT_SYMBOLS = 129,
+    USCRIPT_BAMUM = 130,
+    USCRIPT_LISU = 131,
+    USCRIPT_NAKHI_GEBA = 132,
+    USCRIPT_OLD_SOUTH_ARABIAN = 133,
+    USCRIPT_BASSA_VAH = 134,
+    USCRIPT_DUPLOYAN = 135,
+    USCRIPT_ELBASAN = 136,
+    USCRIPT_GRANTHA = 137,
+    USCRIPT_KPELLE = 138,
+    USCRIPT_LOMA = 139,
+    USCRIPT_MENDE = 140,
+    USCRIPT_MEROITIC_CURSIVE = 141,
+    USCRIPT_OLD_NORTH_ARABIAN = 142,
+    USCRIPT_NABATAEAN = 143,
+    USCRIPT_PALMYRENE = 144,
+    USCRIPT_KHUDAWADI = 145,
+    USCRIPT_WARANG_CITI = 146,
+    USCRIPT_AFAKA = 147,
+    USCRIPT_JURCHEN = 148,
+    USCRIPT_MRO = 149,
+    USCRIPT_NUSHU = 150,
+    USCRIPT_SHARADA = 151,
+    USCRIPT_SORA_SOMPENG = 152,
+    USCRIPT_TAKRI = 153,
+    USCRIPT_TANGUT = 154,
+    USCRIPT_WOLEAI = 155,
+    USCRIPT_ANATOLIAN_HIEROGLYPHS = 156,
+    USCRIPT_KHOJKI = 157,
+    USCRIPT_TIRHUTA = 158,
+    USCRIPT_CAUCASIAN_ALBANIAN = 159,
+    USCRIPT_MAHAJANI = 160,
+    USCRIPT_AHOM = 161,
+    USCRIPT_HATRAN = 162,
+    USCRIPT_MODI = 163,
+    USCRIPT_MULTANI = 164,
+    USCRIPT_PAU_CIN_HAU = 165,
+    USCRIPT_SIDDHAM = 166,
+    USCRIPT_ADLAM = 167,
+    USCRIPT_BHAIKSUKI = 168,
+    USCRIPT_MARCHEN = 169,
+    USCRIPT_NEWA = 170,
+    USCRIPT_OSAGE = 171,
+    USCRIPT_HAN_WITH_BOPOMOFO = 172,
+    USCRIPT_JAMO = 173,
+    USCRIPT_SYMBOLS_EMOJI = 174,
+    USCRIPT_MASARAM_GONDI = 175,
+    USCRIPT_SOYOMBO = 176,
+    USCRIPT_ZANABAZAR_SQUARE = 177,
+    USCRIPT_DOGRA = 178,
+    USCRIPT_GUNJALA_GONDI = 179,
+    USCRIPT_MAKASAR = 180,
+    USCRIPT_MEDEFAIDRIN = 181,
+    USCRIPT_HANIFI_ROHINGYA = 182,
+    USCRIPT_SOGDIAN = 183,
+    USCRIPT_OLD_SOGDIAN = 184,
+    USCRIPT_ELYMAIC = 185,
+    USCRIPT_NYIAKENG_PUACHUE_HMONG = 186,
+    USCRIPT_NANDINAGARI = 187,
+    USCRIPT_WANCHO = 188,
+    USCRIPT_CHORASMIAN = 189,
+    USCRIPT_DIVES_AKURU = 190,
+    USCRIPT_KHITAN_SMALL_SCRIPT = 191,
+    USCRIPT_YEZIDI = 192,
+    USCRIPT_CYPRO_MINOAN = 193,
+    USCRIPT_OLD_UYGHUR = 194,
+    USCRIPT_TANGSA = 195,
+    USCRIPT_TOTO = 196,
+    USCRIPT_VITHKUQI = 197,
+    USCRIPT_KAWI = 198,
+    USCRIPT_NAG_MUNDARI = 199,
+    USCRIPT_CODE_LIMIT = 200,
+}
+unsafe extern "C" {
+    pub fn uscript_getCode_63(
+        nameOrAbbrOrLocale: *const ::std::os::raw::c_char,
+        fillIn: *mut UScriptCode,
+        capacity: i32,
+        err: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uscript_getName_63(scriptCode: UScriptCode) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn uscript_getShortName_63(scriptCode: UScriptCode) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn uscript_getScript_63(codepoint: UChar32, err: *mut UErrorCode) -> UScriptCode;
+}
+unsafe extern "C" {
+    pub fn uscript_hasScript_63(c: UChar32, sc: UScriptCode) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uscript_getScriptExtensions_63(
+        c: UChar32,
+        scripts: *mut UScriptCode,
+        capacity: i32,
+        errorCode: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UScriptUsage {
+    USCRIPT_USAGE_NOT_ENCODED = 0,
+    USCRIPT_USAGE_UNKNOWN = 1,
+    USCRIPT_USAGE_EXCLUDED = 2,
+    USCRIPT_USAGE_LIMITED_USE = 3,
+    USCRIPT_USAGE_ASPIRATIONAL = 4,
+    USCRIPT_USAGE_RECOMMENDED = 5,
+}
+unsafe extern "C" {
+    pub fn uscript_getSampleString_63(
+        script: UScriptCode,
+        dest: *mut UChar,
+        capacity: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uscript_getUsage_63(script: UScriptCode) -> UScriptUsage;
+}
+unsafe extern "C" {
+    pub fn uscript_isRightToLeft_63(script: UScriptCode) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uscript_breaksBetweenLetters_63(script: UScriptCode) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uscript_isCased_63(script: UScriptCode) -> UBool;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct UCollator {
@@ -3296,42 +3860,168 @@ unsafe extern "C" {
         status: *mut UErrorCode,
     );
 }
-#[repr(C)]
-#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, PartialEq)]
-pub struct UFieldPosition {
-    pub field: i32,
-    pub beginIndex: i32,
-    pub endIndex: i32,
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UCurrencyUsage {
+    UCURR_USAGE_STANDARD = 0,
+    UCURR_USAGE_CASH = 1,
+    UCURR_USAGE_COUNT = 2,
+}
+unsafe extern "C" {
+    pub fn ucurr_forLocale_63(
+        locale: *const ::std::os::raw::c_char,
+        buff: *mut UChar,
+        buffCapacity: i32,
+        ec: *mut UErrorCode,
+    ) -> i32;
 }
-#[allow(clippy::unnecessary_operation, clippy::identity_op)]
-const _: () = {
-    ["Size of UFieldPosition"][::std::mem::size_of::<UFieldPosition>() - 12usize];
-    ["Alignment of UFieldPosition"][::std::mem::align_of::<UFieldPosition>() - 4usize];
-    ["Offset of field: UFieldPosition::field"]
-        [::std::mem::offset_of!(UFieldPosition, field) - 0usize];
-    ["Offset of field: UFieldPosition::beginIndex"]
-        [::std::mem::offset_of!(UFieldPosition, beginIndex) - 4usize];
-    ["Offset of field: UFieldPosition::endIndex"]
-        [::std::mem::offset_of!(UFieldPosition, endIndex) - 8usize];
-};
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
-pub enum UFormattableType {
-    UFMT_DATE = 0,
-    UFMT_DOUBLE = 1,
-    UFMT_LONG = 2,
-    UFMT_STRING = 3,
-    UFMT_ARRAY = 4,
-    UFMT_INT64 = 5,
-    UFMT_OBJECT = 6,
-    UFMT_COUNT = 7,
+pub enum UCurrNameStyle {
+    UCURR_SYMBOL_NAME = 0,
+    UCURR_LONG_NAME = 1,
+    UCURR_NARROW_SYMBOL_NAME = 2,
+    UCURR_FORMAL_SYMBOL_NAME = 3,
+    UCURR_VARIANT_SYMBOL_NAME = 4,
 }
-pub type UFormattable = *mut ::std::os::raw::c_void;
+pub type UCurrRegistryKey = *const ::std::os::raw::c_void;
 unsafe extern "C" {
-    pub fn ufmt_open_63(status: *mut UErrorCode) -> *mut UFormattable;
+    pub fn ucurr_register_63(
+        isoCode: *const UChar,
+        locale: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    ) -> UCurrRegistryKey;
 }
 unsafe extern "C" {
-    pub fn ufmt_close_63(fmt: *mut UFormattable);
+    pub fn ucurr_unregister_63(key: UCurrRegistryKey, status: *mut UErrorCode) -> UBool;
+}
+unsafe extern "C" {
+    pub fn ucurr_getName_63(
+        currency: *const UChar,
+        locale: *const ::std::os::raw::c_char,
+        nameStyle: UCurrNameStyle,
+        isChoiceFormat: *mut UBool,
+        len: *mut i32,
+        ec: *mut UErrorCode,
+    ) -> *const UChar;
+}
+unsafe extern "C" {
+    pub fn ucurr_getPluralName_63(
+        currency: *const UChar,
+        locale: *const ::std::os::raw::c_char,
+        isChoiceFormat: *mut UBool,
+        pluralCount: *const ::std::os::raw::c_char,
+        len: *mut i32,
+        ec: *mut UErrorCode,
+    ) -> *const UChar;
+}
+unsafe extern "C" {
+    pub fn ucurr_getDefaultFractionDigits_63(currency: *const UChar, ec: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucurr_getDefaultFractionDigitsForUsage_63(
+        currency: *const UChar,
+        usage: UCurrencyUsage,
+        ec: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucurr_getRoundingIncrement_63(currency: *const UChar, ec: *mut UErrorCode) -> f64;
+}
+unsafe extern "C" {
+    pub fn ucurr_getRoundingIncrementForUsage_63(
+        currency: *const UChar,
+        usage: UCurrencyUsage,
+        ec: *mut UErrorCode,
+    ) -> f64;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UCurrCurrencyType {
+    UCURR_ALL = 2147483647,
+    UCURR_COMMON = 1,
+    UCURR_UNCOMMON = 2,
+    UCURR_DEPRECATED = 4,
+    UCURR_NON_DEPRECATED = 8,
+}
+unsafe extern "C" {
+    pub fn ucurr_openISOCurrencies_63(
+        currType: u32,
+        pErrorCode: *mut UErrorCode,
+    ) -> *mut UEnumeration;
+}
+unsafe extern "C" {
+    pub fn ucurr_isAvailable_63(
+        isoCode: *const UChar,
+        from: UDate,
+        to: UDate,
+        errorCode: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn ucurr_countCurrencies_63(
+        locale: *const ::std::os::raw::c_char,
+        date: UDate,
+        ec: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucurr_forLocaleAndDate_63(
+        locale: *const ::std::os::raw::c_char,
+        date: UDate,
+        index: i32,
+        buff: *mut UChar,
+        buffCapacity: i32,
+        ec: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucurr_getKeywordValuesForLocale_63(
+        key: *const ::std::os::raw::c_char,
+        locale: *const ::std::os::raw::c_char,
+        commonlyUsed: UBool,
+        status: *mut UErrorCode,
+    ) -> *mut UEnumeration;
+}
+unsafe extern "C" {
+    pub fn ucurr_getNumericCode_63(currency: *const UChar) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, PartialEq)]
+pub struct UFieldPosition {
+    pub field: i32,
+    pub beginIndex: i32,
+    pub endIndex: i32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of UFieldPosition"][::std::mem::size_of::<UFieldPosition>() - 12usize];
+    ["Alignment of UFieldPosition"][::std::mem::align_of::<UFieldPosition>() - 4usize];
+    ["Offset of field: UFieldPosition::field"]
+        [::std::mem::offset_of!(UFieldPosition, field) - 0usize];
+    ["Offset of field: UFieldPosition::beginIndex"]
+        [::std::mem::offset_of!(UFieldPosition, beginIndex) - 4usize];
+    ["Offset of field: UFieldPosition::endIndex"]
+        [::std::mem::offset_of!(UFieldPosition, endIndex) - 8usize];
+};
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UFormattableType {
+    UFMT_DATE = 0,
+    UFMT_DOUBLE = 1,
+    UFMT_LONG = 2,
+    UFMT_STRING = 3,
+    UFMT_ARRAY = 4,
+    UFMT_INT64 = 5,
+    UFMT_OBJECT = 6,
+    UFMT_COUNT = 7,
+}
+pub type UFormattable = *mut ::std::os::raw::c_void;
+unsafe extern "C" {
+    pub fn ufmt_open_63(status: *mut UErrorCode) -> *mut UFormattable;
+}
+unsafe extern "C" {
+    pub fn ufmt_close_63(fmt: *mut UFormattable);
 }
 unsafe extern "C" {
     pub fn ufmt_getType_63(fmt: *const UFormattable, status: *mut UErrorCode) -> UFormattableType;
@@ -3451,6 +4141,14 @@ pub enum UNumberFormatStyle {
     UNUM_CURRENCY_STANDARD = 16,
     UNUM_FORMAT_STYLE_COUNT = 17,
 }
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UCurrencySpacing {
+    UNUM_CURRENCY_MATCH = 0,
+    UNUM_CURRENCY_SURROUNDING_MATCH = 1,
+    UNUM_CURRENCY_INSERT = 2,
+    UNUM_CURRENCY_SPACING_COUNT = 3,
+}
 unsafe extern "C" {
     pub fn unum_open_63(
         style: UNumberFormatStyle,
@@ -5787,3 +6485,1935 @@ unsafe extern "C" {
         pErrorCode: *mut UErrorCode,
     ) -> *mut UCPTrie;
 }
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct URegularExpression {
+    _unused: [u8; 0],
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum URegexpFlag {
+    UREGEX_CANON_EQ = 128,
+    UREGEX_CASE_INSENSITIVE = 2,
+    UREGEX_COMMENTS = 4,
+    UREGEX_DOTALL = 32,
+    UREGEX_LITERAL = 16,
+    UREGEX_MULTILINE = 8,
+    UREGEX_UNIX_LINES = 1,
+    UREGEX_UWORD = 256,
+    UREGEX_ERROR_ON_UNKNOWN_ESCAPES = 512,
+}
+unsafe extern "C" {
+    pub fn uregex_open_63(
+        pattern: *const UChar,
+        patternLength: i32,
+        flags: u32,
+        pe: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> *mut URegularExpression;
+}
+unsafe extern "C" {
+    pub fn uregex_openUText_63(
+        pattern: *mut UText,
+        flags: u32,
+        pe: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> *mut URegularExpression;
+}
+unsafe extern "C" {
+    pub fn uregex_openC_63(
+        pattern: *const ::std::os::raw::c_char,
+        flags: u32,
+        pe: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> *mut URegularExpression;
+}
+unsafe extern "C" {
+    pub fn uregex_close_63(regexp: *mut URegularExpression);
+}
+unsafe extern "C" {
+    pub fn uregex_clone_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> *mut URegularExpression;
+}
+unsafe extern "C" {
+    pub fn uregex_pattern_63(
+        regexp: *const URegularExpression,
+        patLength: *mut i32,
+        status: *mut UErrorCode,
+    ) -> *const UChar;
+}
+unsafe extern "C" {
+    pub fn uregex_patternUText_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> *mut UText;
+}
+unsafe extern "C" {
+    pub fn uregex_flags_63(regexp: *const URegularExpression, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_setText_63(
+        regexp: *mut URegularExpression,
+        text: *const UChar,
+        textLength: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_setUText_63(
+        regexp: *mut URegularExpression,
+        text: *mut UText,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_getText_63(
+        regexp: *mut URegularExpression,
+        textLength: *mut i32,
+        status: *mut UErrorCode,
+    ) -> *const UChar;
+}
+unsafe extern "C" {
+    pub fn uregex_getUText_63(
+        regexp: *mut URegularExpression,
+        dest: *mut UText,
+        status: *mut UErrorCode,
+    ) -> *mut UText;
+}
+unsafe extern "C" {
+    pub fn uregex_refreshUText_63(
+        regexp: *mut URegularExpression,
+        text: *mut UText,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_matches_63(
+        regexp: *mut URegularExpression,
+        startIndex: i32,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_matches64_63(
+        regexp: *mut URegularExpression,
+        startIndex: i64,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_lookingAt_63(
+        regexp: *mut URegularExpression,
+        startIndex: i32,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_lookingAt64_63(
+        regexp: *mut URegularExpression,
+        startIndex: i64,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_find_63(
+        regexp: *mut URegularExpression,
+        startIndex: i32,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_find64_63(
+        regexp: *mut URegularExpression,
+        startIndex: i64,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_findNext_63(regexp: *mut URegularExpression, status: *mut UErrorCode) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_groupCount_63(regexp: *mut URegularExpression, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_groupNumberFromName_63(
+        regexp: *mut URegularExpression,
+        groupName: *const UChar,
+        nameLength: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_groupNumberFromCName_63(
+        regexp: *mut URegularExpression,
+        groupName: *const ::std::os::raw::c_char,
+        nameLength: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_group_63(
+        regexp: *mut URegularExpression,
+        groupNum: i32,
+        dest: *mut UChar,
+        destCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_groupUText_63(
+        regexp: *mut URegularExpression,
+        groupNum: i32,
+        dest: *mut UText,
+        groupLength: *mut i64,
+        status: *mut UErrorCode,
+    ) -> *mut UText;
+}
+unsafe extern "C" {
+    pub fn uregex_start_63(
+        regexp: *mut URegularExpression,
+        groupNum: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_start64_63(
+        regexp: *mut URegularExpression,
+        groupNum: i32,
+        status: *mut UErrorCode,
+    ) -> i64;
+}
+unsafe extern "C" {
+    pub fn uregex_end_63(
+        regexp: *mut URegularExpression,
+        groupNum: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_end64_63(
+        regexp: *mut URegularExpression,
+        groupNum: i32,
+        status: *mut UErrorCode,
+    ) -> i64;
+}
+unsafe extern "C" {
+    pub fn uregex_reset_63(regexp: *mut URegularExpression, index: i32, status: *mut UErrorCode);
+}
+unsafe extern "C" {
+    pub fn uregex_reset64_63(regexp: *mut URegularExpression, index: i64, status: *mut UErrorCode);
+}
+unsafe extern "C" {
+    pub fn uregex_setRegion_63(
+        regexp: *mut URegularExpression,
+        regionStart: i32,
+        regionLimit: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_setRegion64_63(
+        regexp: *mut URegularExpression,
+        regionStart: i64,
+        regionLimit: i64,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_setRegionAndStart_63(
+        regexp: *mut URegularExpression,
+        regionStart: i64,
+        regionLimit: i64,
+        startIndex: i64,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_regionStart_63(regexp: *const URegularExpression, status: *mut UErrorCode)
+        -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_regionStart64_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> i64;
+}
+unsafe extern "C" {
+    pub fn uregex_regionEnd_63(regexp: *const URegularExpression, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_regionEnd64_63(regexp: *const URegularExpression, status: *mut UErrorCode)
+        -> i64;
+}
+unsafe extern "C" {
+    pub fn uregex_hasTransparentBounds_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_useTransparentBounds_63(
+        regexp: *mut URegularExpression,
+        b: UBool,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_hasAnchoringBounds_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_useAnchoringBounds_63(
+        regexp: *mut URegularExpression,
+        b: UBool,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_hitEnd_63(regexp: *const URegularExpression, status: *mut UErrorCode) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_requireEnd_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn uregex_replaceAll_63(
+        regexp: *mut URegularExpression,
+        replacementText: *const UChar,
+        replacementLength: i32,
+        destBuf: *mut UChar,
+        destCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_replaceAllUText_63(
+        regexp: *mut URegularExpression,
+        replacement: *mut UText,
+        dest: *mut UText,
+        status: *mut UErrorCode,
+    ) -> *mut UText;
+}
+unsafe extern "C" {
+    pub fn uregex_replaceFirst_63(
+        regexp: *mut URegularExpression,
+        replacementText: *const UChar,
+        replacementLength: i32,
+        destBuf: *mut UChar,
+        destCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_replaceFirstUText_63(
+        regexp: *mut URegularExpression,
+        replacement: *mut UText,
+        dest: *mut UText,
+        status: *mut UErrorCode,
+    ) -> *mut UText;
+}
+unsafe extern "C" {
+    pub fn uregex_appendReplacement_63(
+        regexp: *mut URegularExpression,
+        replacementText: *const UChar,
+        replacementLength: i32,
+        destBuf: *mut *mut UChar,
+        destCapacity: *mut i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_appendReplacementUText_63(
+        regexp: *mut URegularExpression,
+        replacementText: *mut UText,
+        dest: *mut UText,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_appendTail_63(
+        regexp: *mut URegularExpression,
+        destBuf: *mut *mut UChar,
+        destCapacity: *mut i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_appendTailUText_63(
+        regexp: *mut URegularExpression,
+        dest: *mut UText,
+        status: *mut UErrorCode,
+    ) -> *mut UText;
+}
+unsafe extern "C" {
+    pub fn uregex_split_63(
+        regexp: *mut URegularExpression,
+        destBuf: *mut UChar,
+        destCapacity: i32,
+        requiredCapacity: *mut i32,
+        destFields: *mut *mut UChar,
+        destFieldsCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_splitUText_63(
+        regexp: *mut URegularExpression,
+        destFields: *mut *mut UText,
+        destFieldsCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_setTimeLimit_63(
+        regexp: *mut URegularExpression,
+        limit: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_getTimeLimit_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uregex_setStackLimit_63(
+        regexp: *mut URegularExpression,
+        limit: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_getStackLimit_63(
+        regexp: *const URegularExpression,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+pub type URegexMatchCallback = ::std::option::Option<
+    unsafe extern "C" fn(context: *const ::std::os::raw::c_void, steps: i32) -> UBool,
+>;
+unsafe extern "C" {
+    pub fn uregex_setMatchCallback_63(
+        regexp: *mut URegularExpression,
+        callback: URegexMatchCallback,
+        context: *const ::std::os::raw::c_void,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_getMatchCallback_63(
+        regexp: *const URegularExpression,
+        callback: *mut URegexMatchCallback,
+        context: *mut *const ::std::os::raw::c_void,
+        status: *mut UErrorCode,
+    );
+}
+pub type URegexFindProgressCallback = ::std::option::Option<
+    unsafe extern "C" fn(context: *const ::std::os::raw::c_void, matchIndex: i64) -> UBool,
+>;
+unsafe extern "C" {
+    pub fn uregex_setFindProgressCallback_63(
+        regexp: *mut URegularExpression,
+        callback: URegexFindProgressCallback,
+        context: *const ::std::os::raw::c_void,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uregex_getFindProgressCallback_63(
+        regexp: *const URegularExpression,
+        callback: *mut URegexFindProgressCallback,
+        context: *mut *const ::std::os::raw::c_void,
+        status: *mut UErrorCode,
+    );
+}
+pub type UBiDiLevel = u8;
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UBiDiDirection {
+    UBIDI_LTR = 0,
+    UBIDI_RTL = 1,
+    UBIDI_MIXED = 2,
+    UBIDI_NEUTRAL = 3,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UBiDi {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn ubidi_open_63() -> *mut UBiDi;
+}
+unsafe extern "C" {
+    pub fn ubidi_openSized_63(
+        maxLength: i32,
+        maxRunCount: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> *mut UBiDi;
+}
+unsafe extern "C" {
+    pub fn ubidi_close_63(pBiDi: *mut UBiDi);
+}
+unsafe extern "C" {
+    pub fn ubidi_setInverse_63(pBiDi: *mut UBiDi, isInverse: UBool);
+}
+unsafe extern "C" {
+    pub fn ubidi_isInverse_63(pBiDi: *mut UBiDi) -> UBool;
+}
+unsafe extern "C" {
+    pub fn ubidi_orderParagraphsLTR_63(pBiDi: *mut UBiDi, orderParagraphsLTR: UBool);
+}
+unsafe extern "C" {
+    pub fn ubidi_isOrderParagraphsLTR_63(pBiDi: *mut UBiDi) -> UBool;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UBiDiReorderingMode {
+    UBIDI_REORDER_DEFAULT = 0,
+    UBIDI_REORDER_NUMBERS_SPECIAL = 1,
+    UBIDI_REORDER_GROUP_NUMBERS_WITH_R = 2,
+    UBIDI_REORDER_RUNS_ONLY = 3,
+    UBIDI_REORDER_INVERSE_NUMBERS_AS_L = 4,
+    UBIDI_REORDER_INVERSE_LIKE_DIRECT = 5,
+    UBIDI_REORDER_INVERSE_FOR_NUMBERS_SPECIAL = 6,
+    UBIDI_REORDER_COUNT = 7,
+}
+unsafe extern "C" {
+    pub fn ubidi_setReorderingMode_63(pBiDi: *mut UBiDi, reorderingMode: UBiDiReorderingMode);
+}
+unsafe extern "C" {
+    pub fn ubidi_getReorderingMode_63(pBiDi: *mut UBiDi) -> UBiDiReorderingMode;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UBiDiReorderingOption {
+    UBIDI_OPTION_DEFAULT = 0,
+    UBIDI_OPTION_INSERT_MARKS = 1,
+    UBIDI_OPTION_REMOVE_CONTROLS = 2,
+    UBIDI_OPTION_STREAMING = 4,
+}
+unsafe extern "C" {
+    pub fn ubidi_setReorderingOptions_63(pBiDi: *mut UBiDi, reorderingOptions: u32);
+}
+unsafe extern "C" {
+    pub fn ubidi_getReorderingOptions_63(pBiDi: *mut UBiDi) -> u32;
+}
+unsafe extern "C" {
+    pub fn ubidi_setContext_63(
+        pBiDi: *mut UBiDi,
+        prologue: *const UChar,
+        proLength: i32,
+        epilogue: *const UChar,
+        epiLength: i32,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_setPara_63(
+        pBiDi: *mut UBiDi,
+        text: *const UChar,
+        length: i32,
+        paraLevel: UBiDiLevel,
+        embeddingLevels: *mut UBiDiLevel,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_setLine_63(
+        pParaBiDi: *const UBiDi,
+        start: i32,
+        limit: i32,
+        pLineBiDi: *mut UBiDi,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_getDirection_63(pBiDi: *const UBiDi) -> UBiDiDirection;
+}
+unsafe extern "C" {
+    pub fn ubidi_getBaseDirection_63(text: *const UChar, length: i32) -> UBiDiDirection;
+}
+unsafe extern "C" {
+    pub fn ubidi_getText_63(pBiDi: *const UBiDi) -> *const UChar;
+}
+unsafe extern "C" {
+    pub fn ubidi_getLength_63(pBiDi: *const UBiDi) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_getParaLevel_63(pBiDi: *const UBiDi) -> UBiDiLevel;
+}
+unsafe extern "C" {
+    pub fn ubidi_countParagraphs_63(pBiDi: *mut UBiDi) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_getParagraph_63(
+        pBiDi: *const UBiDi,
+        charIndex: i32,
+        pParaStart: *mut i32,
+        pParaLimit: *mut i32,
+        pParaLevel: *mut UBiDiLevel,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_getParagraphByIndex_63(
+        pBiDi: *const UBiDi,
+        paraIndex: i32,
+        pParaStart: *mut i32,
+        pParaLimit: *mut i32,
+        pParaLevel: *mut UBiDiLevel,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_getLevelAt_63(pBiDi: *const UBiDi, charIndex: i32) -> UBiDiLevel;
+}
+unsafe extern "C" {
+    pub fn ubidi_getLevels_63(pBiDi: *mut UBiDi, pErrorCode: *mut UErrorCode) -> *const UBiDiLevel;
+}
+unsafe extern "C" {
+    pub fn ubidi_getLogicalRun_63(
+        pBiDi: *const UBiDi,
+        logicalPosition: i32,
+        pLogicalLimit: *mut i32,
+        pLevel: *mut UBiDiLevel,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_countRuns_63(pBiDi: *mut UBiDi, pErrorCode: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_getVisualRun_63(
+        pBiDi: *mut UBiDi,
+        runIndex: i32,
+        pLogicalStart: *mut i32,
+        pLength: *mut i32,
+    ) -> UBiDiDirection;
+}
+unsafe extern "C" {
+    pub fn ubidi_getVisualIndex_63(
+        pBiDi: *mut UBiDi,
+        logicalIndex: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_getLogicalIndex_63(
+        pBiDi: *mut UBiDi,
+        visualIndex: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_getLogicalMap_63(
+        pBiDi: *mut UBiDi,
+        indexMap: *mut i32,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_getVisualMap_63(
+        pBiDi: *mut UBiDi,
+        indexMap: *mut i32,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_reorderLogical_63(levels: *const UBiDiLevel, length: i32, indexMap: *mut i32);
+}
+unsafe extern "C" {
+    pub fn ubidi_reorderVisual_63(levels: *const UBiDiLevel, length: i32, indexMap: *mut i32);
+}
+unsafe extern "C" {
+    pub fn ubidi_invertMap_63(srcMap: *const i32, destMap: *mut i32, length: i32);
+}
+unsafe extern "C" {
+    pub fn ubidi_getProcessedLength_63(pBiDi: *const UBiDi) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_getResultLength_63(pBiDi: *const UBiDi) -> i32;
+}
+pub type UBiDiClassCallback = ::std::option::Option<
+    unsafe extern "C" fn(context: *const ::std::os::raw::c_void, c: UChar32) -> UCharDirection,
+>;
+unsafe extern "C" {
+    pub fn ubidi_getCustomizedClass_63(pBiDi: *mut UBiDi, c: UChar32) -> UCharDirection;
+}
+unsafe extern "C" {
+    pub fn ubidi_setClassCallback_63(
+        pBiDi: *mut UBiDi,
+        newFn: UBiDiClassCallback,
+        newContext: *const ::std::os::raw::c_void,
+        oldFn: *mut UBiDiClassCallback,
+        oldContext: *mut *const ::std::os::raw::c_void,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_getClassCallback_63(
+        pBiDi: *mut UBiDi,
+        fn_: *mut UBiDiClassCallback,
+        context: *mut *const ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn ubidi_writeReordered_63(
+        pBiDi: *mut UBiDi,
+        dest: *mut UChar,
+        destSize: i32,
+        options: u16,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ubidi_writeReverse_63(
+        src: *const UChar,
+        srcLength: i32,
+        dest: *mut UChar,
+        destSize: i32,
+        options: u16,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UIDNA {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn uidna_openUTS46_63(options: u32, pErrorCode: *mut UErrorCode) -> *mut UIDNA;
+}
+unsafe extern "C" {
+    pub fn uidna_close_63(idna: *mut UIDNA);
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, PartialEq)]
+pub struct UIDNAInfo {
+    pub size: i16,
+    pub isTransitionalDifferent: UBool,
+    pub reservedB3: UBool,
+    pub errors: u32,
+    pub reservedI2: i32,
+    pub reservedI3: i32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of UIDNAInfo"][::std::mem::size_of::<UIDNAInfo>() - 16usize];
+    ["Alignment of UIDNAInfo"][::std::mem::align_of::<UIDNAInfo>() - 4usize];
+    ["Offset of field: UIDNAInfo::size"][::std::mem::offset_of!(UIDNAInfo, size) - 0usize];
+    ["Offset of field: UIDNAInfo::isTransitionalDifferent"]
+        [::std::mem::offset_of!(UIDNAInfo, isTransitionalDifferent) - 2usize];
+    ["Offset of field: UIDNAInfo::reservedB3"]
+        [::std::mem::offset_of!(UIDNAInfo, reservedB3) - 3usize];
+    ["Offset of field: UIDNAInfo::errors"][::std::mem::offset_of!(UIDNAInfo, errors) - 4usize];
+    ["Offset of field: UIDNAInfo::reservedI2"]
+        [::std::mem::offset_of!(UIDNAInfo, reservedI2) - 8usize];
+    ["Offset of field: UIDNAInfo::reservedI3"]
+        [::std::mem::offset_of!(UIDNAInfo, reservedI3) - 12usize];
+};
+unsafe extern "C" {
+    pub fn uidna_labelToASCII_63(
+        idna: *const UIDNA,
+        label: *const UChar,
+        length: i32,
+        dest: *mut UChar,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_labelToUnicode_63(
+        idna: *const UIDNA,
+        label: *const UChar,
+        length: i32,
+        dest: *mut UChar,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_nameToASCII_63(
+        idna: *const UIDNA,
+        name: *const UChar,
+        length: i32,
+        dest: *mut UChar,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_nameToUnicode_63(
+        idna: *const UIDNA,
+        name: *const UChar,
+        length: i32,
+        dest: *mut UChar,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_labelToASCII_UTF8_63(
+        idna: *const UIDNA,
+        label: *const ::std::os::raw::c_char,
+        length: i32,
+        dest: *mut ::std::os::raw::c_char,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_labelToUnicodeUTF8_63(
+        idna: *const UIDNA,
+        label: *const ::std::os::raw::c_char,
+        length: i32,
+        dest: *mut ::std::os::raw::c_char,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_nameToASCII_UTF8_63(
+        idna: *const UIDNA,
+        name: *const ::std::os::raw::c_char,
+        length: i32,
+        dest: *mut ::std::os::raw::c_char,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_nameToUnicodeUTF8_63(
+        idna: *const UIDNA,
+        name: *const ::std::os::raw::c_char,
+        length: i32,
+        dest: *mut ::std::os::raw::c_char,
+        capacity: i32,
+        pInfo: *mut UIDNAInfo,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_toASCII_63(
+        src: *const UChar,
+        srcLength: i32,
+        dest: *mut UChar,
+        destCapacity: i32,
+        options: i32,
+        parseError: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_toUnicode_63(
+        src: *const UChar,
+        srcLength: i32,
+        dest: *mut UChar,
+        destCapacity: i32,
+        options: i32,
+        parseError: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_IDNToASCII_63(
+        src: *const UChar,
+        srcLength: i32,
+        dest: *mut UChar,
+        destCapacity: i32,
+        options: i32,
+        parseError: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_IDNToUnicode_63(
+        src: *const UChar,
+        srcLength: i32,
+        dest: *mut UChar,
+        destCapacity: i32,
+        options: i32,
+        parseError: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uidna_compare_63(
+        s1: *const UChar,
+        length1: i32,
+        s2: *const UChar,
+        length2: i32,
+        options: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USpoofChecker {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USpoofCheckResult {
+    _unused: [u8; 0],
+}
+impl USpoofChecks {
+    pub const USPOOF_SINGLE_SCRIPT: USpoofChecks = USpoofChecks::USPOOF_RESTRICTION_LEVEL;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum USpoofChecks {
+    USPOOF_SINGLE_SCRIPT_CONFUSABLE = 1,
+    USPOOF_MIXED_SCRIPT_CONFUSABLE = 2,
+    USPOOF_WHOLE_SCRIPT_CONFUSABLE = 4,
+    USPOOF_CONFUSABLE = 7,
+    USPOOF_ANY_CASE = 8,
+    USPOOF_RESTRICTION_LEVEL = 16,
+    USPOOF_INVISIBLE = 32,
+    USPOOF_CHAR_LIMIT = 64,
+    USPOOF_MIXED_NUMBERS = 128,
+    USPOOF_HIDDEN_OVERLAY = 256,
+    USPOOF_ALL_CHECKS = 65535,
+    USPOOF_AUX_INFO = 1073741824,
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum URestrictionLevel {
+    USPOOF_ASCII = 268435456,
+    USPOOF_SINGLE_SCRIPT_RESTRICTIVE = 536870912,
+    USPOOF_HIGHLY_RESTRICTIVE = 805306368,
+    USPOOF_MODERATELY_RESTRICTIVE = 1073741824,
+    USPOOF_MINIMALLY_RESTRICTIVE = 1342177280,
+    USPOOF_UNRESTRICTIVE = 1610612736,
+    USPOOF_RESTRICTION_LEVEL_MASK = 2130706432,
+    USPOOF_UNDEFINED_RESTRICTIVE = -1,
+}
+unsafe extern "C" {
+    pub fn uspoof_open_63(status: *mut UErrorCode) -> *mut USpoofChecker;
+}
+unsafe extern "C" {
+    pub fn uspoof_openFromSerialized_63(
+        data: *const ::std::os::raw::c_void,
+        length: i32,
+        pActualLength: *mut i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> *mut USpoofChecker;
+}
+unsafe extern "C" {
+    pub fn uspoof_openFromSource_63(
+        confusables: *const ::std::os::raw::c_char,
+        confusablesLen: i32,
+        confusablesWholeScript: *const ::std::os::raw::c_char,
+        confusablesWholeScriptLen: i32,
+        errType: *mut i32,
+        pe: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> *mut USpoofChecker;
+}
+unsafe extern "C" {
+    pub fn uspoof_close_63(sc: *mut USpoofChecker);
+}
+unsafe extern "C" {
+    pub fn uspoof_clone_63(sc: *const USpoofChecker, status: *mut UErrorCode)
+        -> *mut USpoofChecker;
+}
+unsafe extern "C" {
+    pub fn uspoof_setChecks_63(sc: *mut USpoofChecker, checks: i32, status: *mut UErrorCode);
+}
+unsafe extern "C" {
+    pub fn uspoof_getChecks_63(sc: *const USpoofChecker, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_setRestrictionLevel_63(
+        sc: *mut USpoofChecker,
+        restrictionLevel: URestrictionLevel,
+    );
+}
+unsafe extern "C" {
+    pub fn uspoof_getRestrictionLevel_63(sc: *const USpoofChecker) -> URestrictionLevel;
+}
+unsafe extern "C" {
+    pub fn uspoof_setAllowedLocales_63(
+        sc: *mut USpoofChecker,
+        localesList: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uspoof_getAllowedLocales_63(
+        sc: *mut USpoofChecker,
+        status: *mut UErrorCode,
+    ) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn uspoof_setAllowedChars_63(
+        sc: *mut USpoofChecker,
+        chars: *const USet,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn uspoof_getAllowedChars_63(
+        sc: *const USpoofChecker,
+        status: *mut UErrorCode,
+    ) -> *const USet;
+}
+unsafe extern "C" {
+    pub fn uspoof_check_63(
+        sc: *const USpoofChecker,
+        id: *const UChar,
+        length: i32,
+        position: *mut i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_checkUTF8_63(
+        sc: *const USpoofChecker,
+        id: *const ::std::os::raw::c_char,
+        length: i32,
+        position: *mut i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_check2_63(
+        sc: *const USpoofChecker,
+        id: *const UChar,
+        length: i32,
+        checkResult: *mut USpoofCheckResult,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_check2UTF8_63(
+        sc: *const USpoofChecker,
+        id: *const ::std::os::raw::c_char,
+        length: i32,
+        checkResult: *mut USpoofCheckResult,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_openCheckResult_63(status: *mut UErrorCode) -> *mut USpoofCheckResult;
+}
+unsafe extern "C" {
+    pub fn uspoof_closeCheckResult_63(checkResult: *mut USpoofCheckResult);
+}
+unsafe extern "C" {
+    pub fn uspoof_getCheckResultChecks_63(
+        checkResult: *const USpoofCheckResult,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_getCheckResultRestrictionLevel_63(
+        checkResult: *const USpoofCheckResult,
+        status: *mut UErrorCode,
+    ) -> URestrictionLevel;
+}
+unsafe extern "C" {
+    pub fn uspoof_getCheckResultNumerics_63(
+        checkResult: *const USpoofCheckResult,
+        status: *mut UErrorCode,
+    ) -> *const USet;
+}
+unsafe extern "C" {
+    pub fn uspoof_areConfusable_63(
+        sc: *const USpoofChecker,
+        id1: *const UChar,
+        length1: i32,
+        id2: *const UChar,
+        length2: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_areConfusableUTF8_63(
+        sc: *const USpoofChecker,
+        id1: *const ::std::os::raw::c_char,
+        length1: i32,
+        id2: *const ::std::os::raw::c_char,
+        length2: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_getSkeleton_63(
+        sc: *const USpoofChecker,
+        type_: u32,
+        id: *const UChar,
+        length: i32,
+        dest: *mut UChar,
+        destCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_getSkeletonUTF8_63(
+        sc: *const USpoofChecker,
+        type_: u32,
+        id: *const ::std::os::raw::c_char,
+        length: i32,
+        dest: *mut ::std::os::raw::c_char,
+        destCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uspoof_getInclusionSet_63(status: *mut UErrorCode) -> *const USet;
+}
+unsafe extern "C" {
+    pub fn uspoof_getRecommendedSet_63(status: *mut UErrorCode) -> *const USet;
+}
+unsafe extern "C" {
+    pub fn uspoof_serialize_63(
+        sc: *mut USpoofChecker,
+        data: *mut ::std::os::raw::c_void,
+        capacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UCollationElements {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn ucol_openElements_63(
+        coll: *const UCollator,
+        text: *const UChar,
+        textLength: i32,
+        status: *mut UErrorCode,
+    ) -> *mut UCollationElements;
+}
+unsafe extern "C" {
+    pub fn ucol_keyHashCode_63(key: *const u8, length: i32) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucol_closeElements_63(elems: *mut UCollationElements);
+}
+unsafe extern "C" {
+    pub fn ucol_reset_63(elems: *mut UCollationElements);
+}
+unsafe extern "C" {
+    pub fn ucol_next_63(elems: *mut UCollationElements, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucol_previous_63(elems: *mut UCollationElements, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucol_getMaxExpansion_63(elems: *const UCollationElements, order: i32) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucol_setText_63(
+        elems: *mut UCollationElements,
+        text: *const UChar,
+        textLength: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ucol_getOffset_63(elems: *const UCollationElements) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucol_setOffset_63(elems: *mut UCollationElements, offset: i32, status: *mut UErrorCode);
+}
+unsafe extern "C" {
+    pub fn ucol_primaryOrder_63(order: i32) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucol_secondaryOrder_63(order: i32) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucol_tertiaryOrder_63(order: i32) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UStringSearch {
+    _unused: [u8; 0],
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum USearchAttribute {
+    USEARCH_OVERLAP = 0,
+    USEARCH_CANONICAL_MATCH = 1,
+    USEARCH_ELEMENT_COMPARISON = 2,
+    USEARCH_ATTRIBUTE_COUNT = 3,
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum USearchAttributeValue {
+    USEARCH_DEFAULT = -1,
+    USEARCH_OFF = 0,
+    USEARCH_ON = 1,
+    USEARCH_STANDARD_ELEMENT_COMPARISON = 2,
+    USEARCH_PATTERN_BASE_WEIGHT_IS_WILDCARD = 3,
+    USEARCH_ANY_BASE_WEIGHT_IS_WILDCARD = 4,
+    USEARCH_ATTRIBUTE_VALUE_COUNT = 5,
+}
+unsafe extern "C" {
+    pub fn usearch_open_63(
+        pattern: *const UChar,
+        patternlength: i32,
+        text: *const UChar,
+        textlength: i32,
+        locale: *const ::std::os::raw::c_char,
+        breakiter: *mut UBreakIterator,
+        status: *mut UErrorCode,
+    ) -> *mut UStringSearch;
+}
+unsafe extern "C" {
+    pub fn usearch_openFromCollator_63(
+        pattern: *const UChar,
+        patternlength: i32,
+        text: *const UChar,
+        textlength: i32,
+        collator: *const UCollator,
+        breakiter: *mut UBreakIterator,
+        status: *mut UErrorCode,
+    ) -> *mut UStringSearch;
+}
+unsafe extern "C" {
+    pub fn usearch_close_63(searchiter: *mut UStringSearch);
+}
+unsafe extern "C" {
+    pub fn usearch_setOffset_63(
+        strsrch: *mut UStringSearch,
+        position: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn usearch_getOffset_63(strsrch: *const UStringSearch) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_setAttribute_63(
+        strsrch: *mut UStringSearch,
+        attribute: USearchAttribute,
+        value: USearchAttributeValue,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn usearch_getAttribute_63(
+        strsrch: *const UStringSearch,
+        attribute: USearchAttribute,
+    ) -> USearchAttributeValue;
+}
+unsafe extern "C" {
+    pub fn usearch_getMatchedStart_63(strsrch: *const UStringSearch) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_getMatchedLength_63(strsrch: *const UStringSearch) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_getMatchedText_63(
+        strsrch: *const UStringSearch,
+        result: *mut UChar,
+        resultCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_setBreakIterator_63(
+        strsrch: *mut UStringSearch,
+        breakiter: *mut UBreakIterator,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn usearch_getBreakIterator_63(strsrch: *const UStringSearch) -> *const UBreakIterator;
+}
+unsafe extern "C" {
+    pub fn usearch_setText_63(
+        strsrch: *mut UStringSearch,
+        text: *const UChar,
+        textlength: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn usearch_getText_63(strsrch: *const UStringSearch, length: *mut i32) -> *const UChar;
+}
+unsafe extern "C" {
+    pub fn usearch_getCollator_63(strsrch: *const UStringSearch) -> *mut UCollator;
+}
+unsafe extern "C" {
+    pub fn usearch_setCollator_63(
+        strsrch: *mut UStringSearch,
+        collator: *const UCollator,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn usearch_setPattern_63(
+        strsrch: *mut UStringSearch,
+        pattern: *const UChar,
+        patternlength: i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn usearch_getPattern_63(strsrch: *const UStringSearch, length: *mut i32) -> *const UChar;
+}
+unsafe extern "C" {
+    pub fn usearch_first_63(strsrch: *mut UStringSearch, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_following_63(
+        strsrch: *mut UStringSearch,
+        position: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_last_63(strsrch: *mut UStringSearch, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_preceding_63(
+        strsrch: *mut UStringSearch,
+        position: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_next_63(strsrch: *mut UStringSearch, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_previous_63(strsrch: *mut UStringSearch, status: *mut UErrorCode) -> i32;
+}
+unsafe extern "C" {
+    pub fn usearch_reset_63(strsrch: *mut UStringSearch);
+}
+unsafe extern "C" {
+    pub fn usearch_search_63(
+        strsrch: *mut UStringSearch,
+        startIdx: i32,
+        matchStart: *mut i32,
+        matchLimit: *mut i32,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+unsafe extern "C" {
+    pub fn usearch_searchBackwards_63(
+        strsrch: *mut UStringSearch,
+        startIdx: i32,
+        matchStart: *mut i32,
+        matchLimit: *mut i32,
+        status: *mut UErrorCode,
+    ) -> UBool;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UCaseMap {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn ucasemap_open_63(
+        locale: *const ::std::os::raw::c_char,
+        options: u32,
+        pErrorCode: *mut UErrorCode,
+    ) -> *mut UCaseMap;
+}
+unsafe extern "C" {
+    pub fn ucasemap_close_63(csm: *mut UCaseMap);
+}
+unsafe extern "C" {
+    pub fn ucasemap_getLocale_63(csm: *const UCaseMap) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn ucasemap_getOptions_63(csm: *const UCaseMap) -> u32;
+}
+unsafe extern "C" {
+    pub fn ucasemap_setLocale_63(
+        csm: *mut UCaseMap,
+        locale: *const ::std::os::raw::c_char,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ucasemap_setOptions_63(csm: *mut UCaseMap, options: u32, pErrorCode: *mut UErrorCode);
+}
+unsafe extern "C" {
+    pub fn ucasemap_getBreakIterator_63(csm: *const UCaseMap) -> *const UBreakIterator;
+}
+unsafe extern "C" {
+    pub fn ucasemap_setBreakIterator_63(
+        csm: *mut UCaseMap,
+        iterToAdopt: *mut UBreakIterator,
+        pErrorCode: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ucasemap_toTitle_63(
+        csm: *mut UCaseMap,
+        dest: *mut UChar,
+        destCapacity: i32,
+        src: *const UChar,
+        srcLength: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucasemap_utf8ToLower_63(
+        csm: *const UCaseMap,
+        dest: *mut ::std::os::raw::c_char,
+        destCapacity: i32,
+        src: *const ::std::os::raw::c_char,
+        srcLength: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucasemap_utf8ToUpper_63(
+        csm: *const UCaseMap,
+        dest: *mut ::std::os::raw::c_char,
+        destCapacity: i32,
+        src: *const ::std::os::raw::c_char,
+        srcLength: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucasemap_utf8ToTitle_63(
+        csm: *mut UCaseMap,
+        dest: *mut ::std::os::raw::c_char,
+        destCapacity: i32,
+        src: *const ::std::os::raw::c_char,
+        srcLength: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucasemap_utf8FoldCase_63(
+        csm: *const UCaseMap,
+        dest: *mut ::std::os::raw::c_char,
+        destCapacity: i32,
+        src: *const ::std::os::raw::c_char,
+        srcLength: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UDateRelativeDateTimeFormatterStyle {
+    UDAT_STYLE_LONG = 0,
+    UDAT_STYLE_SHORT = 1,
+    UDAT_STYLE_NARROW = 2,
+    UDAT_STYLE_COUNT = 3,
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum URelativeDateTimeUnit {
+    UDAT_REL_UNIT_YEAR = 0,
+    UDAT_REL_UNIT_QUARTER = 1,
+    UDAT_REL_UNIT_MONTH = 2,
+    UDAT_REL_UNIT_WEEK = 3,
+    UDAT_REL_UNIT_DAY = 4,
+    UDAT_REL_UNIT_HOUR = 5,
+    UDAT_REL_UNIT_MINUTE = 6,
+    UDAT_REL_UNIT_SECOND = 7,
+    UDAT_REL_UNIT_SUNDAY = 8,
+    UDAT_REL_UNIT_MONDAY = 9,
+    UDAT_REL_UNIT_TUESDAY = 10,
+    UDAT_REL_UNIT_WEDNESDAY = 11,
+    UDAT_REL_UNIT_THURSDAY = 12,
+    UDAT_REL_UNIT_FRIDAY = 13,
+    UDAT_REL_UNIT_SATURDAY = 14,
+    UDAT_REL_UNIT_COUNT = 15,
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum URelativeDateTimeFormatterField {
+    UDAT_REL_LITERAL_FIELD = 0,
+    UDAT_REL_NUMERIC_FIELD = 1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct URelativeDateTimeFormatter {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn ureldatefmt_open_63(
+        locale: *const ::std::os::raw::c_char,
+        nfToAdopt: *mut UNumberFormat,
+        width: UDateRelativeDateTimeFormatterStyle,
+        capitalizationContext: UDisplayContext,
+        status: *mut UErrorCode,
+    ) -> *mut URelativeDateTimeFormatter;
+}
+unsafe extern "C" {
+    pub fn ureldatefmt_close_63(reldatefmt: *mut URelativeDateTimeFormatter);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UFormattedRelativeDateTime {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn ureldatefmt_formatNumeric_63(
+        reldatefmt: *const URelativeDateTimeFormatter,
+        offset: f64,
+        unit: URelativeDateTimeUnit,
+        result: *mut UChar,
+        resultCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ureldatefmt_format_63(
+        reldatefmt: *const URelativeDateTimeFormatter,
+        offset: f64,
+        unit: URelativeDateTimeUnit,
+        result: *mut UChar,
+        resultCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ureldatefmt_combineDateAndTime_63(
+        reldatefmt: *const URelativeDateTimeFormatter,
+        relativeDateString: *const UChar,
+        relativeDateStringLen: i32,
+        timeString: *const UChar,
+        timeStringLen: i32,
+        result: *mut UChar,
+        resultCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UDialectHandling {
+    ULDN_STANDARD_NAMES = 0,
+    ULDN_DIALECT_NAMES = 1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ULocaleDisplayNames {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn uldn_open_63(
+        locale: *const ::std::os::raw::c_char,
+        dialectHandling: UDialectHandling,
+        pErrorCode: *mut UErrorCode,
+    ) -> *mut ULocaleDisplayNames;
+}
+unsafe extern "C" {
+    pub fn uldn_close_63(ldn: *mut ULocaleDisplayNames);
+}
+unsafe extern "C" {
+    pub fn uldn_getLocale_63(ldn: *const ULocaleDisplayNames) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn uldn_getDialectHandling_63(ldn: *const ULocaleDisplayNames) -> UDialectHandling;
+}
+unsafe extern "C" {
+    pub fn uldn_localeDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        locale: *const ::std::os::raw::c_char,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_languageDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        lang: *const ::std::os::raw::c_char,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_scriptDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        script: *const ::std::os::raw::c_char,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_scriptCodeDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        scriptCode: UScriptCode,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_regionDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        region: *const ::std::os::raw::c_char,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_variantDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        variant: *const ::std::os::raw::c_char,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_keyDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        key: *const ::std::os::raw::c_char,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_keyValueDisplayName_63(
+        ldn: *const ULocaleDisplayNames,
+        key: *const ::std::os::raw::c_char,
+        value: *const ::std::os::raw::c_char,
+        result: *mut UChar,
+        maxResultSize: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn uldn_openForContext_63(
+        locale: *const ::std::os::raw::c_char,
+        contexts: *mut UDisplayContext,
+        length: i32,
+        pErrorCode: *mut UErrorCode,
+    ) -> *mut ULocaleDisplayNames;
+}
+unsafe extern "C" {
+    pub fn uldn_getContext_63(
+        ldn: *const ULocaleDisplayNames,
+        type_: UDisplayContextType,
+        pErrorCode: *mut UErrorCode,
+    ) -> UDisplayContext;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UDateIntervalFormat {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UFormattedDateInterval {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn udtitvfmt_open_63(
+        locale: *const ::std::os::raw::c_char,
+        skeleton: *const UChar,
+        skeletonLength: i32,
+        tzID: *const UChar,
+        tzIDLength: i32,
+        status: *mut UErrorCode,
+    ) -> *mut UDateIntervalFormat;
+}
+unsafe extern "C" {
+    pub fn udtitvfmt_close_63(formatter: *mut UDateIntervalFormat);
+}
+unsafe extern "C" {
+    pub fn udtitvfmt_format_63(
+        formatter: *const UDateIntervalFormat,
+        fromDate: UDate,
+        toDate: UDate,
+        result: *mut UChar,
+        resultCapacity: i32,
+        position: *mut UFieldPosition,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ULocaleData {
+    _unused: [u8; 0],
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum ULocaleDataExemplarSetType {
+    ULOCDATA_ES_STANDARD = 0,
+    ULOCDATA_ES_AUXILIARY = 1,
+    ULOCDATA_ES_INDEX = 2,
+    ULOCDATA_ES_PUNCTUATION = 3,
+    ULOCDATA_ES_COUNT = 4,
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum ULocaleDataDelimiterType {
+    ULOCDATA_QUOTATION_START = 0,
+    ULOCDATA_QUOTATION_END = 1,
+    ULOCDATA_ALT_QUOTATION_START = 2,
+    ULOCDATA_ALT_QUOTATION_END = 3,
+    ULOCDATA_DELIMITER_COUNT = 4,
+}
+unsafe extern "C" {
+    pub fn ulocdata_open_63(
+        localeID: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    ) -> *mut ULocaleData;
+}
+unsafe extern "C" {
+    pub fn ulocdata_close_63(uld: *mut ULocaleData);
+}
+unsafe extern "C" {
+    pub fn ulocdata_setNoSubstitute_63(uld: *mut ULocaleData, setting: UBool);
+}
+unsafe extern "C" {
+    pub fn ulocdata_getNoSubstitute_63(uld: *mut ULocaleData) -> UBool;
+}
+unsafe extern "C" {
+    pub fn ulocdata_getExemplarSet_63(
+        uld: *mut ULocaleData,
+        fillIn: *mut USet,
+        options: u32,
+        extype: ULocaleDataExemplarSetType,
+        status: *mut UErrorCode,
+    ) -> *mut USet;
+}
+unsafe extern "C" {
+    pub fn ulocdata_getDelimiter_63(
+        uld: *mut ULocaleData,
+        type_: ULocaleDataDelimiterType,
+        result: *mut UChar,
+        resultLength: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UMeasurementSystem {
+    UMS_SI = 0,
+    UMS_US = 1,
+    UMS_UK = 2,
+    UMS_LIMIT = 3,
+}
+unsafe extern "C" {
+    pub fn ulocdata_getMeasurementSystem_63(
+        localeID: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    ) -> UMeasurementSystem;
+}
+unsafe extern "C" {
+    pub fn ulocdata_getPaperSize_63(
+        localeID: *const ::std::os::raw::c_char,
+        height: *mut i32,
+        width: *mut i32,
+        status: *mut UErrorCode,
+    );
+}
+unsafe extern "C" {
+    pub fn ulocdata_getCLDRVersion_63(versionArray: *mut u8, status: *mut UErrorCode);
+}
+unsafe extern "C" {
+    pub fn ulocdata_getLocaleDisplayPattern_63(
+        uld: *mut ULocaleData,
+        pattern: *mut UChar,
+        patternCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ulocdata_getLocaleSeparator_63(
+        uld: *mut ULocaleData,
+        separator: *mut UChar,
+        separatorCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UNumberingSystem {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn unumsys_open_63(
+        locale: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    ) -> *mut UNumberingSystem;
+}
+unsafe extern "C" {
+    pub fn unumsys_openByName_63(
+        name: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    ) -> *mut UNumberingSystem;
+}
+unsafe extern "C" {
+    pub fn unumsys_close_63(unumsys: *mut UNumberingSystem);
+}
+unsafe extern "C" {
+    pub fn unumsys_openAvailableNames_63(status: *mut UErrorCode) -> *mut UEnumeration;
+}
+unsafe extern "C" {
+    pub fn unumsys_getName_63(unumsys: *const UNumberingSystem) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn unumsys_isAlgorithmic_63(unumsys: *const UNumberingSystem) -> UBool;
+}
+unsafe extern "C" {
+    pub fn unumsys_getRadix_63(unumsys: *const UNumberingSystem) -> i32;
+}
+unsafe extern "C" {
+    pub fn unumsys_getDescription_63(
+        unumsys: *const UNumberingSystem,
+        result: *mut UChar,
+        resultLength: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UGender {
+    UGENDER_MALE = 0,
+    UGENDER_FEMALE = 1,
+    UGENDER_OTHER = 2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UGenderInfo {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn ugender_getInstance_63(
+        locale: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    ) -> *const UGenderInfo;
+}
+unsafe extern "C" {
+    pub fn ugender_getListGender_63(
+        genderInfo: *const UGenderInfo,
+        genders: *const UGender,
+        size: i32,
+        status: *mut UErrorCode,
+    ) -> UGender;
+}
+unsafe extern "C" {
+    pub fn u_shapeArabic_63(
+        source: *const UChar,
+        sourceLength: i32,
+        dest: *mut UChar,
+        destSize: i32,
+        options: u32,
+        pErrorCode: *mut UErrorCode,
+    ) -> i32;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UConverterSelector {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn ucnvsel_open_63(
+        converterList: *const *const ::std::os::raw::c_char,
+        converterListSize: i32,
+        excludedCodePoints: *const USet,
+        whichSet: UConverterUnicodeSet,
+        status: *mut UErrorCode,
+    ) -> *mut UConverterSelector;
+}
+unsafe extern "C" {
+    pub fn ucnvsel_close_63(sel: *mut UConverterSelector);
+}
+unsafe extern "C" {
+    pub fn ucnvsel_openFromSerialized_63(
+        buffer: *const ::std::os::raw::c_void,
+        length: i32,
+        status: *mut UErrorCode,
+    ) -> *mut UConverterSelector;
+}
+unsafe extern "C" {
+    pub fn ucnvsel_serialize_63(
+        sel: *const UConverterSelector,
+        buffer: *mut ::std::os::raw::c_void,
+        bufferCapacity: i32,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn ucnvsel_selectForString_63(
+        sel: *const UConverterSelector,
+        s: *const UChar,
+        length: i32,
+        status: *mut UErrorCode,
+    ) -> *mut UEnumeration;
+}
+unsafe extern "C" {
+    pub fn ucnvsel_selectForUTF8_63(
+        sel: *const UConverterSelector,
+        s: *const ::std::os::raw::c_char,
+        length: i32,
+        status: *mut UErrorCode,
+    ) -> *mut UEnumeration;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct UStringPrepProfile {
+    _unused: [u8; 0],
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, PartialEq, Eq)]
+pub enum UStringPrepProfileType {
+    USPREP_RFC3491_NAMEPREP = 0,
+    USPREP_RFC3530_NFS4_CS_PREP = 1,
+    USPREP_RFC3530_NFS4_CS_PREP_CI = 2,
+    USPREP_RFC3530_NFS4_CIS_PREP = 3,
+    USPREP_RFC3530_NFS4_MIXED_PREP_PREFIX = 4,
+    USPREP_RFC3530_NFS4_MIXED_PREP_SUFFIX = 5,
+    USPREP_RFC3722_ISCSI = 6,
+    USPREP_RFC3920_NODEPREP = 7,
+    USPREP_RFC3920_RESOURCEPREP = 8,
+    USPREP_RFC4011_MIB = 9,
+    USPREP_RFC4013_SASLPREP = 10,
+    USPREP_RFC4505_TRACE = 11,
+    USPREP_RFC4518_LDAP = 12,
+    USPREP_RFC4518_LDAP_CI = 13,
+}
+unsafe extern "C" {
+    pub fn usprep_open_63(
+        path: *const ::std::os::raw::c_char,
+        fileName: *const ::std::os::raw::c_char,
+        status: *mut UErrorCode,
+    ) -> *mut UStringPrepProfile;
+}
+unsafe extern "C" {
+    pub fn usprep_openByType_63(
+        type_: UStringPrepProfileType,
+        status: *mut UErrorCode,
+    ) -> *mut UStringPrepProfile;
+}
+unsafe extern "C" {
+    pub fn usprep_close_63(profile: *mut UStringPrepProfile);
+}
+unsafe extern "C" {
+    pub fn usprep_prepare_63(
+        prep: *const UStringPrepProfile,
+        src: *const UChar,
+        srcLength: i32,
+        dest: *mut UChar,
+        destCapacity: i32,
+        options: i32,
+        parseError: *mut UParseError,
+        status: *mut UErrorCode,
+    ) -> i32;
+}
@@ -62,6 +62,25 @@ readonly BINDGEN_SOURCE_MODULES=(
         "unorm2"
         "ucptrie"
         "umutablecptrie"
+        "uregex"
+        "ubidi"
+        "uidna"
+        "uspoof"
+        "usearch"
+        "ucasemap"
+        "uformattedvalue"
+        "ureldatefmt"
+        "uldnames"
+        "unumberrangeformatter"
+        "udateintervalformat"
+        "ucurr"
+        "ulocdata"
+        "uscript"
+        "unumsys"
+        "ugender"
+        "ushape"
+        "ucnvsel"
+        "usprep"
 )
 
 # Types for which to generate the bindings.  Expand this list if you need more.
@@ -113,6 +132,30 @@ readonly BINDGEN_ALLOWLIST_TYPES=(
         "UCPTrieType"
         "UCPTRIE.*"
         "UPRV.*"
+        "URegex.*"
+        "URegularExpression"
+        "UBiDi.*"
+        "UIDNA.*"
+        "USpoof.*"
+        "URestrictionLevel"
+        "UStringSearch"
+        "USearch.*"
+        "UCaseMap"
+        "UConstrainedFieldPosition"
+        "URelativeDateTime.*"
+        "ULocaleDisplayNames"
+        "UDialectHandling"
+        "UNumberRange.*"
+        "UFormattedNumberRange"
+        "UDateIntervalFormat"
+        "UFormattedDateInterval"
+        "UCurr.*"
+        "UCurrencyUsage"
+        "ULocaleData.*"
+        "UMeasurementSystem"
+        "UScript.*"
+        "UStringPrep.*"
+        "UGender.*"
 )
 
 # Functions for which to generate the bindings.  Expand this list if you need
@@ -123,6 +166,8 @@ readonly BINDGEN_ALLOWLIST_FUNCTIONS=(
         "ubrk_.*"
         "ucal_.*"
         "ucnv_.*"
+        "ucnvsel_.*"
+        "usprep_.*"
         "ucol_.*"
         "ucsdet_.*"
         "udat_.*"
@@ -144,6 +189,24 @@ readonly BINDGEN_ALLOWLIST_FUNCTIONS=(
         "usrc_.*"
         "umutablecp.*"
         "ucp.*"
+        "uset_.*"
+        "uregex_.*"
+        "ubidi_.*"
+        "uidna_.*"
+        "uspoof_.*"
+        "usearch_.*"
+        "ucasemap_.*"
+        "ufmtval_.*"
+        "ucfpos_.*"
+        "ureldatefmt_.*"
+        "uldn_.*"
+        "unumrf_.*"
+        "udtitvfmt_.*"
+        "ucurr_.*"
+        "ulocdata_.*"
+        "uscript_.*"
+        "unumsys_.*"
+        "ugender_.*"
 )
 
 # Functions that take a C va_list argument cannot be called from Rust, so we
@@ -63,6 +63,25 @@ mod inner {
             "unorm2",
             "ucptrie",
             "umutablecptrie",
+            "uregex",
+            "ubidi",
+            "uidna",
+            "uspoof",
+            "usearch",
+            "ucasemap",
+            "uformattedvalue",
+            "ureldatefmt",
+            "uldnames",
+            "unumberrangeformatter",
+            "udateintervalformat",
+            "ucurr",
+            "ulocdata",
+            "uscript",
+            "unumsys",
+            "ugender",
+            "ushape",
+            "ucnvsel",
+            "usprep",
         ];
 
         // C functions that will be made available to rust code.  Add more to this list if you want to
@@ -72,6 +91,8 @@ mod inner {
             "ubrk_.*",
             "ucal_.*",
             "ucnv_.*",
+            "ucnvsel_.*",
+            "usprep_.*",
             "ucol_.*",
             "ucsdet_.*",
             "udat_.*",
@@ -94,6 +115,24 @@ mod inner {
             "umutablecp.*",
             "ucp.*",
             "ures_.*",
+            "uset_.*",
+            "uregex_.*",
+            "ubidi_.*",
+            "uidna_.*",
+            "uspoof_.*",
+            "usearch_.*",
+            "ucasemap_.*",
+            "ufmtval_.*",
+            "ucfpos_.*",
+            "ureldatefmt_.*",
+            "uldn_.*",
+            "unumrf_.*",
+            "udtitvfmt_.*",
+            "ucurr_.*",
+            "ulocdata_.*",
+            "uscript_.*",
+            "unumsys_.*",
+            "ugender_.*",
         ];
 
         // Functions that take a C va_list argument cannot be called from Rust, so we
@@ -166,6 +205,30 @@ mod inner {
             "UCPTrieType",
             "UCPTRIE.*",
             "UPRV.*",
+            "URegex.*",
+            "URegularExpression",
+            "UBiDi.*",
+            "UIDNA.*",
+            "USpoof.*",
+            "URestrictionLevel",
+            "UStringSearch",
+            "USearch.*",
+            "UCaseMap",
+            "UConstrainedFieldPosition",
+            "URelativeDateTime.*",
+            "ULocaleDisplayNames",
+            "UDialectHandling",
+            "UNumberRange.*",
+            "UFormattedNumberRange",
+            "UDateIntervalFormat",
+            "UFormattedDateInterval",
+            "UCurr.*",
+            "UCurrencyUsage",
+            "ULocaleData.*",
+            "UMeasurementSystem",
+            "UScript.*",
+            "UStringPrep.*",
+            "UGender.*",
         ];
     }
 
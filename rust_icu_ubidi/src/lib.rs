@@ -0,0 +1,698 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU bidirectional text support for rust
+//!
+//! This crate implements the Unicode Bidirectional Algorithm (UAX #9), as implemented by the ICU
+//! library.  Specifically the functionality exposed through its C API, as available in the
+//! [header `ubidi.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ubidi_8h.html).
+//!
+//! The main type is [BiDi], which holds the result of analyzing a paragraph, or several
+//! paragraphs, of text.  The analysis yields the embedding level of each character, from which
+//! the text can be reordered for display.  When a paragraph is wrapped into several lines, each
+//! line is reordered separately by obtaining a [Line] from the paragraph.
+//!
+//! All indexes into the text are in UTF-16 code units, as is the case in the underlying ICU
+//! API.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_ubidi as ubidi;
+//!
+//! let mut bidi = ubidi::BiDi::try_new().expect("bidi");
+//! bidi.set_para("abc אבג (def)", ubidi::UBIDI_DEFAULT_LTR).expect("set_para");
+//! assert_eq!(sys::UBiDiDirection::UBIDI_MIXED, bidi.direction());
+//! assert_eq!(0, bidi.para_level());
+//!
+//! let visual = bidi.write_reordered(ubidi::UBIDI_DO_MIRRORING).expect("write_reordered");
+//! assert_eq!("abc גבא (def)", visual);
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        marker::PhantomData,
+        ops, ptr, slice,
+    },
+};
+
+/// Paragraph level for [BiDi::set_para]: determine the paragraph level from the text, and
+/// default to left-to-right if the text has no strong directional characters.
+pub const UBIDI_DEFAULT_LTR: sys::UBiDiLevel = 0xfe;
+
+/// Paragraph level for [BiDi::set_para]: determine the paragraph level from the text, and
+/// default to right-to-left if the text has no strong directional characters.
+pub const UBIDI_DEFAULT_RTL: sys::UBiDiLevel = 0xff;
+
+/// The highest explicit embedding level.
+pub const UBIDI_MAX_EXPLICIT_LEVEL: sys::UBiDiLevel = 125;
+
+/// Option for [BiDi::write_reordered]: keep combining characters after their base characters
+/// in right-to-left runs.
+pub const UBIDI_KEEP_BASE_COMBINING: u16 = 1;
+
+/// Option for [BiDi::write_reordered]: replace characters in right-to-left runs with their
+/// mirror-image characters, for example `(` with `)`.
+pub const UBIDI_DO_MIRRORING: u16 = 2;
+
+/// Option for [BiDi::write_reordered]: surround runs of numbers with LRM characters where
+/// needed to keep their order in the output.
+pub const UBIDI_INSERT_LRM_FOR_NUMERIC: u16 = 4;
+
+/// Option for [BiDi::write_reordered]: remove the bidirectional control characters from the
+/// output.
+pub const UBIDI_REMOVE_BIDI_CONTROLS: u16 = 8;
+
+/// Option for [BiDi::write_reordered]: write the output in reverse order, for right-to-left
+/// displays.
+pub const UBIDI_OUTPUT_REVERSE: u16 = 16;
+
+/// A paragraph of the analyzed text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paragraph {
+    /// The range of the paragraph in the text.
+    pub range: ops::Range<usize>,
+    /// The embedding level of the paragraph.
+    pub level: sys::UBiDiLevel,
+}
+
+/// A run of characters with the same direction, in visual order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisualRun {
+    /// The range of the run in the text, in logical order.
+    pub logical_range: ops::Range<usize>,
+    /// The direction of the run, either `UBIDI_LTR` or `UBIDI_RTL`.
+    pub direction: sys::UBiDiDirection,
+}
+
+/// The bidirectional analysis of a text.
+///
+/// The methods that query the result of the analysis are also available on a [Line].
+#[derive(Debug)]
+pub struct BiDi {
+    rep: ptr::NonNull<sys::UBiDi>,
+    // ICU does not copy the text passed to `ubidi_setPara`, so it is kept here.
+    text: ustring::UChar,
+}
+
+// Implements `ubidi_close`
+simple_drop_impl!(BiDi, ubidi_close);
+
+// Safety: the analysis has no affinity to the thread that created it, and the text it refers
+// to is owned.
+unsafe impl Send for BiDi {}
+
+impl BiDi {
+    /// Creates an empty analysis object.  Use [BiDi::set_para] to analyze a text.
+    ///
+    /// Implements `ubidi_open`
+    pub fn try_new() -> Result<Self, common::Error> {
+        let rep = unsafe { versioned_function!(ubidi_open)() };
+        let rep = ptr::NonNull::new(rep).ok_or(common::Error::Sys(
+            sys::UErrorCode::U_MEMORY_ALLOCATION_ERROR,
+        ))?;
+        Ok(BiDi {
+            rep,
+            text: ustring::UChar::from(vec![]),
+        })
+    }
+
+    /// Analyzes `text`, which may contain several paragraphs separated by paragraph
+    /// separators.
+    ///
+    /// `para_level` is the embedding level of the paragraphs: 0 for left-to-right, 1 for
+    /// right-to-left, or [UBIDI_DEFAULT_LTR] or [UBIDI_DEFAULT_RTL] to determine it from the
+    /// text.
+    ///
+    /// Implements `ubidi_setPara`
+    pub fn set_para(
+        &mut self,
+        text: &str,
+        para_level: sys::UBiDiLevel,
+    ) -> Result<(), common::Error> {
+        let text = ustring::UChar::try_from(text)?;
+        self.set_para_ustring(&text, para_level)
+    }
+
+    /// Implements `ubidi_setPara`
+    pub fn set_para_ustring(
+        &mut self,
+        text: &ustring::UChar,
+        para_level: sys::UBiDiLevel,
+    ) -> Result<(), common::Error> {
+        let text = text.clone();
+        let mut status = common::Error::OK_CODE;
+        // Unsafety note: ICU keeps pointing at the contents of `text`, which is moved into
+        // `self.text` below and therefore stays in place for as long as ICU uses it.
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_setPara)(
+                self.rep.as_ptr(),
+                text.as_c_ptr(),
+                text.len() as i32,
+                para_level,
+                ptr::null_mut(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        self.text = text;
+        Ok(())
+    }
+
+    /// Returns a [Line] object for the range `start..limit` of the text, which must be within
+    /// one paragraph.
+    ///
+    /// Implements `ubidi_setLine`
+    pub fn line(&self, start: usize, limit: usize) -> Result<Line<'_>, common::Error> {
+        let line = BiDi::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_setLine)(
+                self.rep.as_ptr(),
+                start as i32,
+                limit as i32,
+                line.rep.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(Line {
+            bidi: line,
+            _para: PhantomData,
+        })
+    }
+
+    /// Sets whether the text is reordered from visual to logical order, rather than the
+    /// other way around.  Must be called before [BiDi::set_para].
+    ///
+    /// Implements `ubidi_setInverse`
+    pub fn set_inverse(&mut self, inverse: bool) {
+        unsafe { versioned_function!(ubidi_setInverse)(self.rep.as_ptr(), inverse as sys::UBool) }
+    }
+
+    /// Implements `ubidi_isInverse`
+    pub fn is_inverse(&self) -> bool {
+        unsafe { versioned_function!(ubidi_isInverse)(self.rep.as_ptr()) != 0 }
+    }
+
+    /// Sets whether paragraph separators are always ordered as left-to-right.  Must be called
+    /// before [BiDi::set_para].
+    ///
+    /// Implements `ubidi_orderParagraphsLTR`
+    pub fn order_paragraphs_ltr(&mut self, ltr: bool) {
+        unsafe {
+            versioned_function!(ubidi_orderParagraphsLTR)(self.rep.as_ptr(), ltr as sys::UBool)
+        }
+    }
+
+    /// Implements `ubidi_isOrderParagraphsLTR`
+    pub fn is_order_paragraphs_ltr(&self) -> bool {
+        unsafe { versioned_function!(ubidi_isOrderParagraphsLTR)(self.rep.as_ptr()) != 0 }
+    }
+
+    /// Sets a variant of the reordering algorithm.  Must be called before [BiDi::set_para].
+    ///
+    /// Implements `ubidi_setReorderingMode`
+    pub fn set_reordering_mode(&mut self, mode: sys::UBiDiReorderingMode) {
+        unsafe { versioned_function!(ubidi_setReorderingMode)(self.rep.as_ptr(), mode) }
+    }
+
+    /// Implements `ubidi_getReorderingMode`
+    pub fn reordering_mode(&self) -> sys::UBiDiReorderingMode {
+        unsafe { versioned_function!(ubidi_getReorderingMode)(self.rep.as_ptr()) }
+    }
+
+    /// Sets the reordering options, a bitmask of [sys::UBiDiReorderingOption] values.  Must be
+    /// called before [BiDi::set_para].
+    ///
+    /// Implements `ubidi_setReorderingOptions`
+    pub fn set_reordering_options(&mut self, options: u32) {
+        unsafe { versioned_function!(ubidi_setReorderingOptions)(self.rep.as_ptr(), options) }
+    }
+
+    /// Implements `ubidi_getReorderingOptions`
+    pub fn reordering_options(&self) -> u32 {
+        unsafe { versioned_function!(ubidi_getReorderingOptions)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the direction of the text: `UBIDI_LTR` or `UBIDI_RTL` if it is unidirectional,
+    /// `UBIDI_MIXED` otherwise.
+    ///
+    /// Implements `ubidi_getDirection`
+    pub fn direction(&self) -> sys::UBiDiDirection {
+        unsafe { versioned_function!(ubidi_getDirection)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the length of the text.
+    ///
+    /// Implements `ubidi_getLength`
+    pub fn len(&self) -> usize {
+        unsafe { versioned_function!(ubidi_getLength)(self.rep.as_ptr()) as usize }
+    }
+
+    /// Returns true if the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the length of the text that was analyzed, which differs from [BiDi::len] only
+    /// when the `UBIDI_OPTION_STREAMING` option is used.
+    ///
+    /// Implements `ubidi_getProcessedLength`
+    pub fn processed_length(&self) -> usize {
+        unsafe { versioned_function!(ubidi_getProcessedLength)(self.rep.as_ptr()) as usize }
+    }
+
+    /// Returns the length of the reordered text, which differs from [BiDi::processed_length]
+    /// when marks are inserted or controls removed.
+    ///
+    /// Implements `ubidi_getResultLength`
+    pub fn result_length(&self) -> usize {
+        unsafe { versioned_function!(ubidi_getResultLength)(self.rep.as_ptr()) as usize }
+    }
+
+    /// Returns the embedding level of the first paragraph, or of the line.
+    ///
+    /// Implements `ubidi_getParaLevel`
+    pub fn para_level(&self) -> sys::UBiDiLevel {
+        unsafe { versioned_function!(ubidi_getParaLevel)(self.rep.as_ptr()) }
+    }
+
+    /// Implements `ubidi_countParagraphs`
+    pub fn count_paragraphs(&self) -> usize {
+        unsafe { versioned_function!(ubidi_countParagraphs)(self.rep.as_ptr()) as usize }
+    }
+
+    /// Returns the paragraph with the given index.
+    ///
+    /// Implements `ubidi_getParagraphByIndex`
+    pub fn paragraph(&self, index: usize) -> Result<Paragraph, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let mut start: i32 = 0;
+        let mut limit: i32 = 0;
+        let mut level: sys::UBiDiLevel = 0;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_getParagraphByIndex)(
+                self.rep.as_ptr(),
+                index as i32,
+                &mut start,
+                &mut limit,
+                &mut level,
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(Paragraph {
+            range: start as usize..limit as usize,
+            level,
+        })
+    }
+
+    /// Returns the embedding level of the character at `index`.
+    ///
+    /// Implements `ubidi_getLevelAt`
+    pub fn level_at(&self, index: usize) -> sys::UBiDiLevel {
+        unsafe { versioned_function!(ubidi_getLevelAt)(self.rep.as_ptr(), index as i32) }
+    }
+
+    /// Returns the embedding level of each character of the text.
+    ///
+    /// Implements `ubidi_getLevels`
+    pub fn levels(&self) -> Result<Vec<sys::UBiDiLevel>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let levels = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_getLevels)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        let len = self.processed_length();
+        if len == 0 {
+            return Ok(vec![]);
+        }
+        // Unsafety note: ICU returns an array of the processed length, which stays valid until
+        // the analysis is changed.
+        Ok(unsafe { slice::from_raw_parts(levels, len) }.to_vec())
+    }
+
+    /// Returns the number of runs of characters with the same direction.
+    ///
+    /// Implements `ubidi_countRuns`
+    pub fn count_runs(&self) -> Result<usize, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let count = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_countRuns)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(count as usize)
+    }
+
+    /// Returns the run at `index` in visual order, or an error if there are not as many runs.
+    ///
+    /// Implements `ubidi_getVisualRun`
+    pub fn visual_run(&self, index: usize) -> Result<VisualRun, common::Error> {
+        // `ubidi_getVisualRun` has no status; it reports an out of range `index` as an empty run.
+        if index >= self.count_runs()? {
+            return Err(common::Error::Sys(
+                sys::UErrorCode::U_INDEX_OUTOFBOUNDS_ERROR,
+            ));
+        }
+        let mut start: i32 = 0;
+        let mut length: i32 = 0;
+        let direction = unsafe {
+            versioned_function!(ubidi_getVisualRun)(
+                self.rep.as_ptr(),
+                index as i32,
+                &mut start,
+                &mut length,
+            )
+        };
+        Ok(VisualRun {
+            logical_range: start as usize..(start + length) as usize,
+            direction,
+        })
+    }
+
+    /// Returns all runs in visual order.
+    pub fn visual_runs(&self) -> Result<Vec<VisualRun>, common::Error> {
+        let count = self.count_runs()?;
+        (0..count).map(|i| self.visual_run(i)).collect()
+    }
+
+    /// Returns the visual position of the character at logical position `index`, or `None` if
+    /// the character is removed from the output.
+    ///
+    /// Implements `ubidi_getVisualIndex`
+    pub fn visual_index(&self, index: usize) -> Result<Option<usize>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_getVisualIndex)(self.rep.as_ptr(), index as i32, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result.try_into().ok())
+    }
+
+    /// Returns the logical position of the character at visual position `index`, or `None`
+    /// if the character is an inserted mark.
+    ///
+    /// Implements `ubidi_getLogicalIndex`
+    pub fn logical_index(&self, index: usize) -> Result<Option<usize>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_getLogicalIndex)(self.rep.as_ptr(), index as i32, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result.try_into().ok())
+    }
+
+    /// Returns the visual position of each character, in logical order.  See
+    /// [BiDi::visual_index].
+    ///
+    /// Implements `ubidi_getLogicalMap`
+    pub fn logical_map(&self) -> Result<Vec<Option<usize>>, common::Error> {
+        let mut map: Vec<i32> = vec![0; self.processed_length()];
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_getLogicalMap)(
+                self.rep.as_ptr(),
+                map.as_mut_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(map.into_iter().map(|i| i.try_into().ok()).collect())
+    }
+
+    /// Returns the logical position of each character, in visual order.  See
+    /// [BiDi::logical_index].
+    ///
+    /// Implements `ubidi_getVisualMap`
+    pub fn visual_map(&self) -> Result<Vec<Option<usize>>, common::Error> {
+        let mut map: Vec<i32> = vec![0; self.result_length()];
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ubidi_getVisualMap)(
+                self.rep.as_ptr(),
+                map.as_mut_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(map.into_iter().map(|i| i.try_into().ok()).collect())
+    }
+
+    /// Returns the text in visual order.  `options` is a bitmask of [UBIDI_DO_MIRRORING] and
+    /// the other `UBIDI_*` write options defined in this crate.
+    ///
+    /// Implements `ubidi_writeReordered`
+    pub fn write_reordered(&self, options: u16) -> Result<String, common::Error> {
+        let result = self.write_reordered_ustring(options)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `ubidi_writeReordered`
+    pub fn write_reordered_ustring(&self, options: u16) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 200;
+        buffered_uchar_method_with_retry!(
+            write_reordered_raw,
+            CAPACITY,
+            [rep: *mut sys::UBiDi,],
+            [options: u16,]
+        );
+        write_reordered_raw(
+            versioned_function!(ubidi_writeReordered),
+            self.rep.as_ptr(),
+            options,
+        )
+    }
+}
+
+/// The bidirectional analysis of one line of a paragraph, obtained from [BiDi::line].
+///
+/// All the query methods of [BiDi] are available on a line, and report positions relative to
+/// the start of the line.
+#[derive(Debug)]
+pub struct Line<'a> {
+    bidi: BiDi,
+    // The line refers to the paragraph's analysis, which must outlive it.
+    _para: PhantomData<&'a BiDi>,
+}
+
+impl<'a> ops::Deref for Line<'a> {
+    type Target = BiDi;
+
+    fn deref(&self) -> &BiDi {
+        &self.bidi
+    }
+}
+
+/// Returns the base direction of `text`, from its first character with a strong direction, or
+/// `UBIDI_NEUTRAL` if there is none.
+///
+/// Implements `ubidi_getBaseDirection`
+pub fn base_direction(text: &str) -> Result<sys::UBiDiDirection, common::Error> {
+    let text = ustring::UChar::try_from(text)?;
+    Ok(unsafe { versioned_function!(ubidi_getBaseDirection)(text.as_c_ptr(), text.len() as i32) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "abc " followed by the Hebrew letters alef, bet, gimel, and " (def)".
+    const MIXED: &str = "abc אבג (def)";
+
+    #[test]
+    fn direction_and_levels() {
+        let mut bidi = BiDi::try_new().expect("bidi");
+        bidi.set_para(MIXED, UBIDI_DEFAULT_LTR).expect("set_para");
+        assert_eq!(sys::UBiDiDirection::UBIDI_MIXED, bidi.direction());
+        assert_eq!(0, bidi.para_level());
+        assert_eq!(13, bidi.len());
+        assert_eq!(
+            vec![0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            bidi.levels().expect("levels")
+        );
+        assert_eq!(1, bidi.level_at(5));
+
+        bidi.set_para("אבג abc", UBIDI_DEFAULT_LTR)
+            .expect("set_para");
+        assert_eq!(1, bidi.para_level());
+        bidi.set_para("123", UBIDI_DEFAULT_RTL).expect("set_para");
+        assert_eq!(1, bidi.para_level());
+        bidi.set_para("abc", 1).expect("set_para");
+        assert_eq!(1, bidi.para_level());
+        assert_eq!(sys::UBiDiDirection::UBIDI_MIXED, bidi.direction());
+    }
+
+    #[test]
+    fn base_direction_of_text() {
+        assert_eq!(
+            sys::UBiDiDirection::UBIDI_RTL,
+            base_direction("123 אבג abc").unwrap()
+        );
+        assert_eq!(
+            sys::UBiDiDirection::UBIDI_LTR,
+            base_direction("abc אבג").unwrap()
+        );
+        assert_eq!(
+            sys::UBiDiDirection::UBIDI_NEUTRAL,
+            base_direction("123 !").unwrap()
+        );
+    }
+
+    #[test]
+    fn visual_runs() {
+        let mut bidi = BiDi::try_new().expect("bidi");
+        bidi.set_para(MIXED, UBIDI_DEFAULT_LTR).expect("set_para");
+        assert_eq!(3, bidi.count_runs().expect("count_runs"));
+        assert_eq!(
+            vec![
+                VisualRun {
+                    logical_range: 0..4,
+                    direction: sys::UBiDiDirection::UBIDI_LTR
+                },
+                VisualRun {
+                    logical_range: 4..7,
+                    direction: sys::UBiDiDirection::UBIDI_RTL
+                },
+                VisualRun {
+                    logical_range: 7..13,
+                    direction: sys::UBiDiDirection::UBIDI_LTR
+                },
+            ],
+            bidi.visual_runs().expect("visual_runs")
+        );
+        assert_eq!(
+            Some(sys::UErrorCode::U_INDEX_OUTOFBOUNDS_ERROR),
+            bidi.visual_run(3).err().and_then(|e| match e {
+                common::Error::Sys(code) => Some(code),
+                _ => None,
+            })
+        );
+    }
+
+    #[test]
+    fn index_maps() {
+        let mut bidi = BiDi::try_new().expect("bidi");
+        bidi.set_para("ab אבג", 0).expect("set_para");
+        assert_eq!(Some(5), bidi.visual_index(3).unwrap());
+        assert_eq!(Some(3), bidi.logical_index(5).unwrap());
+        let expected: Vec<Option<usize>> = vec![0, 1, 2, 5, 4, 3].into_iter().map(Some).collect();
+        assert_eq!(expected, bidi.logical_map().unwrap());
+        assert_eq!(expected, bidi.visual_map().unwrap());
+        bidi.visual_index(6).expect_err("out of range");
+
+        bidi.set_reordering_options(
+            sys::UBiDiReorderingOption::UBIDI_OPTION_REMOVE_CONTROLS as u32,
+        );
+        // "a", right-to-left mark, "b".
+        bidi.set_para("a\u{200f}b", 0).expect("set_para");
+        assert_eq!(2, bidi.result_length());
+        assert_eq!(vec![Some(0), None, Some(1)], bidi.logical_map().unwrap());
+        assert_eq!(vec![Some(0), Some(2)], bidi.visual_map().unwrap());
+    }
+
+    #[test]
+    fn write_reordered() {
+        let mut bidi = BiDi::try_new().expect("bidi");
+        bidi.set_para("אבג (def)", UBIDI_DEFAULT_LTR)
+            .expect("set_para");
+        assert_eq!(
+            "(def) גבא",
+            bidi.write_reordered(UBIDI_DO_MIRRORING).unwrap()
+        );
+        assert_eq!(")def( גבא", bidi.write_reordered(0).unwrap());
+        assert_eq!(
+            "אבג )fed(",
+            bidi.write_reordered(UBIDI_DO_MIRRORING | UBIDI_OUTPUT_REVERSE)
+                .unwrap()
+        );
+
+        let long = "abc אבג ".repeat(50);
+        bidi.set_para(&long, 0).expect("set_para");
+        assert_eq!("abc גבא ".repeat(50), bidi.write_reordered(0).unwrap());
+    }
+
+    #[test]
+    fn paragraphs_and_lines() {
+        let mut bidi = BiDi::try_new().expect("bidi");
+        bidi.set_para("abc אבג\nאבג abc", UBIDI_DEFAULT_LTR)
+            .expect("set_para");
+        assert_eq!(2, bidi.count_paragraphs());
+        assert_eq!(
+            Paragraph {
+                range: 0..8,
+                level: 0
+            },
+            bidi.paragraph(0).unwrap()
+        );
+        assert_eq!(
+            Paragraph {
+                range: 8..15,
+                level: 1
+            },
+            bidi.paragraph(1).unwrap()
+        );
+        bidi.paragraph(2).expect_err("no such paragraph");
+
+        let line = bidi.line(8, 12).expect("line");
+        assert_eq!(4, line.len());
+        assert_eq!(1, line.para_level());
+        assert_eq!(sys::UBiDiDirection::UBIDI_RTL, line.direction());
+        assert_eq!(" גבא", line.write_reordered(0).unwrap());
+        assert_eq!(Some(3), line.visual_index(0).unwrap());
+
+        bidi.line(4, 10).expect_err("line crosses paragraphs");
+    }
+
+    #[test]
+    fn settings() {
+        let mut bidi = BiDi::try_new().expect("bidi");
+        assert!(!bidi.is_inverse());
+        bidi.set_inverse(true);
+        assert!(bidi.is_inverse());
+        bidi.order_paragraphs_ltr(true);
+        assert!(bidi.is_order_paragraphs_ltr());
+        bidi.set_reordering_mode(sys::UBiDiReorderingMode::UBIDI_REORDER_RUNS_ONLY);
+        assert_eq!(
+            sys::UBiDiReorderingMode::UBIDI_REORDER_RUNS_ONLY,
+            bidi.reordering_mode()
+        );
+        bidi.set_reordering_options(sys::UBiDiReorderingOption::UBIDI_OPTION_STREAMING as u32);
+        assert_eq!(
+            sys::UBiDiReorderingOption::UBIDI_OPTION_STREAMING as u32,
+            bidi.reordering_options()
+        );
+    }
+}
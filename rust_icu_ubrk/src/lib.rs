@@ -318,6 +318,12 @@ impl UBreakIterator {
         Ok(rules)
     }
 
+    /// Returns the underlying ICU break iterator, for use with other ICU APIs that accept a
+    /// `UBreakIterator`.  The text and rules of the iterator remain owned by `self`.
+    pub fn as_ptr(&self) -> *const sys::UBreakIterator {
+        self.rep.as_ptr()
+    }
+
     /// Performs a clone of the underlying representation.
     ///
     /// The cloned break iterator will hold pointers to the same text, and rules,
@@ -19,8 +19,8 @@
 
 use {
     log::trace, rust_icu_common as common, rust_icu_sys as sys, rust_icu_sys::versioned_function,
-    rust_icu_sys::*, rust_icu_uenum as uenum, rust_icu_ustring as ustring, std::convert::TryFrom,
-    std::ffi,
+    rust_icu_sys::*, rust_icu_uenum as uenum, rust_icu_ustring as ustring,
+    std::convert::{TryFrom, TryInto}, std::ffi,
 };
 
 /// Implements the UCalendar type from `ucal.h`.
@@ -92,6 +92,12 @@ impl UCalendar {
         self.rep
     }
 
+    /// Returns this UCalendar's internal C representation, for the low-level C functions that
+    /// may modify the calendar.
+    pub fn as_c_calendar_mut(&mut self) -> *mut sys::UCalendar {
+        self.rep
+    }
+
     /// Sets the calendar's current date/time in milliseconds since the epoch.
     ///
     /// Implements `ucal_setMillis`.
@@ -201,6 +207,39 @@ impl UCalendar {
         common::Error::ok_or_warning(status)?;
         Ok(value)
     }
+
+    /// Returns the type of this calendar, such as "gregorian" or "buddhist".
+    ///
+    /// Implements `ucal_getType`.
+    pub fn get_type(&self) -> Result<String, common::Error> {
+        let mut status: UErrorCode = common::Error::OK_CODE;
+        let raw = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucal_getType)(self.as_c_calendar(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: the type is a C string with static storage in ICU.
+        let c_str = unsafe { ffi::CStr::from_ptr(raw) };
+        Ok(c_str.to_str()?.to_owned())
+    }
+
+    /// Returns the ID of the time zone of this calendar, such as "America/New_York".
+    ///
+    /// Implements `ucal_getTimeZoneID`.
+    pub fn get_time_zone_id(&self) -> Result<String, common::Error> {
+        const CAPACITY: usize = 64;
+        ustring::buffered_uchar_method_with_retry!(
+            get_time_zone_id_impl,
+            CAPACITY,
+            [cal: *const sys::UCalendar,],
+            []
+        );
+        let zone_id = get_time_zone_id_impl(
+            versioned_function!(ucal_getTimeZoneID),
+            self.as_c_calendar(),
+        )?;
+        String::try_from(&zone_id)
+    }
 }
 
 /// Implements `ucal_setDefaultTimeZone`
@@ -465,6 +504,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_type_and_time_zone_id() -> Result<(), common::Error> {
+        let cal = UCalendar::new("America/New_York", "th-TH", UCalendarType::UCAL_DEFAULT)?;
+        assert_eq!(cal.get_type()?, "buddhist");
+        assert_eq!(cal.get_time_zone_id()?, "America/New_York");
+
+        let cal = UCalendar::new("Asia/Tokyo", "ja-JP", UCalendarType::UCAL_GREGORIAN)?;
+        assert_eq!(cal.get_type()?, "gregorian");
+        assert_eq!(cal.get_time_zone_id()?, "Asia/Tokyo");
+        Ok(())
+    }
+
     #[test]
     fn test_offsets_and_daylight_time() -> Result<(), common::Error> {
         let mut cal = UCalendar::new("America/New_York", "en-US", UCalendarType::UCAL_GREGORIAN)?;
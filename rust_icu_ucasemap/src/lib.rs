@@ -0,0 +1,530 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU locale-sensitive case mapping for rust
+//!
+//! This crate converts text to lowercase, uppercase and titlecase, and case-folds it, following
+//! the rules of a locale.  For example, uppercasing "i" gives "İ" in Turkish, and lowercasing
+//! "Ì" gives "i̇̀" in Lithuanian, neither of which `str::to_uppercase` and `str::to_lowercase`
+//! do.  It is a wrapper around the ICU library, specifically the functionality exposed through
+//! its C API, as available in the [header
+//! `ucasemap.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ucasemap_8h.html)
+//! and the case mapping functions of
+//! [`ustring.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ustring_8h.html).
+//!
+//! Each mapping is available for UTF-8 text, as `&str`, and for UTF-16 text, as
+//! [ustring::UChar].
+//!
+//! The crate does not report which parts of the text changed, or map positions between the
+//! original and the mapped text.  ICU only provides this through the C++ `icu::Edits` class,
+//! which its C API does not expose.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_ucasemap as ucasemap;
+//!
+//! let turkish = ucasemap::UCaseMap::try_new("tr", ucasemap::Options::default()).unwrap();
+//! assert_eq!("İSTANBUL", turkish.to_upper("istanbul").unwrap());
+//! assert_eq!("ıi", turkish.to_lower("Iİ").unwrap());
+//!
+//! let mut dutch = ucasemap::UCaseMap::try_new("nl", ucasemap::Options::default()).unwrap();
+//! assert_eq!("IJsselmeer", dutch.to_title("ijsselmeer").unwrap());
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::{flag_set, simple_drop_impl},
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ubrk as ubrk, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{convert::TryInto, ffi, os::raw, ptr, slice},
+};
+
+flag_set! {
+    /// Options for case mapping, from the `U_FOLD_CASE_*` and `U_TITLECASE_*` constants of
+    /// `stringoptions.h`.  The empty set is the default behavior.
+    Options {
+        /// When case-folding, map dotted I and dotless i as appropriate for Turkic languages,
+        /// rather than with the default mappings.
+        FOLD_CASE_EXCLUDE_SPECIAL_I = 0x1,
+        /// When titlecasing, treat the whole string as a single word.
+        TITLECASE_WHOLE_STRING = 0x20,
+        /// When titlecasing, titlecase the start of each sentence rather than of each word.
+        TITLECASE_SENTENCES = 0x40,
+        /// When titlecasing, do not lowercase the characters that follow the first
+        /// character of each word.
+        TITLECASE_NO_LOWERCASE = 0x100,
+        /// When titlecasing, titlecase the character at each break, rather than the first
+        /// cased character after it.
+        TITLECASE_NO_BREAK_ADJUSTMENT = 0x200,
+        /// When titlecasing, titlecase the first cased character after each break, rather
+        /// than the first letter, number, symbol or private use character after it.
+        TITLECASE_ADJUST_TO_CASED = 0x400,
+    }
+}
+
+// The case mappings available from ICU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mapping {
+    Lower,
+    Upper,
+    Title,
+    Fold,
+}
+
+// Clones `break_iterator` for the case mapper, which closes the iterator that it adopts.
+//
+// Implements `ubrk_clone`.
+#[cfg(feature = "icu_version_69_plus")]
+fn clone_break_iterator(
+    break_iterator: &ubrk::UBreakIterator,
+) -> Result<*mut sys::UBreakIterator, common::Error> {
+    let mut status = common::Error::OK_CODE;
+    let clone = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ubrk_clone)(break_iterator.as_ptr(), &mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(clone)
+}
+
+// Clones `break_iterator` for the case mapper, which closes the iterator that it adopts.
+// ICU versions before 69 do not have `ubrk_clone`.
+//
+// Implements `ubrk_safeClone`.
+#[cfg(not(feature = "icu_version_69_plus"))]
+fn clone_break_iterator(
+    break_iterator: &ubrk::UBreakIterator,
+) -> Result<*mut sys::UBreakIterator, common::Error> {
+    let mut status = common::Error::OK_CODE;
+    let clone = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ubrk_safeClone)(
+            break_iterator.as_ptr(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+            &mut status,
+        )
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(clone)
+}
+
+/// A locale-sensitive case mapper.
+pub struct UCaseMap {
+    rep: ptr::NonNull<sys::UCaseMap>,
+    // The caseMap adopts a clone of this iterator, which may refer to rules held here.
+    break_iterator: Option<ubrk::UBreakIterator>,
+}
+
+// Implements `ucasemap_close`.
+simple_drop_impl!(UCaseMap, ucasemap_close);
+
+impl UCaseMap {
+    /// Creates a case mapper for `locale`, such as "tr" or "lt".  The empty string selects
+    /// the root locale.
+    ///
+    /// Implements `ucasemap_open`.
+    pub fn try_new(locale: &str, options: Options) -> Result<UCaseMap, common::Error> {
+        let locale = ffi::CString::new(locale)?;
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucasemap_open)(locale.as_ptr(), options.bits(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UCaseMap {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            break_iterator: None,
+        })
+    }
+
+    /// Returns the locale of the case mapper.
+    ///
+    /// Implements `ucasemap_getLocale`.
+    pub fn locale(&self) -> String {
+        // Unsafety note: the locale is a NUL-terminated string owned by the case mapper.
+        let locale = unsafe {
+            ffi::CStr::from_ptr(versioned_function!(ucasemap_getLocale)(self.rep.as_ptr()))
+        };
+        locale.to_string_lossy().into_owned()
+    }
+
+    /// Implements `ucasemap_setLocale`.
+    pub fn set_locale(&mut self, locale: &str) -> Result<(), common::Error> {
+        let locale = ffi::CString::new(locale)?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucasemap_setLocale)(
+                self.rep.as_ptr(),
+                locale.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Implements `ucasemap_getOptions`.
+    pub fn options(&self) -> Options {
+        Options::from_bits(unsafe { versioned_function!(ucasemap_getOptions)(self.rep.as_ptr()) })
+    }
+
+    /// Sets the case mapping options.
+    ///
+    /// The titlecasing break iterator is chosen the first time that text is titlecased, so
+    /// setting [Options::TITLECASE_WHOLE_STRING] or [Options::TITLECASE_SENTENCES] after that
+    /// has no effect.  Use [UCaseMap::try_new] or [UCaseMap::set_break_iterator] instead.
+    ///
+    /// Implements `ucasemap_setOptions`.
+    pub fn set_options(&mut self, options: Options) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucasemap_setOptions)(
+                self.rep.as_ptr(),
+                options.bits(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Sets the break iterator that finds the words, or other units, to titlecase.  By
+    /// default a word break iterator for the locale of the case mapper is used.
+    ///
+    /// The case mapper sets the text of the iterator when titlecasing, so the text that the
+    /// iterator was created with does not matter.
+    ///
+    /// Implements `ucasemap_setBreakIterator`.
+    pub fn set_break_iterator(
+        &mut self,
+        break_iterator: ubrk::UBreakIterator,
+    ) -> Result<(), common::Error> {
+        let clone = clone_break_iterator(&break_iterator)?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucasemap_setBreakIterator)(self.rep.as_ptr(), clone, &mut status);
+        }
+        common::Error::ok_or_warning(status)?;
+        self.break_iterator = Some(break_iterator);
+        Ok(())
+    }
+
+    /// Lowercases UTF-8 text.
+    ///
+    /// Implements `ucasemap_utf8ToLower`.
+    pub fn to_lower(&self, text: &str) -> Result<String, common::Error> {
+        self.map_utf8(Mapping::Lower, text)
+    }
+
+    /// Uppercases UTF-8 text.
+    ///
+    /// Implements `ucasemap_utf8ToUpper`.
+    pub fn to_upper(&self, text: &str) -> Result<String, common::Error> {
+        self.map_utf8(Mapping::Upper, text)
+    }
+
+    /// Titlecases UTF-8 text, using the break iterator of the case mapper to find words.
+    ///
+    /// Implements `ucasemap_utf8ToTitle`.
+    pub fn to_title(&mut self, text: &str) -> Result<String, common::Error> {
+        self.map_utf8(Mapping::Title, text)
+    }
+
+    /// Case-folds UTF-8 text, for caseless matching.
+    ///
+    /// Implements `ucasemap_utf8FoldCase`.
+    pub fn fold_case(&self, text: &str) -> Result<String, common::Error> {
+        self.map_utf8(Mapping::Fold, text)
+    }
+
+    /// Lowercases UTF-16 text.
+    ///
+    /// Implements `u_strToLower`.
+    pub fn to_lower_ustring(&self, text: &ustring::UChar) -> Result<ustring::UChar, common::Error> {
+        self.map_utf16(Mapping::Lower, units(text))
+    }
+
+    /// Uppercases UTF-16 text.
+    ///
+    /// Implements `u_strToUpper`.
+    pub fn to_upper_ustring(&self, text: &ustring::UChar) -> Result<ustring::UChar, common::Error> {
+        self.map_utf16(Mapping::Upper, units(text))
+    }
+
+    /// Titlecases UTF-16 text, using the break iterator of the case mapper to find words.
+    ///
+    /// Implements `ucasemap_toTitle`.
+    pub fn to_title_ustring(
+        &mut self,
+        text: &ustring::UChar,
+    ) -> Result<ustring::UChar, common::Error> {
+        self.map_utf16(Mapping::Title, units(text))
+    }
+
+    /// Case-folds UTF-16 text, for caseless matching.
+    ///
+    /// Implements `u_strFoldCase`.
+    pub fn fold_case_ustring(
+        &self,
+        text: &ustring::UChar,
+    ) -> Result<ustring::UChar, common::Error> {
+        self.map_utf16(Mapping::Fold, units(text))
+    }
+
+    // Titlecasing takes a mutable case mapper, as it sets the text of its break iterator.
+    // The public titlecasing methods take `&mut self` for this reason.
+    fn map_utf8(&self, mapping: Mapping, text: &str) -> Result<String, common::Error> {
+        const CAPACITY: usize = 200;
+        let rep = self.rep.as_ptr();
+        let len: i32 = text.len().try_into().map_err(common::Error::wrapper)?;
+        let src = text.as_ptr() as *const raw::c_char;
+        common::buffered_string_method_with_retry(
+            |buf, cap, status| unsafe {
+                match mapping {
+                    Mapping::Lower => {
+                        versioned_function!(ucasemap_utf8ToLower)(rep, buf, cap, src, len, status)
+                    }
+                    Mapping::Upper => {
+                        versioned_function!(ucasemap_utf8ToUpper)(rep, buf, cap, src, len, status)
+                    }
+                    Mapping::Title => {
+                        versioned_function!(ucasemap_utf8ToTitle)(rep, buf, cap, src, len, status)
+                    }
+                    Mapping::Fold => {
+                        versioned_function!(ucasemap_utf8FoldCase)(rep, buf, cap, src, len, status)
+                    }
+                }
+            },
+            CAPACITY,
+        )
+    }
+
+    fn map_utf16(
+        &self,
+        mapping: Mapping,
+        text: &[sys::UChar],
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 200;
+        let len: i32 = text.len().try_into().map_err(common::Error::wrapper)?;
+        let src = text.as_ptr();
+        match mapping {
+            Mapping::Lower | Mapping::Upper => {
+                buffered_uchar_method_with_retry!(
+                    case_impl,
+                    CAPACITY,
+                    [],
+                    [src: *const sys::UChar, len: i32, locale: *const raw::c_char,]
+                );
+                // Unsafety note: the locale is owned by the case mapper.
+                let locale = unsafe { versioned_function!(ucasemap_getLocale)(self.rep.as_ptr()) };
+                let function = if mapping == Mapping::Lower {
+                    versioned_function!(u_strToLower)
+                } else {
+                    versioned_function!(u_strToUpper)
+                };
+                case_impl(function, src, len, locale)
+            }
+            Mapping::Title => {
+                buffered_uchar_method_with_retry!(
+                    title_impl,
+                    CAPACITY,
+                    [rep: *mut sys::UCaseMap,],
+                    [src: *const sys::UChar, len: i32,]
+                );
+                title_impl(
+                    versioned_function!(ucasemap_toTitle),
+                    self.rep.as_ptr(),
+                    src,
+                    len,
+                )
+            }
+            Mapping::Fold => {
+                buffered_uchar_method_with_retry!(
+                    fold_impl,
+                    CAPACITY,
+                    [],
+                    [src: *const sys::UChar, len: i32, options: u32,]
+                );
+                let options = self.options().bits() & Options::FOLD_CASE_EXCLUDE_SPECIAL_I.bits();
+                fold_impl(versioned_function!(u_strFoldCase), src, len, options)
+            }
+        }
+    }
+}
+
+// Returns the code units of `text`.
+fn units(text: &ustring::UChar) -> &[sys::UChar] {
+    if text.is_empty() {
+        return &[];
+    }
+    // Unsafety note: `text` holds `text.len()` code units starting at `text.as_c_ptr()`.
+    unsafe { slice::from_raw_parts(text.as_c_ptr(), text.len()) }
+}
+
+/// Lowercases `text` following the rules of `locale`.
+///
+/// This is a shorthand for [UCaseMap::to_lower].
+pub fn to_lower(locale: &str, text: &str) -> Result<String, common::Error> {
+    UCaseMap::try_new(locale, Options::default())?.to_lower(text)
+}
+
+/// Uppercases `text` following the rules of `locale`.
+///
+/// This is a shorthand for [UCaseMap::to_upper].
+pub fn to_upper(locale: &str, text: &str) -> Result<String, common::Error> {
+    UCaseMap::try_new(locale, Options::default())?.to_upper(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn root() -> UCaseMap {
+        UCaseMap::try_new("", Options::default()).expect("case map")
+    }
+
+    #[test]
+    fn turkish_and_lithuanian() {
+        let tr = UCaseMap::try_new("tr", Options::default()).unwrap();
+        assert_eq!("TİTLE İ I", tr.to_upper("title i ı").unwrap());
+        assert_eq!("title i ı", tr.to_lower("TİTLE İ I").unwrap());
+        assert_eq!("TITLE I I", root().to_upper("title i ı").unwrap());
+
+        let lt = UCaseMap::try_new("lt", Options::default()).unwrap();
+        assert_eq!("i\u{307}\u{300}", lt.to_lower("Ì").unwrap());
+        assert_eq!("ì", root().to_lower("Ì").unwrap());
+        assert_eq!("I", lt.to_upper("i\u{307}").unwrap());
+
+        assert_eq!("İ", to_upper("tr", "i").unwrap());
+        assert_eq!("ı", to_lower("az", "I").unwrap());
+    }
+
+    #[test]
+    fn locale_and_options() {
+        let mut map = UCaseMap::try_new("tr", Options::default()).unwrap();
+        assert_eq!("tr", map.locale());
+        assert_eq!(Options::default(), map.options());
+
+        map.set_locale("en").unwrap();
+        assert_eq!("en", map.locale());
+        assert_eq!("I", map.to_upper("i").unwrap());
+
+        map.set_options(Options::TITLECASE_NO_LOWERCASE).unwrap();
+        assert_eq!(Options::TITLECASE_NO_LOWERCASE, map.options());
+    }
+
+    #[test]
+    fn fold_case() {
+        let mut map = root();
+        assert_eq!("strasse s→s", map.fold_case("STRAẞE ſ→S").unwrap());
+        assert_eq!("i\u{307}", map.fold_case("İ").unwrap());
+
+        map.set_options(Options::FOLD_CASE_EXCLUDE_SPECIAL_I)
+            .unwrap();
+        assert_eq!("i", map.fold_case("İ").unwrap());
+        assert_eq!("ı", map.fold_case("I").unwrap());
+
+        let text = ustring::UChar::try_from("I").unwrap();
+        let folded = map.fold_case_ustring(&text).unwrap();
+        assert_eq!("ı", String::try_from(&folded).unwrap());
+    }
+
+    #[test]
+    fn title() {
+        let mut map = root();
+        assert_eq!(
+            "The Quick Brown Fox",
+            map.to_title("the qUICK brown fox").unwrap()
+        );
+
+        map.set_options(Options::TITLECASE_NO_LOWERCASE).unwrap();
+        assert_eq!(
+            "The QUICK Brown Fox",
+            map.to_title("the qUICK brown fox").unwrap()
+        );
+
+        // The break iterator is chosen on first use, so the whole string option is given here.
+        let mut map = UCaseMap::try_new("", Options::TITLECASE_WHOLE_STRING).unwrap();
+        assert_eq!(
+            "The quick brown fox",
+            map.to_title("the qUICK brown fox").unwrap()
+        );
+
+        let mut dutch = UCaseMap::try_new("nl", Options::default()).unwrap();
+        assert_eq!("IJssel", dutch.to_title("ijssel").unwrap());
+    }
+
+    #[test]
+    fn title_with_break_iterator() {
+        let mut map = root();
+        // Sentence breaks titlecase the first word of each sentence only.
+        let iter = ubrk::UBreakIterator::try_new(sys::UBreakIteratorType::UBRK_SENTENCE, "en", "")
+            .unwrap();
+        map.set_break_iterator(iter).unwrap();
+        assert_eq!(
+            "Hello world. Goodbye world.",
+            map.to_title("hello WORLD. GOODBYE world.").unwrap()
+        );
+
+        let text = ustring::UChar::try_from("one two. THREE").unwrap();
+        let title = map.to_title_ustring(&text).unwrap();
+        assert_eq!("One two. Three", String::try_from(&title).unwrap());
+
+        // A rule-based iterator that breaks between all characters.
+        let iter = ubrk::UBreakIterator::try_new_rules("[\\p{Any}];", "").unwrap();
+        map.set_break_iterator(iter).unwrap();
+        assert_eq!("ABC", map.to_title("abc").unwrap());
+    }
+
+    #[test]
+    fn utf16() {
+        let mut map = UCaseMap::try_new("tr", Options::default()).unwrap();
+        let text = ustring::UChar::try_from("istanbul").unwrap();
+        let upper = map.to_upper_ustring(&text).unwrap();
+        assert_eq!("İSTANBUL", String::try_from(&upper).unwrap());
+        let lower = map.to_lower_ustring(&upper).unwrap();
+        assert_eq!("istanbul", String::try_from(&lower).unwrap());
+        let title = map.to_title_ustring(&text).unwrap();
+        assert_eq!("İstanbul", String::try_from(&title).unwrap());
+
+        // Long enough to need a second call with a larger buffer.
+        let long = "ß".repeat(150);
+        let upper = map.to_upper(&long).unwrap();
+        assert_eq!("SS".repeat(150), upper);
+        let upper = map
+            .to_upper_ustring(&ustring::UChar::try_from(long.as_str()).unwrap())
+            .unwrap();
+        assert_eq!(300, upper.len());
+    }
+
+    #[test]
+    fn empty() {
+        let mut map = root();
+        assert_eq!("", map.to_upper("").unwrap());
+        assert_eq!("", map.to_title("").unwrap());
+        assert_eq!("", map.to_lower("").unwrap());
+        let text = ustring::UChar::try_from("").unwrap();
+        assert!(map.fold_case_ustring(&text).unwrap().is_empty());
+    }
+}
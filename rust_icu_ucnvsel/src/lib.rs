@@ -0,0 +1,379 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU converter selection for rust
+//!
+//! This crate finds the character encodings, out of a list of candidates, that can represent
+//! all of a text.  This is useful for picking a legacy encoding to export text in.  It is a
+//! wrapper around the ICU library, specifically the functionality exposed through its C API,
+//! as available in the [header
+//! `ucnvsel.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ucnvsel_8h.html).
+//! The selected encodings can be used with the converters of `rust_icu_ucnv`.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_ucnvsel as ucnvsel;
+//!
+//! let selector = ucnvsel::UConverterSelector::try_new(
+//!     &["ISO-8859-1", "Shift_JIS", "UTF-8"],
+//!     None,
+//!     sys::UConverterUnicodeSet::UCNV_ROUNDTRIP_SET,
+//! )
+//! .unwrap();
+//! let selected: Vec<String> = selector
+//!     .select_for_str("日本語")
+//!     .unwrap()
+//!     .map(|name| name.unwrap())
+//!     .collect();
+//! assert_eq!(vec!["Shift_JIS", "UTF-8"], selected);
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ucnv as ucnv, rust_icu_uenum as uenum, rust_icu_uset as uset,
+    rust_icu_ustring as ustring,
+    std::{marker::PhantomData, os::raw, ptr},
+};
+
+/// Selects the converters, out of a list of candidates, that can convert a text.
+#[derive(Debug)]
+pub struct UConverterSelector {
+    rep: ptr::NonNull<sys::UConverterSelector>,
+    // The serialized form that a selector opened with
+    // [UConverterSelector::try_from_serialized] reads from, which must outlive it.  ICU
+    // requires it to be 32-bit aligned.
+    _serialized: Option<Vec<u32>>,
+}
+
+// Implements `ucnvsel_close`.
+simple_drop_impl!(UConverterSelector, ucnvsel_close);
+
+impl UConverterSelector {
+    /// Creates a selector among the converters named in `converters`, or among all available
+    /// converters if `converters` is empty.
+    ///
+    /// The code points in `excluded` do not affect the selection, for example because the
+    /// caller handles them by escaping.  `which_set` chooses whether a converter must map
+    /// the text exactly, with `UCNV_ROUNDTRIP_SET`, or may also use fallback mappings, with
+    /// `UCNV_ROUNDTRIP_AND_FALLBACK_SET`.
+    ///
+    /// Implements `ucnvsel_open`.
+    pub fn try_new(
+        converters: &[&str],
+        excluded: Option<&uset::USet>,
+        which_set: sys::UConverterUnicodeSet,
+    ) -> Result<Self, common::Error> {
+        let converters = common::CStringVec::new(converters)?;
+        let list = if converters.is_empty() {
+            ptr::null()
+        } else {
+            converters.as_c_array()
+        };
+        let excluded = excluded.map_or(ptr::null(), |set| set.as_ptr());
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucnvsel_open)(
+                list,
+                converters.len() as i32,
+                excluded,
+                which_set,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UConverterSelector {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            _serialized: None,
+        })
+    }
+
+    /// Recreates a selector from the output of [UConverterSelector::serialize], which may come
+    /// from a machine of different endianness.  This is much faster than creating the selector
+    /// anew.
+    ///
+    /// Implements `ucnvsel_openFromSerialized`.
+    pub fn try_from_serialized(serialized: &[u8]) -> Result<Self, common::Error> {
+        let serialized: Vec<u32> = serialized
+            .chunks(4)
+            .map(|chunk| {
+                let mut word = [0u8; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u32::from_ne_bytes(word)
+            })
+            .collect();
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucnvsel_openFromSerialized)(
+                serialized.as_ptr() as *const raw::c_void,
+                (serialized.len() * 4) as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UConverterSelector {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            _serialized: Some(serialized),
+        })
+    }
+
+    /// Returns the serialized form of the selector.
+    ///
+    /// Implements `ucnvsel_serialize`.
+    pub fn serialize(&self) -> Result<Vec<u8>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let len = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucnvsel_serialize)(
+                self.rep.as_ptr(),
+                ptr::null_mut(),
+                0,
+                &mut status,
+            )
+        };
+        common::Error::ok_preflight(status)?;
+        let mut buffer: Vec<u32> = vec![0; (len as usize).div_ceil(4)];
+        status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucnvsel_serialize)(
+                self.rep.as_ptr(),
+                buffer.as_mut_ptr() as *mut raw::c_void,
+                (buffer.len() * 4) as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        let mut result: Vec<u8> = buffer.iter().flat_map(|word| word.to_ne_bytes()).collect();
+        result.truncate(len as usize);
+        Ok(result)
+    }
+
+    /// Returns the names of the converters that can convert all of `text`, apart from the
+    /// excluded code points, in the order in which they were given to the selector.
+    ///
+    /// Implements `ucnvsel_selectForUTF8`.
+    pub fn select_for_str(&self, text: &str) -> Result<Selected<'_>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucnvsel_selectForUTF8)(
+                self.rep.as_ptr(),
+                text.as_ptr() as *const raw::c_char,
+                text.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Selected::from_raw(rep))
+    }
+
+    /// Returns the names of the converters that can convert all of `text`, as
+    /// [UConverterSelector::select_for_str] does for UTF-8 text.
+    ///
+    /// Implements `ucnvsel_selectForString`.
+    pub fn select_for_ustring(&self, text: &ustring::UChar) -> Result<Selected<'_>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucnvsel_selectForString)(
+                self.rep.as_ptr(),
+                text.as_c_ptr(),
+                text.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Selected::from_raw(rep))
+    }
+
+    /// Converts `text` with each of the selected converters, and returns the name of the
+    /// converter with the shortest output, together with that output.  Of converters with
+    /// outputs of equal length, the one given to the selector first wins.  Returns `None` if
+    /// no converter can convert the text.
+    ///
+    /// Excluded code points are converted as the converter does by default, usually to a
+    /// substitution character.
+    pub fn smallest_encoding(
+        &self,
+        text: &str,
+    ) -> Result<Option<(String, Vec<u8>)>, common::Error> {
+        let mut smallest: Option<(String, Vec<u8>)> = None;
+        for name in self.select_for_str(text)? {
+            let name = name?;
+            let encoded = ucnv::utf8::Converter::open(&name)?.convert_from_utf8(text)?;
+            if smallest
+                .as_ref()
+                .is_none_or(|(_, bytes)| encoded.len() < bytes.len())
+            {
+                smallest = Some((name, encoded));
+            }
+        }
+        Ok(smallest)
+    }
+}
+
+/// The names of the converters that a [UConverterSelector] selected.  They are valid as long
+/// as the selector is.
+#[derive(Debug)]
+pub struct Selected<'sel> {
+    rep: uenum::Enumeration,
+    _selector: PhantomData<&'sel UConverterSelector>,
+}
+
+impl Selected<'_> {
+    fn from_raw(rep: *mut sys::UEnumeration) -> Self {
+        Selected {
+            // Unsafety note: the enumeration was just returned by ICU, and the lifetime of
+            // `Selected` keeps it from outliving the selector.
+            rep: unsafe { uenum::Enumeration::from_raw_parts(None, rep) },
+            _selector: PhantomData,
+        }
+    }
+}
+
+impl Iterator for Selected<'_> {
+    type Item = Result<String, common::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rep.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::convert::TryFrom};
+
+    const CANDIDATES: &[&str] = &["ISO-8859-1", "Shift_JIS", "EUC-JP", "UTF-8"];
+
+    fn selected(selector: &UConverterSelector, text: &str) -> Vec<String> {
+        selector
+            .select_for_str(text)
+            .unwrap()
+            .map(|name| name.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn select() {
+        let selector = UConverterSelector::try_new(
+            CANDIDATES,
+            None,
+            sys::UConverterUnicodeSet::UCNV_ROUNDTRIP_SET,
+        )
+        .unwrap();
+        assert_eq!(CANDIDATES.to_vec(), selected(&selector, "Hello"));
+        assert_eq!(
+            vec!["ISO-8859-1", "EUC-JP", "UTF-8"],
+            selected(&selector, "café")
+        );
+        assert_eq!(
+            vec!["Shift_JIS", "EUC-JP", "UTF-8"],
+            selected(&selector, "日本語")
+        );
+        assert_eq!(vec!["UTF-8"], selected(&selector, "日本語 😀"));
+
+        let text = ustring::UChar::try_from("日本語").unwrap();
+        let names: Vec<String> = selector
+            .select_for_ustring(&text)
+            .unwrap()
+            .map(|name| name.unwrap())
+            .collect();
+        assert_eq!(vec!["Shift_JIS", "EUC-JP", "UTF-8"], names);
+    }
+
+    #[test]
+    fn excluded() {
+        let mut excluded = uset::USet::new_empty();
+        excluded.add_string("😀");
+        let selector = UConverterSelector::try_new(
+            CANDIDATES,
+            Some(&excluded),
+            sys::UConverterUnicodeSet::UCNV_ROUNDTRIP_SET,
+        )
+        .unwrap();
+        assert_eq!(
+            vec!["Shift_JIS", "EUC-JP", "UTF-8"],
+            selected(&selector, "日本語 😀")
+        );
+    }
+
+    #[test]
+    fn smallest_encoding() {
+        let selector = UConverterSelector::try_new(
+            &["UTF-8", "EUC-JP", "Shift_JIS"],
+            None,
+            sys::UConverterUnicodeSet::UCNV_ROUNDTRIP_SET,
+        )
+        .unwrap();
+        let (name, bytes) = selector.smallest_encoding("日本語").unwrap().unwrap();
+        assert_eq!("EUC-JP", name);
+        assert_eq!(6, bytes.len());
+
+        // Plain ASCII is the same length in all three, so the first candidate wins.
+        let (name, bytes) = selector.smallest_encoding("abc").unwrap().unwrap();
+        assert_eq!("UTF-8", name);
+        assert_eq!(b"abc".to_vec(), bytes);
+
+        let selector = UConverterSelector::try_new(
+            &["Shift_JIS"],
+            None,
+            sys::UConverterUnicodeSet::UCNV_ROUNDTRIP_SET,
+        )
+        .unwrap();
+        assert_eq!(None, selector.smallest_encoding("😀").unwrap());
+    }
+
+    #[test]
+    fn serialize() {
+        let selector = UConverterSelector::try_new(
+            CANDIDATES,
+            None,
+            sys::UConverterUnicodeSet::UCNV_ROUNDTRIP_SET,
+        )
+        .unwrap();
+        let serialized = selector.serialize().unwrap();
+        drop(selector);
+
+        let selector = UConverterSelector::try_from_serialized(&serialized).unwrap();
+        assert_eq!(
+            vec!["Shift_JIS", "EUC-JP", "UTF-8"],
+            selected(&selector, "日本語")
+        );
+    }
+
+    #[test]
+    fn all_converters() {
+        let selector =
+            UConverterSelector::try_new(&[], None, sys::UConverterUnicodeSet::UCNV_ROUNDTRIP_SET)
+                .unwrap();
+        let names = selected(&selector, "ASCII");
+        assert!(names.len() > 10);
+        assert!(names.iter().any(|name| name == "UTF-8"));
+        assert_eq!(
+            ucnv::available_converters().count(),
+            selected(&selector, "").len()
+        );
+    }
+}
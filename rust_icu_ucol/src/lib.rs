@@ -204,6 +204,29 @@ impl UCollator {
         }
     }
 
+    /// Returns the underlying ICU collator, for use with other ICU APIs that accept a
+    /// `UCollator`.
+    pub fn as_ptr(&self) -> *const sys::UCollator {
+        self.rep.as_ptr()
+    }
+
+    /// Returns the locale, valid or actual, of this collator.
+    ///
+    /// Implements `ucol_getLocaleByType`.
+    pub fn get_locale_by_type(
+        &self,
+        data_loc_type: sys::ULocDataLocaleType,
+    ) -> Result<String, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let raw = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucol_getLocaleByType)(self.rep.as_ptr(), data_loc_type, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        let c_str = unsafe { ffi::CStr::from_ptr(raw) };
+        Ok(c_str.to_str()?.to_owned())
+    }
+
     /// Implements `ucol_getStrength`.
     pub fn get_strength(&self) -> sys::UCollationStrength {
         let result = unsafe { versioned_function!(ucol_getStrength)(self.rep.as_ptr()) };
@@ -273,6 +296,17 @@ mod tests {
         assert_ne!(0, available.iter().count());
     }
 
+    #[test]
+    fn get_locale_by_type_test() -> Result<(), common::Error> {
+        let collator = crate::UCollator::try_from("sv-FI@collation=search")?;
+        // Finland Swedish sorts as Swedish, and the collation type is kept.
+        assert_eq!(
+            "sv@collation=search",
+            collator.get_locale_by_type(sys::ULocDataLocaleType::ULOC_VALID_LOCALE)?
+        );
+        Ok(())
+    }
+
     #[test]
     fn strcoll_utf8_test() -> Result<(), common::Error> {
         let collator = crate::UCollator::try_from("sr-Latn")?;
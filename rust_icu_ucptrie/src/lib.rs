@@ -0,0 +1,580 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU code point trie support for rust
+//!
+//! This crate provides code point tries: compact, fast lookup tables which map each Unicode code
+//! point to an integer value.  It is based on the implementation in the ICU library,
+//! specifically the functionality exposed through its C API, as available in the headers
+//! [`ucptrie.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ucptrie_8h.html)
+//! and [`umutablecptrie.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/umutablecptrie_8h.html).
+//!
+//! A trie is built with a [MutableCodePointTrie], and then frozen into an immutable
+//! [CodePointTrie], which can be serialized to bytes and loaded back.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_ucptrie as ucptrie;
+//!
+//! let mut builder = ucptrie::MutableCodePointTrie::try_new(0, 0xff).expect("builder");
+//! builder.set_range('a' as sys::UChar32, 'z' as sys::UChar32, 1).expect("set_range");
+//! builder.set('_' as sys::UChar32, 2).expect("set");
+//!
+//! let trie = builder
+//!     .build(
+//!         sys::UCPTrieType::UCPTRIE_TYPE_SMALL,
+//!         sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_8,
+//!     )
+//!     .expect("build");
+//! assert_eq!(1, trie.get('q' as sys::UChar32));
+//! assert_eq!(2, trie.get('_' as sys::UChar32));
+//! assert_eq!(0, trie.get('Q' as sys::UChar32));
+//!
+//! let bytes = trie.to_bytes().expect("to_bytes");
+//! let loaded = ucptrie::CodePointTrie::try_from_bytes(
+//!     sys::UCPTrieType::UCPTRIE_TYPE_ANY,
+//!     sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_ANY,
+//!     &bytes,
+//! )
+//! .expect("loaded");
+//! assert_eq!(1, loaded.get('q' as sys::UChar32));
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    std::{ffi, ops, ptr},
+};
+
+/// A range of code points which all map to the same value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Range {
+    /// The code points in the range.
+    pub code_points: ops::RangeInclusive<sys::UChar32>,
+    /// The value that all code points in the range map to.
+    pub value: u32,
+}
+
+/// A mutable code point trie, used to build a [CodePointTrie].
+#[derive(Debug)]
+pub struct MutableCodePointTrie {
+    rep: ptr::NonNull<sys::UMutableCPTrie>,
+}
+
+// Implements `umutablecptrie_close`
+simple_drop_impl!(MutableCodePointTrie, umutablecptrie_close);
+
+// Safety: the mutable trie has no affinity to the thread that created it.
+unsafe impl Send for MutableCodePointTrie {}
+
+impl MutableCodePointTrie {
+    /// Creates a new trie, in which all code points map to `initial_value`.  `error_value` is
+    /// returned when looking up out-of-range code points.
+    ///
+    /// Implements `umutablecptrie_open`
+    pub fn try_new(initial_value: u32, error_value: u32) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(umutablecptrie_open)(initial_value, error_value, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(MutableCodePointTrie {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Creates a mutable trie with the same contents as `trie`.
+    ///
+    /// Implements `umutablecptrie_fromUCPTrie`
+    pub fn try_from_trie(trie: &CodePointTrie) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(umutablecptrie_fromUCPTrie)(trie.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(MutableCodePointTrie {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Makes an independent copy of this trie.
+    ///
+    /// Implements `umutablecptrie_clone`
+    pub fn try_clone(&self) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(umutablecptrie_clone)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(MutableCodePointTrie {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Returns the value for the code point `c`.
+    ///
+    /// Implements `umutablecptrie_get`
+    pub fn get(&self, c: sys::UChar32) -> u32 {
+        unsafe { versioned_function!(umutablecptrie_get)(self.rep.as_ptr(), c) }
+    }
+
+    /// Sets the value for the code point `c`.
+    ///
+    /// Implements `umutablecptrie_set`
+    pub fn set(&mut self, c: sys::UChar32, value: u32) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(umutablecptrie_set)(self.rep.as_ptr(), c, value, &mut status)
+        };
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Sets the value for all code points in the range `start..=end`.
+    ///
+    /// Implements `umutablecptrie_setRange`
+    pub fn set_range(
+        &mut self,
+        start: sys::UChar32,
+        end: sys::UChar32,
+        value: u32,
+    ) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(umutablecptrie_setRange)(
+                self.rep.as_ptr(),
+                start,
+                end,
+                value,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Builds an immutable trie of the given type and value width.  Values which do not fit in
+    /// `value_width` bits are truncated.
+    ///
+    /// The builder is compacted in the process, but can still be used afterwards.
+    ///
+    /// Implements `umutablecptrie_buildImmutable`
+    pub fn build(
+        &mut self,
+        trie_type: sys::UCPTrieType,
+        value_width: sys::UCPTrieValueWidth,
+    ) -> Result<CodePointTrie, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(umutablecptrie_buildImmutable)(
+                self.rep.as_ptr(),
+                trie_type,
+                value_width,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(CodePointTrie {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            _data: None,
+        })
+    }
+
+    /// Returns an iterator over the ranges of code points which map to the same value.
+    ///
+    /// Implements `umutablecptrie_getRange`
+    pub fn ranges(&self) -> Ranges<'_> {
+        self.ranges_with_option(sys::UCPMapRangeOption::UCPMAP_RANGE_NORMAL, 0)
+    }
+
+    /// Returns an iterator over the ranges of code points which map to the same value, treating
+    /// surrogates as specified by `option`.  Surrogates selected by `option` are treated as
+    /// mapping to `surrogate_value`.
+    ///
+    /// Implements `umutablecptrie_getRange`
+    pub fn ranges_with_option(
+        &self,
+        option: sys::UCPMapRangeOption,
+        surrogate_value: u32,
+    ) -> Ranges<'_> {
+        Ranges {
+            trie: Trie::Mutable(self),
+            option,
+            surrogate_value,
+            start: 0,
+        }
+    }
+}
+
+/// An immutable code point trie.
+///
+/// Lookups are fast, and an immutable trie can be shared between threads.
+#[derive(Debug)]
+pub struct CodePointTrie {
+    rep: ptr::NonNull<sys::UCPTrie>,
+    // A trie loaded from bytes refers to the bytes rather than copying them, so they are kept
+    // here.  `u32` makes sure the data is suitably aligned.
+    _data: Option<Vec<u32>>,
+}
+
+// Implements `ucptrie_close`
+simple_drop_impl!(CodePointTrie, ucptrie_close);
+
+// Safety: the trie is immutable after construction.
+unsafe impl Send for CodePointTrie {}
+unsafe impl Sync for CodePointTrie {}
+
+impl CodePointTrie {
+    /// Loads a trie from its serialized form, as produced by [CodePointTrie::to_bytes].
+    ///
+    /// `trie_type` and `value_width` must match the serialized trie, or be
+    /// `UCPTRIE_TYPE_ANY` and `UCPTRIE_VALUE_BITS_ANY` respectively to accept any.
+    ///
+    /// Implements `ucptrie_openFromBinary`
+    pub fn try_from_bytes(
+        trie_type: sys::UCPTrieType,
+        value_width: sys::UCPTrieValueWidth,
+        bytes: &[u8],
+    ) -> Result<Self, common::Error> {
+        let mut data: Vec<u32> = vec![0; bytes.len().div_ceil(4)];
+        // Unsafety note: data has room for at least bytes.len() bytes.
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), data.as_mut_ptr() as *mut u8, bytes.len())
+        };
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucptrie_openFromBinary)(
+                trie_type,
+                value_width,
+                data.as_ptr() as *const ffi::c_void,
+                bytes.len() as i32,
+                ptr::null_mut(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(CodePointTrie {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            _data: Some(data),
+        })
+    }
+
+    /// Serializes this trie into bytes, which can be loaded back with
+    /// [CodePointTrie::try_from_bytes].
+    ///
+    /// Implements `ucptrie_toBinary`
+    pub fn to_bytes(&self) -> Result<Vec<u8>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let len = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucptrie_toBinary)(
+                self.rep.as_ptr(),
+                ptr::null_mut(),
+                0,
+                &mut status,
+            )
+        };
+        common::Error::ok_preflight(status)?;
+        let mut result: Vec<u8> = vec![0; len as usize];
+        status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucptrie_toBinary)(
+                self.rep.as_ptr(),
+                result.as_mut_ptr() as *mut ffi::c_void,
+                result.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+
+    /// Returns the type of this trie.
+    ///
+    /// Implements `ucptrie_getType`
+    pub fn get_type(&self) -> sys::UCPTrieType {
+        unsafe { versioned_function!(ucptrie_getType)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the number of bits in each value of this trie.
+    ///
+    /// Implements `ucptrie_getValueWidth`
+    pub fn get_value_width(&self) -> sys::UCPTrieValueWidth {
+        unsafe { versioned_function!(ucptrie_getValueWidth)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the value for the code point `c`.
+    ///
+    /// Implements `ucptrie_get`
+    pub fn get(&self, c: sys::UChar32) -> u32 {
+        unsafe { versioned_function!(ucptrie_get)(self.rep.as_ptr(), c) }
+    }
+
+    /// Returns an iterator over the ranges of code points which map to the same value.
+    ///
+    /// Implements `ucptrie_getRange`
+    pub fn ranges(&self) -> Ranges<'_> {
+        self.ranges_with_option(sys::UCPMapRangeOption::UCPMAP_RANGE_NORMAL, 0)
+    }
+
+    /// Returns an iterator over the ranges of code points which map to the same value, treating
+    /// surrogates as specified by `option`.  Surrogates selected by `option` are treated as
+    /// mapping to `surrogate_value`.
+    ///
+    /// Implements `ucptrie_getRange`
+    pub fn ranges_with_option(
+        &self,
+        option: sys::UCPMapRangeOption,
+        surrogate_value: u32,
+    ) -> Ranges<'_> {
+        Ranges {
+            trie: Trie::Immutable(self),
+            option,
+            surrogate_value,
+            start: 0,
+        }
+    }
+}
+
+/// An iterator over the value ranges of a trie.
+///
+/// Created by [CodePointTrie::ranges] or [MutableCodePointTrie::ranges].
+pub struct Ranges<'a> {
+    trie: Trie<'a>,
+    option: sys::UCPMapRangeOption,
+    surrogate_value: u32,
+    start: sys::UChar32,
+}
+
+// The trie that a [Ranges] iterates over.
+enum Trie<'a> {
+    Mutable(&'a MutableCodePointTrie),
+    Immutable(&'a CodePointTrie),
+}
+
+impl Iterator for Ranges<'_> {
+    type Item = Range;
+
+    fn next(&mut self) -> Option<Range> {
+        let mut value: u32 = 0;
+        let end = match self.trie {
+            Trie::Mutable(trie) => unsafe {
+                versioned_function!(umutablecptrie_getRange)(
+                    trie.rep.as_ptr(),
+                    self.start,
+                    self.option,
+                    self.surrogate_value,
+                    None,
+                    ptr::null(),
+                    &mut value,
+                )
+            },
+            Trie::Immutable(trie) => unsafe {
+                versioned_function!(ucptrie_getRange)(
+                    trie.rep.as_ptr(),
+                    self.start,
+                    self.option,
+                    self.surrogate_value,
+                    None,
+                    ptr::null(),
+                    &mut value,
+                )
+            },
+        };
+        if end < 0 {
+            return None;
+        }
+        let range = Range {
+            code_points: self.start..=end,
+            value,
+        };
+        self.start = end + 1;
+        Some(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cp(c: char) -> sys::UChar32 {
+        c as sys::UChar32
+    }
+
+    fn builder() -> MutableCodePointTrie {
+        let mut builder = MutableCodePointTrie::try_new(0, 0xff).expect("builder");
+        builder.set_range(cp('a'), cp('z'), 1).expect("set_range");
+        builder.set(cp('_'), 2).expect("set");
+        builder
+            .set_range(0x1F600, 0x1F64F, 0x12345)
+            .expect("set_range");
+        builder
+    }
+
+    #[test]
+    fn mutable_get_set() {
+        let mut builder = builder();
+        assert_eq!(1, builder.get(cp('m')));
+        assert_eq!(2, builder.get(cp('_')));
+        assert_eq!(0, builder.get(cp('A')));
+        assert_eq!(0xff, builder.get(0x110000));
+
+        let copy = builder.try_clone().expect("clone");
+        builder.set(cp('m'), 3).expect("set");
+        assert_eq!(3, builder.get(cp('m')));
+        assert_eq!(1, copy.get(cp('m')));
+
+        builder
+            .set(0x110000, 1)
+            .expect_err("out of range code point");
+    }
+
+    #[test]
+    fn build_all_types_and_widths() {
+        use sys::UCPTrieType::*;
+        use sys::UCPTrieValueWidth::*;
+        for trie_type in &[UCPTRIE_TYPE_FAST, UCPTRIE_TYPE_SMALL] {
+            for (width, emoji) in &[
+                (UCPTRIE_VALUE_BITS_8, 0x45),
+                (UCPTRIE_VALUE_BITS_16, 0x2345),
+                (UCPTRIE_VALUE_BITS_32, 0x12345),
+            ] {
+                let trie = builder().build(*trie_type, *width).expect("build");
+                assert_eq!(*trie_type, trie.get_type());
+                assert_eq!(*width, trie.get_value_width());
+                assert_eq!(1, trie.get(cp('k')));
+                assert_eq!(2, trie.get(cp('_')));
+                assert_eq!(0, trie.get(cp('-')));
+                assert_eq!(*emoji, trie.get(0x1F60A), "{:?}/{:?}", trie_type, width);
+            }
+        }
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let trie = builder()
+            .build(
+                sys::UCPTrieType::UCPTRIE_TYPE_FAST,
+                sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_32,
+            )
+            .expect("build");
+        let bytes = trie.to_bytes().expect("to_bytes");
+        let loaded = CodePointTrie::try_from_bytes(
+            sys::UCPTrieType::UCPTRIE_TYPE_ANY,
+            sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_ANY,
+            &bytes,
+        )
+        .expect("loaded");
+        assert_eq!(sys::UCPTrieType::UCPTRIE_TYPE_FAST, loaded.get_type());
+        assert_eq!(0x12345, loaded.get(0x1F600));
+        assert_eq!(
+            trie.ranges().collect::<Vec<_>>(),
+            loaded.ranges().collect::<Vec<_>>()
+        );
+
+        CodePointTrie::try_from_bytes(
+            sys::UCPTrieType::UCPTRIE_TYPE_SMALL,
+            sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_ANY,
+            &bytes,
+        )
+        .expect_err("type mismatch");
+        CodePointTrie::try_from_bytes(
+            sys::UCPTrieType::UCPTRIE_TYPE_ANY,
+            sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_ANY,
+            &bytes[..8],
+        )
+        .expect_err("truncated");
+    }
+
+    #[test]
+    fn ranges() {
+        let expected = vec![
+            Range {
+                code_points: 0..=cp('_') - 1,
+                value: 0,
+            },
+            Range {
+                code_points: cp('_')..=cp('_'),
+                value: 2,
+            },
+            Range {
+                code_points: cp('_') + 1..=cp('a') - 1,
+                value: 0,
+            },
+            Range {
+                code_points: cp('a')..=cp('z'),
+                value: 1,
+            },
+            Range {
+                code_points: cp('z') + 1..=0x1F5FF,
+                value: 0,
+            },
+            Range {
+                code_points: 0x1F600..=0x1F64F,
+                value: 0x12345,
+            },
+            Range {
+                code_points: 0x1F650..=0x10FFFF,
+                value: 0,
+            },
+        ];
+        let mut builder = builder();
+        assert_eq!(expected, builder.ranges().collect::<Vec<_>>());
+
+        let trie = builder
+            .build(
+                sys::UCPTrieType::UCPTRIE_TYPE_SMALL,
+                sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_32,
+            )
+            .expect("build");
+        assert_eq!(expected, trie.ranges().collect::<Vec<_>>());
+
+        let with_surrogates = trie
+            .ranges_with_option(sys::UCPMapRangeOption::UCPMAP_RANGE_FIXED_ALL_SURROGATES, 7)
+            .collect::<Vec<_>>();
+        assert!(with_surrogates.contains(&Range {
+            code_points: 0xD800..=0xDFFF,
+            value: 7
+        }));
+    }
+
+    #[test]
+    fn mutable_from_trie() {
+        let trie = builder()
+            .build(
+                sys::UCPTrieType::UCPTRIE_TYPE_FAST,
+                sys::UCPTrieValueWidth::UCPTRIE_VALUE_BITS_16,
+            )
+            .expect("build");
+        let mut builder = MutableCodePointTrie::try_from_trie(&trie).expect("from trie");
+        assert_eq!(1, builder.get(cp('a')));
+        builder.set(cp('A'), 5).expect("set");
+        assert_eq!(5, builder.get(cp('A')));
+        assert_eq!(0, trie.get(cp('A')));
+    }
+}
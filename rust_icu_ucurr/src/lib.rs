@@ -0,0 +1,465 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU currency data for rust
+//!
+//! This crate provides the data about ISO 4217 currencies that ICU uses to format amounts of
+//! money: the currency of a region, the number of fraction digits and the rounding increment,
+//! and the display names and symbols.  It is a wrapper around the ICU library, specifically the
+//! functionality exposed through its C API, as available in the [header
+//! `ucurr.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ucurr_8h.html).
+//!
+//! Currencies are given by their three letter ISO 4217 codes, such as "USD".
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_ucurr as ucurr;
+//!
+//! assert_eq!("JPY", ucurr::for_locale("ja_JP").unwrap());
+//! assert_eq!(
+//!     0,
+//!     ucurr::fraction_digits("JPY", sys::UCurrencyUsage::UCURR_USAGE_STANDARD).unwrap()
+//! );
+//! assert_eq!(
+//!     "¥",
+//!     ucurr::name("JPY", "en", sys::UCurrNameStyle::UCURR_SYMBOL_NAME).unwrap()
+//! );
+//! ```
+
+use {
+    rust_icu_common as common, rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_uenum as uenum, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ffi, slice,
+    },
+};
+
+rust_icu_common::flag_set! {
+    /// The types of currencies to list with [iso_currencies].  Combine them with `|` to select
+    /// the currencies that have all of the types, for example `COMMON | NON_DEPRECATED` for
+    /// the currencies in current use.
+    CurrencyTypes {
+        /// All ISO 4217 currencies.
+        ALL = 0x7fffffff,
+        /// Currencies in common use, which usually have bank notes or coins.
+        COMMON = 1,
+        /// Fund codes, precious metals and other codes for special financial products.
+        UNCOMMON = 2,
+        /// Currencies that are no longer in use.
+        DEPRECATED = 4,
+        /// Currencies that are still in use, that is legal tender.
+        NON_DEPRECATED = 8,
+    }
+}
+
+// Converts an ISO 4217 code into the zero-terminated string that ICU expects.
+fn iso_code(currency: &str) -> Result<ustring::UChar, common::Error> {
+    let mut code = ustring::UChar::try_from(currency)?;
+    code.make_z();
+    Ok(code)
+}
+
+/// Returns the currency of `locale`, which is that of its region unless the locale has a
+/// `currency` keyword, as in "de_CH@currency=EUR".
+///
+/// Implements `ucurr_forLocale`.
+pub fn for_locale(locale: &str) -> Result<String, common::Error> {
+    let locale = ffi::CString::new(locale)?;
+    const CAPACITY: usize = 4;
+    buffered_uchar_method_with_retry!(
+        for_locale_impl,
+        CAPACITY,
+        [locale: *const std::os::raw::c_char,],
+        []
+    );
+    let result = for_locale_impl(versioned_function!(ucurr_forLocale), locale.as_ptr())?;
+    String::try_from(&result)
+}
+
+/// Returns the currencies that were legal tender in the region of `locale` at `date`, most
+/// recently introduced first.  This gives the history of the currencies of a region, such as
+/// the German mark being replaced by the euro.
+///
+/// Implements `ucurr_countCurrencies` and `ucurr_forLocaleAndDate`.
+pub fn for_locale_and_date(locale: &str, date: sys::UDate) -> Result<Vec<String>, common::Error> {
+    let locale = ffi::CString::new(locale)?;
+    let mut status = common::Error::OK_CODE;
+    let count = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_countCurrencies)(locale.as_ptr(), date, &mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    const CAPACITY: usize = 4;
+    buffered_uchar_method_with_retry!(
+        for_locale_and_date_impl,
+        CAPACITY,
+        [
+            locale: *const std::os::raw::c_char,
+            date: sys::UDate,
+            index: i32,
+        ],
+        []
+    );
+    // The index is 1-based.
+    (1..=count)
+        .map(|index| {
+            let result = for_locale_and_date_impl(
+                versioned_function!(ucurr_forLocaleAndDate),
+                locale.as_ptr(),
+                date,
+                index,
+            )?;
+            String::try_from(&result)
+        })
+        .collect()
+}
+
+/// Returns true if `currency` was legal tender at any time between `from` and `to`.
+///
+/// Implements `ucurr_isAvailable`.
+pub fn is_available(
+    currency: &str,
+    from: sys::UDate,
+    to: sys::UDate,
+) -> Result<bool, common::Error> {
+    let currency = iso_code(currency)?;
+    let mut status = common::Error::OK_CODE;
+    let result = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_isAvailable)(currency.as_c_ptr(), from, to, &mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(result != 0)
+}
+
+/// Returns the number of fraction digits that amounts of `currency` are shown with, for the
+/// given `usage`.  For example, the Japanese yen has 0 digits, and the Swiss franc has 2.
+///
+/// Implements `ucurr_getDefaultFractionDigitsForUsage`.
+pub fn fraction_digits(currency: &str, usage: sys::UCurrencyUsage) -> Result<i32, common::Error> {
+    let currency = iso_code(currency)?;
+    let mut status = common::Error::OK_CODE;
+    let result = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_getDefaultFractionDigitsForUsage)(
+            currency.as_c_ptr(),
+            usage,
+            &mut status,
+        )
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(result)
+}
+
+/// Returns the increment that amounts of `currency` are rounded to, for the given `usage`, or
+/// 0 if they are not rounded beyond their fraction digits.  For example, cash amounts of Swiss
+/// francs are rounded to 0.05.
+///
+/// Implements `ucurr_getRoundingIncrementForUsage`.
+pub fn rounding_increment(
+    currency: &str,
+    usage: sys::UCurrencyUsage,
+) -> Result<f64, common::Error> {
+    let currency = iso_code(currency)?;
+    let mut status = common::Error::OK_CODE;
+    let result = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_getRoundingIncrementForUsage)(
+            currency.as_c_ptr(),
+            usage,
+            &mut status,
+        )
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(result)
+}
+
+// Copies a string that ICU returned along with its length.
+fn to_string(rep: *const sys::UChar, len: i32) -> Result<String, common::Error> {
+    if rep.is_null() || len <= 0 {
+        return Ok(String::new());
+    }
+    // Unsafety note: ICU returns `len` code units of static data.
+    let units = unsafe { slice::from_raw_parts(rep, len as usize) };
+    String::try_from(&ustring::UChar::from(units.to_vec()))
+}
+
+/// Returns the name of `currency` in `locale`, in the given `style`, such as the symbol "$"
+/// or the long name "US Dollar" for USD in English.  These are the symbols that the number
+/// formatters use.  If the locale has no name for the currency, the ISO code is returned.
+///
+/// Implements `ucurr_getName`.
+pub fn name(
+    currency: &str,
+    locale: &str,
+    style: sys::UCurrNameStyle,
+) -> Result<String, common::Error> {
+    let currency = iso_code(currency)?;
+    let locale = ffi::CString::new(locale)?;
+    let mut is_choice_format: sys::UBool = 0;
+    let mut len = 0;
+    let mut status = common::Error::OK_CODE;
+    let rep = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_getName)(
+            currency.as_c_ptr(),
+            locale.as_ptr(),
+            style,
+            &mut is_choice_format,
+            &mut len,
+            &mut status,
+        )
+    };
+    common::Error::ok_or_warning(status)?;
+    to_string(rep, len)
+}
+
+/// Returns the long name of `currency` in `locale`, for the plural category `plural_count`,
+/// such as "US dollars" for the category "other" in English.
+///
+/// Implements `ucurr_getPluralName`.
+pub fn plural_name(
+    currency: &str,
+    locale: &str,
+    plural_count: &str,
+) -> Result<String, common::Error> {
+    let currency = iso_code(currency)?;
+    let locale = ffi::CString::new(locale)?;
+    let plural_count = ffi::CString::new(plural_count)?;
+    let mut is_choice_format: sys::UBool = 0;
+    let mut len = 0;
+    let mut status = common::Error::OK_CODE;
+    let rep = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_getPluralName)(
+            currency.as_c_ptr(),
+            locale.as_ptr(),
+            &mut is_choice_format,
+            plural_count.as_ptr(),
+            &mut len,
+            &mut status,
+        )
+    };
+    common::Error::ok_or_warning(status)?;
+    to_string(rep, len)
+}
+
+/// Returns the ISO 4217 numeric code of `currency`, such as 978 for EUR, or `None` if the
+/// currency has none.
+///
+/// Implements `ucurr_getNumericCode`.
+pub fn numeric_code(currency: &str) -> Result<Option<i32>, common::Error> {
+    let currency = iso_code(currency)?;
+    let code = unsafe { versioned_function!(ucurr_getNumericCode)(currency.as_c_ptr()) };
+    Ok(if code == 0 { None } else { Some(code) })
+}
+
+/// Returns the ISO 4217 codes of the currencies that have all of `types`.
+///
+/// Implements `ucurr_openISOCurrencies`.
+pub fn iso_currencies(types: CurrencyTypes) -> Result<uenum::Enumeration, common::Error> {
+    let mut status = common::Error::OK_CODE;
+    let rep = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_openISOCurrencies)(types.bits(), &mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(unsafe { uenum::Enumeration::from_raw_parts(None, rep) })
+}
+
+/// Returns the currencies that are relevant for `locale`, in order of preference.  With
+/// `commonly_used`, only the currencies in use in the region of the locale are returned,
+/// otherwise they are followed by all other currencies.
+///
+/// Implements `ucurr_getKeywordValuesForLocale`.
+pub fn keyword_values_for_locale(
+    locale: &str,
+    commonly_used: bool,
+) -> Result<uenum::Enumeration, common::Error> {
+    let key = ffi::CString::new("currency")?;
+    let locale = ffi::CString::new(locale)?;
+    let mut status = common::Error::OK_CODE;
+    let rep = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ucurr_getKeywordValuesForLocale)(
+            key.as_ptr(),
+            locale.as_ptr(),
+            commonly_used as sys::UBool,
+            &mut status,
+        )
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(unsafe { uenum::Enumeration::from_raw_parts(None, rep) })
+}
+
+/// Overrides the currency of a locale, for as long as it is kept.  The override is removed
+/// when the registration is dropped.
+///
+/// The override applies to the whole process, and to everything that looks up the currency of
+/// the locale, such as [for_locale] and the number formatters.
+#[derive(Debug)]
+pub struct Registration {
+    key: sys::UCurrRegistryKey,
+}
+
+impl Registration {
+    /// Makes `currency` the currency of `locale`.  Only the region and the variants "EURO" and
+    /// "PREEURO" of the locale are considered.
+    ///
+    /// Implements `ucurr_register`.
+    pub fn try_new(currency: &str, locale: &str) -> Result<Self, common::Error> {
+        let currency = iso_code(currency)?;
+        let locale = ffi::CString::new(locale)?;
+        let mut status = common::Error::OK_CODE;
+        let key = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucurr_register)(currency.as_c_ptr(), locale.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Registration { key })
+    }
+}
+
+impl Drop for Registration {
+    /// Removes the override.
+    ///
+    /// Implements `ucurr_unregister`.
+    fn drop(&mut self) {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            versioned_function!(ucurr_unregister)(self.key, &mut status);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sys::UCurrNameStyle;
+    use sys::UCurrencyUsage::{UCURR_USAGE_CASH, UCURR_USAGE_STANDARD};
+
+    // 1995-01-01T00:00Z.
+    const YEAR_1995: sys::UDate = 788918400000.0;
+    // 2000-06-01T00:00Z.
+    const YEAR_2000: sys::UDate = 959817600000.0;
+    // 2010-01-01T00:00Z.
+    const YEAR_2010: sys::UDate = 1262304000000.0;
+
+    #[test]
+    fn currency_of_locale() {
+        assert_eq!("USD", for_locale("en_US").unwrap());
+        assert_eq!("EUR", for_locale("de_DE").unwrap());
+        assert_eq!("EUR", for_locale("de_CH@currency=EUR").unwrap());
+    }
+
+    #[test]
+    fn currencies_over_time() {
+        assert_eq!(
+            vec!["DEM"],
+            for_locale_and_date("de_DE", YEAR_1995).unwrap()
+        );
+        assert_eq!(
+            vec!["EUR", "DEM"],
+            for_locale_and_date("de_DE", YEAR_2000).unwrap()
+        );
+        assert_eq!(
+            vec!["EUR"],
+            for_locale_and_date("de_DE", YEAR_2010).unwrap()
+        );
+        assert!(!is_available("EUR", YEAR_1995, YEAR_1995).unwrap());
+        assert!(is_available("EUR", YEAR_2010, YEAR_2010).unwrap());
+        assert!(!is_available("DEM", YEAR_2010, YEAR_2010).unwrap());
+        assert!(is_available("DEM", YEAR_1995, YEAR_2010).unwrap());
+    }
+
+    #[test]
+    fn digits_and_rounding() {
+        assert_eq!(2, fraction_digits("USD", UCURR_USAGE_STANDARD).unwrap());
+        assert_eq!(0, fraction_digits("JPY", UCURR_USAGE_STANDARD).unwrap());
+        assert_eq!(2, fraction_digits("TWD", UCURR_USAGE_STANDARD).unwrap());
+        assert_eq!(0, fraction_digits("TWD", UCURR_USAGE_CASH).unwrap());
+        assert_eq!(
+            0.0,
+            rounding_increment("CHF", UCURR_USAGE_STANDARD).unwrap()
+        );
+        assert_eq!(0.05, rounding_increment("CHF", UCURR_USAGE_CASH).unwrap());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            "CA$",
+            name("CAD", "en", UCurrNameStyle::UCURR_SYMBOL_NAME).unwrap()
+        );
+        assert_eq!(
+            "$",
+            name("CAD", "en", UCurrNameStyle::UCURR_NARROW_SYMBOL_NAME).unwrap()
+        );
+        assert_eq!(
+            "US Dollar",
+            name("USD", "en", UCurrNameStyle::UCURR_LONG_NAME).unwrap()
+        );
+        assert_eq!(
+            "Euro",
+            name("EUR", "de", UCurrNameStyle::UCURR_LONG_NAME).unwrap()
+        );
+        assert_eq!("US dollar", plural_name("USD", "en", "one").unwrap());
+        assert_eq!("US dollars", plural_name("USD", "en", "other").unwrap());
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(Some(978), numeric_code("EUR").unwrap());
+        assert_eq!(Some(840), numeric_code("USD").unwrap());
+        assert_eq!(None, numeric_code("XYZ").unwrap());
+
+        let current: Vec<String> =
+            iso_currencies(CurrencyTypes::COMMON | CurrencyTypes::NON_DEPRECATED)
+                .unwrap()
+                .map(|c| c.unwrap())
+                .collect();
+        assert!(current.contains(&"USD".to_string()));
+        assert!(!current.contains(&"DEM".to_string()));
+        let deprecated: Vec<String> = iso_currencies(CurrencyTypes::DEPRECATED)
+            .unwrap()
+            .map(|c| c.unwrap())
+            .collect();
+        assert!(deprecated.contains(&"DEM".to_string()));
+
+        let preferred: Vec<String> = keyword_values_for_locale("en_US", true)
+            .unwrap()
+            .map(|c| c.unwrap())
+            .collect();
+        assert_eq!("USD", preferred[0]);
+    }
+
+    #[test]
+    fn registration() {
+        assert_eq!("CHF", for_locale("fr_CH").unwrap());
+        {
+            let _registration = Registration::try_new("EUR", "fr_CH").unwrap();
+            assert_eq!("EUR", for_locale("fr_CH").unwrap());
+        }
+        assert_eq!("CHF", for_locale("fr_CH").unwrap());
+    }
+}
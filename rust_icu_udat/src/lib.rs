@@ -23,7 +23,8 @@
 
 use {
     rust_icu_common as common, rust_icu_sys as sys, rust_icu_sys::versioned_function,
-    rust_icu_ucal as ucal, rust_icu_uloc as uloc, rust_icu_ustring as ustring,
+    rust_icu_ucal as ucal, rust_icu_uloc as uloc, rust_icu_unum as unum,
+    rust_icu_ustring as ustring,
 };
 use std::convert::{TryFrom, TryInto};
 
@@ -221,6 +222,24 @@ impl UDateFormat {
         };
     }
 
+    /// Returns the locale, valid or actual, of this date format.  ICU does not record the
+    /// locale of formats created with `new_with_pattern`, and returns an empty string for them.
+    ///
+    /// Implements `udat_getLocaleByType`.
+    pub fn get_locale_by_type(
+        &self,
+        data_loc_type: sys::ULocDataLocaleType,
+    ) -> Result<String, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let raw = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udat_getLocaleByType)(self.rep, data_loc_type, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        let c_str = unsafe { std::ffi::CStr::from_ptr(raw) };
+        Ok(c_str.to_str()?.to_owned())
+    }
+
     /// Parses a date-time given as a string into a `sys::UDate` timestamp.
     ///
     /// This version of date parsing does not allow reuse of the input parameters so it is less
@@ -316,6 +335,41 @@ impl UDateFormat {
         }
         String::try_from(&result)
     }
+
+    /// Formats a date using this formatter, and returns the formatted string together with
+    /// an iterator over its fields.
+    ///
+    /// The field types are the values of [sys::UDateFormatField], and the field ranges are
+    /// indexes into the returned string.  An UTF-8 version is not provided because the
+    /// indexes are in UTF-16 code units.
+    ///
+    /// Implements `udat_formatForFields`
+    pub fn format_for_fields_ustring<'a>(
+        &'a self,
+        date_to_format: sys::UDate,
+    ) -> Result<
+        (
+            ustring::UChar,
+            unum::UFieldPositionIterator<'a, *const sys::UDateFormat>,
+        ),
+        common::Error,
+    > {
+        let mut iterator = unum::UFieldPositionIterator::try_new_unowned()?;
+        const CAPACITY: usize = 200;
+        ustring::buffered_uchar_method_with_retry!(
+            format_for_fields_impl,
+            CAPACITY,
+            [format: *const sys::UDateFormat, date: sys::UDate,],
+            [iter: *mut sys::UFieldPositionIterator,]
+        );
+        let result = format_for_fields_impl(
+            versioned_function!(udat_formatForFields),
+            self.rep,
+            date_to_format,
+            iterator.as_mut_ptr(),
+        )?;
+        Ok((result, iterator))
+    }
 }
 
 #[cfg(test)]
@@ -535,6 +589,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_format_for_fields() -> Result<(), common::Error> {
+        let loc = uloc::ULoc::try_from("en-US")?;
+        let tz_id = ustring::UChar::try_from("UTC")?;
+        let pattern = ustring::UChar::try_from("MMMM d, y")?;
+        let fmt = super::UDateFormat::new_with_pattern(&loc, &tz_id, &pattern)?;
+        let (result, fields) = fmt.format_for_fields_ustring(0.0)?;
+        assert_eq!("January 1, 1970", String::try_from(&result)?);
+        let fields: Vec<(i32, i32, i32)> = fields
+            .map(|f| (f.field_type, f.begin_index, f.past_end_index))
+            .collect();
+        assert_eq!(
+            vec![
+                (sys::UDateFormatField::UDAT_MONTH_FIELD as i32, 0, 7),
+                (sys::UDateFormatField::UDAT_DATE_FIELD as i32, 8, 9),
+                (sys::UDateFormatField::UDAT_YEAR_FIELD as i32, 11, 15),
+            ],
+            fields
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_locale_by_type() -> Result<(), common::Error> {
+        let loc = uloc::ULoc::try_from("de-AT-x-private")?;
+        let tz_id = ustring::UChar::try_from("UTC")?;
+        let fmt = super::UDateFormat::new_with_styles(
+            sys::UDateFormatStyle::UDAT_SHORT,
+            sys::UDateFormatStyle::UDAT_LONG,
+            &loc,
+            &tz_id,
+        )?;
+        assert_eq!(
+            "de_AT",
+            fmt.get_locale_by_type(sys::ULocDataLocaleType::ULOC_VALID_LOCALE)?
+        );
+
+        // ICU does not keep the locale of a format that is made from a pattern.
+        let pattern = ustring::UChar::try_from("y")?;
+        let fmt = super::UDateFormat::new_with_pattern(&loc, &tz_id, &pattern)?;
+        assert_eq!(
+            "",
+            fmt.get_locale_by_type(sys::ULocDataLocaleType::ULOC_VALID_LOCALE)?
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_utf8() -> Result<(), common::Error> {
         #[derive(Debug)]
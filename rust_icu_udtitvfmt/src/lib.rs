@@ -0,0 +1,408 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU date interval formatting support for rust
+//!
+//! This crate formats intervals between two dates, such as "Jan 3 – 5, 2026" or "10:00 – 11:30
+//! AM", omitting the parts that the two dates share, in a locale-sensitive way.  It is a
+//! wrapper around the ICU library, specifically the functionality exposed through its C API,
+//! as available in the [header
+//! `udateintervalformat.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/udateintervalformat_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_udtitvfmt as udtitvfmt;
+//!
+//! let fmt = udtitvfmt::UDateIntervalFormat::try_new("en-US", "yMMMd", "UTC").unwrap();
+//! // 2026-01-03T10:00Z to 2026-01-05T11:30Z.
+//! let result = fmt.format(1767434400000.0, 1767612600000.0).unwrap();
+//! assert_eq!("Jan 3\u{2009}–\u{2009}5, 2026", result);
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ucal as ucal, rust_icu_uformattedvalue as ufmtval, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ffi, ptr,
+    },
+};
+
+/// Formats intervals between two dates, according to a skeleton, a locale and a time zone.
+#[derive(Debug)]
+pub struct UDateIntervalFormat {
+    rep: ptr::NonNull<sys::UDateIntervalFormat>,
+}
+
+// Implements `udtitvfmt_close`.
+simple_drop_impl!(UDateIntervalFormat, udtitvfmt_close);
+
+impl UDateIntervalFormat {
+    /// Creates a formatter for `locale`, which shows the fields given in the date and time
+    /// `skeleton`, such as "yMMMd" or "hm", in the time zone `tz_id`.  An empty `tz_id` means
+    /// the default time zone.
+    ///
+    /// Implements `udtitvfmt_open`.
+    pub fn try_new(locale: &str, skeleton: &str, tz_id: &str) -> Result<Self, common::Error> {
+        let skeleton = ustring::UChar::try_from(skeleton)?;
+        let tz_id = ustring::UChar::try_from(tz_id)?;
+        UDateIntervalFormat::try_new_ustring(locale, &skeleton, &tz_id)
+    }
+
+    /// Implements `udtitvfmt_open`.
+    pub fn try_new_ustring(
+        locale: &str,
+        skeleton: &ustring::UChar,
+        tz_id: &ustring::UChar,
+    ) -> Result<Self, common::Error> {
+        let locale = ffi::CString::new(locale)?;
+        let (tz_id_ptr, tz_id_len) = if tz_id.is_empty() {
+            (ptr::null(), 0)
+        } else {
+            (tz_id.as_c_ptr(), tz_id.len() as i32)
+        };
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udtitvfmt_open)(
+                locale.as_ptr(),
+                skeleton.as_c_ptr(),
+                skeleton.len() as i32,
+                tz_id_ptr,
+                tz_id_len,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UDateIntervalFormat {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Sets a display context, such as the capitalization.
+    ///
+    /// Implements `udtitvfmt_setContext`.  Since ICU 68.
+    pub fn set_context(&mut self, context: sys::UDisplayContext) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udtitvfmt_setContext)(self.rep.as_ptr(), context, &mut status)
+        };
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Returns the display context of type `context_type` that is in effect.
+    ///
+    /// Implements `udtitvfmt_getContext`.  Since ICU 68.
+    pub fn context(
+        &self,
+        context_type: sys::UDisplayContextType,
+    ) -> Result<sys::UDisplayContext, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let context = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udtitvfmt_getContext)(self.rep.as_ptr(), context_type, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(context)
+    }
+
+    /// Formats the interval from `from` to `to`.
+    ///
+    /// Implements `udtitvfmt_format`.
+    pub fn format(&self, from: sys::UDate, to: sys::UDate) -> Result<String, common::Error> {
+        let result = self.format_ustring(from, to)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `udtitvfmt_format`.
+    pub fn format_ustring(
+        &self,
+        from: sys::UDate,
+        to: sys::UDate,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 50;
+        buffered_uchar_method_with_retry!(
+            format_impl,
+            CAPACITY,
+            [
+                rep: *const sys::UDateIntervalFormat,
+                from: sys::UDate,
+                to: sys::UDate,
+            ],
+            [position: *mut sys::UFieldPosition,]
+        );
+        format_impl(
+            versioned_function!(udtitvfmt_format),
+            self.rep.as_ptr(),
+            from,
+            to,
+            ptr::null_mut(),
+        )
+    }
+
+    /// Formats the interval from `from` to `to`, into a result that also holds the fields.
+    ///
+    /// Implements `udtitvfmt_formatToResult`.
+    pub fn format_to_result(
+        &self,
+        from: sys::UDate,
+        to: sys::UDate,
+    ) -> Result<UFormattedDateInterval, common::Error> {
+        let result = UFormattedDateInterval::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udtitvfmt_formatToResult)(
+                self.rep.as_ptr(),
+                from,
+                to,
+                result.rep.as_ptr(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+
+    /// Formats the interval between the times of two calendars.
+    ///
+    /// ICU may update the fields of the calendars while formatting, which is why they are
+    /// borrowed mutably.
+    ///
+    /// Implements `udtitvfmt_formatCalendarToResult`.  Since ICU 67.
+    pub fn format_calendars_to_result(
+        &self,
+        from: &mut ucal::UCalendar,
+        to: &mut ucal::UCalendar,
+    ) -> Result<UFormattedDateInterval, common::Error> {
+        let result = UFormattedDateInterval::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udtitvfmt_formatCalendarToResult)(
+                self.rep.as_ptr(),
+                from.as_c_calendar_mut(),
+                to.as_c_calendar_mut(),
+                result.rep.as_ptr(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+}
+
+/// Stores a formatted date interval.
+///
+/// These objects are produced by [UDateIntervalFormat::format_to_result] and
+/// [UDateIntervalFormat::format_calendars_to_result].
+#[derive(Debug)]
+pub struct UFormattedDateInterval {
+    rep: ptr::NonNull<sys::UFormattedDateInterval>,
+}
+
+// Implements `udtitvfmt_closeResult`.
+simple_drop_impl!(UFormattedDateInterval, udtitvfmt_closeResult);
+
+impl UFormattedDateInterval {
+    // Implements `udtitvfmt_openResult`.
+    fn try_new() -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udtitvfmt_openResult)(&mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UFormattedDateInterval {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Returns the formatted string and its fields.  Besides the date fields, there are spans
+    /// in the category `UFIELD_CATEGORY_DATE_INTERVAL_SPAN`: the span numbered 0 covers the
+    /// part that belongs to the start of the interval, and the span numbered 1 the part that
+    /// belongs to its end.  There are no spans if the two dates format the same.
+    ///
+    /// Implements `udtitvfmt_resultAsValue`.
+    pub fn as_value(&self) -> Result<ufmtval::UFormattedValue<'_>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let value = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(udtitvfmt_resultAsValue)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: the value is owned by `self`, which it borrows.
+        Ok(unsafe { ufmtval::UFormattedValue::from_raw(value) })
+    }
+}
+
+impl TryFrom<&UFormattedDateInterval> for ustring::UChar {
+    type Error = common::Error;
+
+    /// Implements `udtitvfmt_resultAsValue`.
+    fn try_from(result: &UFormattedDateInterval) -> Result<ustring::UChar, common::Error> {
+        ustring::UChar::try_from(&result.as_value()?)
+    }
+}
+
+impl TryFrom<&UFormattedDateInterval> for String {
+    type Error = common::Error;
+
+    /// Implements `udtitvfmt_resultAsValue`.
+    fn try_from(result: &UFormattedDateInterval) -> Result<String, common::Error> {
+        String::try_from(&result.as_value()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2026-01-03T10:00Z.
+    const JAN_3_10_00: sys::UDate = 1767434400000.0;
+    // 2026-01-03T11:30Z.
+    const JAN_3_11_30: sys::UDate = 1767439800000.0;
+    // 2026-01-05T11:30Z.
+    const JAN_5_11_30: sys::UDate = 1767612600000.0;
+    // 2026-02-10T09:00Z.
+    const FEB_10_09_00: sys::UDate = 1770714000000.0;
+
+    #[test]
+    fn formatting_table() {
+        #[derive(Debug)]
+        struct TestCase {
+            locale: &'static str,
+            skeleton: &'static str,
+            tz_id: &'static str,
+            interval: (sys::UDate, sys::UDate),
+            expected: &'static str,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "en-US",
+                skeleton: "yMMMd",
+                tz_id: "UTC",
+                interval: (JAN_3_10_00, JAN_5_11_30),
+                expected: "Jan 3\u{2009}–\u{2009}5, 2026",
+            },
+            TestCase {
+                locale: "en-US",
+                skeleton: "yMMMd",
+                tz_id: "UTC",
+                interval: (JAN_3_10_00, FEB_10_09_00),
+                expected: "Jan 3\u{2009}–\u{2009}Feb 10, 2026",
+            },
+            TestCase {
+                locale: "en-US",
+                skeleton: "hm",
+                tz_id: "UTC",
+                interval: (JAN_3_10_00, JAN_3_11_30),
+                expected: "10:00\u{2009}–\u{2009}11:30\u{202f}AM",
+            },
+            TestCase {
+                locale: "en-US",
+                skeleton: "hm",
+                tz_id: "America/New_York",
+                interval: (JAN_3_10_00, JAN_3_11_30),
+                expected: "5:00\u{2009}–\u{2009}6:30\u{202f}AM",
+            },
+            TestCase {
+                locale: "de-DE",
+                skeleton: "yMMMd",
+                tz_id: "UTC",
+                interval: (JAN_3_10_00, JAN_5_11_30),
+                expected: "3.–5. Jan. 2026",
+            },
+            TestCase {
+                locale: "ja-JP",
+                skeleton: "yMMMd",
+                tz_id: "UTC",
+                interval: (JAN_3_10_00, FEB_10_09_00),
+                expected: "2026年1月3日～2月10日",
+            },
+        ];
+        for test in tests {
+            let fmt = UDateIntervalFormat::try_new(test.locale, test.skeleton, test.tz_id)
+                .unwrap_or_else(|_| panic!("for test {:?}", &test));
+            let actual = fmt.format(test.interval.0, test.interval.1).unwrap();
+            assert_eq!(test.expected, actual, "for test {:?}", &test);
+            let result = fmt
+                .format_to_result(test.interval.0, test.interval.1)
+                .unwrap();
+            assert_eq!(test.expected, String::try_from(&result).unwrap());
+        }
+    }
+
+    #[test]
+    fn interval_spans() {
+        let fmt = UDateIntervalFormat::try_new("en-US", "yMMMd", "UTC").unwrap();
+        let result = fmt.format_to_result(JAN_3_10_00, FEB_10_09_00).unwrap();
+        let value = result.as_value().unwrap();
+        let spans: Vec<_> = value
+            .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_DATE_INTERVAL_SPAN)
+            .unwrap()
+            .into_iter()
+            .map(|f| (f.field, f.range))
+            .collect();
+        // "Jan 3" is the start, and "Feb 10" is the end.
+        assert_eq!(vec![(0, 0..5), (1, 8..14)], spans);
+
+        // The same dates have no spans.
+        let result = fmt.format_to_result(JAN_3_10_00, JAN_3_11_30).unwrap();
+        assert_eq!("Jan 3, 2026", String::try_from(&result).unwrap());
+        assert_eq!(
+            0,
+            result
+                .as_value()
+                .unwrap()
+                .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_DATE_INTERVAL_SPAN)
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn calendars_and_context() {
+        let mut fmt = UDateIntervalFormat::try_new("en-US", "yMMMMd", "UTC").unwrap();
+        let mut from =
+            ucal::UCalendar::new("UTC", "en-US", sys::UCalendarType::UCAL_GREGORIAN).unwrap();
+        from.set_millis(JAN_3_10_00).unwrap();
+        let mut to =
+            ucal::UCalendar::new("UTC", "en-US", sys::UCalendarType::UCAL_GREGORIAN).unwrap();
+        to.set_millis(JAN_5_11_30).unwrap();
+        let result = fmt.format_calendars_to_result(&mut from, &mut to).unwrap();
+        assert_eq!(
+            "January 3\u{2009}–\u{2009}5, 2026",
+            String::try_from(&result).unwrap()
+        );
+
+        fmt.set_context(sys::UDisplayContext::UDISPCTX_CAPITALIZATION_FOR_BEGINNING_OF_SENTENCE)
+            .unwrap();
+        assert_eq!(
+            sys::UDisplayContext::UDISPCTX_CAPITALIZATION_FOR_BEGINNING_OF_SENTENCE,
+            fmt.context(sys::UDisplayContextType::UDISPCTX_TYPE_CAPITALIZATION)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU formatted values for rust
+//!
+//! Several ICU formatters produce their results as a "formatted value": the formatted string,
+//! together with the fields that make it up, such as the integer part of a number or the
+//! literal text around it.  This crate reads those results.  It is a wrapper around the ICU
+//! library, specifically the functionality exposed through its C API, as available in the
+//! [header
+//! `uformattedvalue.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/uformattedvalue_8h.html).
+//!
+//! The formatter crates, such as `rust_icu_ureldatefmt`, hand out a [UFormattedValue] that
+//! borrows their result.  Formatted values are available since ICU 64.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    std::{convert::TryFrom, marker::PhantomData, ops, ptr, slice},
+};
+
+/// A field of a formatted value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// The category of the field, which determines the meaning of [Field::field].  One of the
+    /// values of [sys::UFieldCategory], such as `UFIELD_CATEGORY_NUMBER`.
+    pub category: i32,
+    /// The field within the category, for example a value of `UNumberFormatFields` in the
+    /// number category.
+    pub field: i32,
+    /// The range of the field in the formatted string, in UTF-16 code units.
+    pub range: ops::Range<usize>,
+}
+
+impl Field {
+    /// Returns true if the field is in `category`.
+    pub fn is_in(&self, category: sys::UFieldCategory) -> bool {
+        self.category == category as i32
+    }
+}
+
+/// Walks the fields of a formatted value.
+#[derive(Debug)]
+struct ConstrainedFieldPosition {
+    rep: ptr::NonNull<sys::UConstrainedFieldPosition>,
+}
+
+// Implements `ucfpos_close`.
+simple_drop_impl!(ConstrainedFieldPosition, ucfpos_close);
+
+impl ConstrainedFieldPosition {
+    // Implements `ucfpos_open`.
+    fn try_new() -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucfpos_open)(&mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(ConstrainedFieldPosition {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    // Implements `ucfpos_constrainCategory`.
+    fn constrain_category(&mut self, category: sys::UFieldCategory) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ucfpos_constrainCategory)(
+                self.rep.as_ptr(),
+                category as i32,
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    // Implements `ucfpos_getCategory`, `ucfpos_getField` and `ucfpos_getIndexes`.
+    fn field(&self) -> Result<Field, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let (mut start, mut limit) = (0, 0);
+        let (category, field) = unsafe {
+            assert!(common::Error::is_ok(status));
+            let category = versioned_function!(ucfpos_getCategory)(self.rep.as_ptr(), &mut status);
+            let field = versioned_function!(ucfpos_getField)(self.rep.as_ptr(), &mut status);
+            versioned_function!(ucfpos_getIndexes)(
+                self.rep.as_ptr(),
+                &mut start,
+                &mut limit,
+                &mut status,
+            );
+            (category, field)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Field {
+            category,
+            field,
+            range: start as usize..limit as usize,
+        })
+    }
+}
+
+/// A formatted value, borrowed from the formatter result that it belongs to.
+#[derive(Debug)]
+pub struct UFormattedValue<'a> {
+    rep: ptr::NonNull<sys::UFormattedValue>,
+    _owner: PhantomData<&'a sys::UFormattedValue>,
+}
+
+impl<'a> UFormattedValue<'a> {
+    /// Wraps a formatted value that ICU returned, such as from `ureldatefmt_resultAsValue`.
+    ///
+    /// # Safety
+    ///
+    /// `rep` must be non-null, and must remain valid for the lifetime `'a`, which is usually
+    /// that of a borrow of the formatter result that owns it.
+    pub unsafe fn from_raw(rep: *const sys::UFormattedValue) -> UFormattedValue<'a> {
+        UFormattedValue {
+            rep: ptr::NonNull::new(rep as *mut sys::UFormattedValue).unwrap(),
+            _owner: PhantomData,
+        }
+    }
+
+    /// Returns the fields of the formatted value, in the order that ICU reports them.
+    ///
+    /// Implements `ufmtval_nextPosition`.
+    pub fn fields(&self) -> Result<Vec<Field>, common::Error> {
+        self.fields_impl(None)
+    }
+
+    /// Returns the fields of the formatted value that are in `category`.
+    ///
+    /// Implements `ufmtval_nextPosition`.
+    pub fn fields_in(&self, category: sys::UFieldCategory) -> Result<Vec<Field>, common::Error> {
+        self.fields_impl(Some(category))
+    }
+
+    fn fields_impl(
+        &self,
+        category: Option<sys::UFieldCategory>,
+    ) -> Result<Vec<Field>, common::Error> {
+        let mut position = ConstrainedFieldPosition::try_new()?;
+        if let Some(category) = category {
+            position.constrain_category(category)?;
+        }
+        let mut fields = vec![];
+        loop {
+            let mut status = common::Error::OK_CODE;
+            let found = unsafe {
+                assert!(common::Error::is_ok(status));
+                versioned_function!(ufmtval_nextPosition)(
+                    self.rep.as_ptr(),
+                    position.rep.as_ptr(),
+                    &mut status,
+                )
+            };
+            common::Error::ok_or_warning(status)?;
+            if found == 0 {
+                return Ok(fields);
+            }
+            fields.push(position.field()?);
+        }
+    }
+}
+
+impl TryFrom<&UFormattedValue<'_>> for ustring::UChar {
+    type Error = common::Error;
+
+    /// Returns the formatted string.
+    ///
+    /// Implements `ufmtval_getString`.
+    fn try_from(value: &UFormattedValue<'_>) -> Result<ustring::UChar, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let mut len = 0;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ufmtval_getString)(value.rep.as_ptr(), &mut len, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        if len == 0 {
+            return Ok(ustring::UChar::from(vec![]));
+        }
+        // Unsafety note: ICU returns `len` code units, valid for as long as the value is.
+        let units = unsafe { slice::from_raw_parts(rep, len as usize) };
+        Ok(ustring::UChar::from(units.to_vec()))
+    }
+}
+
+impl TryFrom<&UFormattedValue<'_>> for String {
+    type Error = common::Error;
+
+    /// Returns the formatted string.
+    ///
+    /// Field ranges are in UTF-16 code units, so use `ustring::UChar::try_from` instead to
+    /// extract the text of the fields.
+    ///
+    /// Implements `ufmtval_getString`.
+    fn try_from(value: &UFormattedValue<'_>) -> Result<String, common::Error> {
+        let result = ustring::UChar::try_from(value)?;
+        String::try_from(&result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi;
+
+    // Formats a number with `unumf`, and calls `test` with its formatted value.
+    fn with_formatted_number(skeleton: &str, locale: &str, number: i64, test: fn(UFormattedValue)) {
+        let skeleton = ustring::UChar::try_from(skeleton).unwrap();
+        let locale = ffi::CString::new(locale).unwrap();
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            let formatter = versioned_function!(unumf_openForSkeletonAndLocale)(
+                skeleton.as_c_ptr(),
+                skeleton.len() as i32,
+                locale.as_ptr(),
+                &mut status,
+            );
+            let result = versioned_function!(unumf_openResult)(&mut status);
+            versioned_function!(unumf_formatInt)(formatter, number, result, &mut status);
+            let value = versioned_function!(unumf_resultAsValue)(result, &mut status);
+            common::Error::ok_or_warning(status).unwrap();
+            test(UFormattedValue::from_raw(value));
+            versioned_function!(unumf_closeResult)(result);
+            versioned_function!(unumf_close)(formatter);
+        }
+    }
+
+    #[test]
+    fn string_and_fields() {
+        with_formatted_number("currency/EUR", "de-DE", -1234, |value| {
+            assert_eq!("-1.234,00\u{a0}€", String::try_from(&value).unwrap());
+            let fields = value.fields().unwrap();
+            assert!(fields
+                .iter()
+                .all(|f| f.is_in(sys::UFieldCategory::UFIELD_CATEGORY_NUMBER)));
+            let ranges: Vec<_> = fields.iter().map(|f| f.range.clone()).collect();
+            // Sign, grouping separator, integer, decimal separator, fraction, and currency.
+            assert_eq!(vec![0..1, 2..3, 1..6, 6..7, 7..9, 10..11], ranges);
+        });
+    }
+
+    #[test]
+    fn fields_in_category() {
+        with_formatted_number("", "en", 7, |value| {
+            let text = ustring::UChar::try_from(&value).unwrap();
+            assert_eq!(1, text.len());
+            assert_eq!(
+                0,
+                value
+                    .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_DATE)
+                    .unwrap()
+                    .len()
+            );
+            assert_eq!(
+                vec![0..1],
+                value
+                    .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_NUMBER)
+                    .unwrap()
+                    .into_iter()
+                    .map(|f| f.range)
+                    .collect::<Vec<_>>()
+            );
+        });
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU grammatical gender of lists for rust
+//!
+//! In many languages, words that refer to a list of people agree with the gender of the list
+//! as a whole.  In French, for example, "ils" refers to a list that has at least one man, while
+//! "elles" refers to a list of women only.  This crate computes the gender of a list from the
+//! genders of its members, following the rules of a locale.  It is a wrapper around the ICU
+//! library, specifically the functionality exposed through its C API, as available in the
+//! [header `ugender.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ugender_8h.html).
+//!
+//! A [Gender] can be passed to `rust_icu_umsg::message_format!` to select the message text
+//! that agrees with it.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_ugender::{self as ugender, Gender};
+//! use rust_icu_uloc as uloc;
+//! use std::convert::TryFrom;
+//!
+//! let french = uloc::ULoc::try_from("fr").unwrap();
+//! let info = ugender::UGenderInfo::try_new(&french).unwrap();
+//! assert_eq!(Gender::Female, info.list_gender(&[Gender::Female, Gender::Female]).unwrap());
+//! assert_eq!(Gender::Male, info.list_gender(&[Gender::Female, Gender::Male]).unwrap());
+//! ```
+
+use {
+    rust_icu_common as common, rust_icu_sys as sys, rust_icu_sys::versioned_function,
+    rust_icu_uloc as uloc, std::ptr,
+};
+
+/// The grammatical gender of a person or of a list of people.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    /// Male gender.
+    Male,
+    /// Female gender.
+    Female,
+    /// Neutral or unknown gender, and the gender of lists in languages that do not inflect for
+    /// it.
+    Other,
+}
+
+impl Gender {
+    /// Returns the keyword that a MessageFormat `select` argument matches for this gender:
+    /// "male", "female" or "other".
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Gender::Male => "male",
+            Gender::Female => "female",
+            Gender::Other => "other",
+        }
+    }
+}
+
+impl From<Gender> for sys::UGender {
+    fn from(gender: Gender) -> sys::UGender {
+        match gender {
+            Gender::Male => sys::UGender::UGENDER_MALE,
+            Gender::Female => sys::UGender::UGENDER_FEMALE,
+            Gender::Other => sys::UGender::UGENDER_OTHER,
+        }
+    }
+}
+
+impl From<sys::UGender> for Gender {
+    fn from(gender: sys::UGender) -> Gender {
+        match gender {
+            sys::UGender::UGENDER_MALE => Gender::Male,
+            sys::UGender::UGENDER_FEMALE => Gender::Female,
+            sys::UGender::UGENDER_OTHER => Gender::Other,
+        }
+    }
+}
+
+/// The rules for the gender of lists in a locale.
+///
+/// ICU caches the rules and owns them for the lifetime of the process, so there is nothing to
+/// close.
+#[derive(Debug)]
+pub struct UGenderInfo {
+    rep: ptr::NonNull<sys::UGenderInfo>,
+}
+
+impl UGenderInfo {
+    /// Returns the rules for the gender of lists in `locale`.
+    ///
+    /// Implements `ugender_getInstance`.
+    pub fn try_new(locale: &uloc::ULoc) -> Result<Self, common::Error> {
+        let locale = locale.as_c_str();
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ugender_getInstance)(locale.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UGenderInfo {
+            rep: ptr::NonNull::new(rep as *mut sys::UGenderInfo).unwrap(),
+        })
+    }
+
+    /// Returns the gender of a list of people with the given `genders`.  The gender of an
+    /// empty list is [Gender::Other].
+    ///
+    /// Implements `ugender_getListGender`.
+    pub fn list_gender(&self, genders: &[Gender]) -> Result<Gender, common::Error> {
+        let genders: Vec<sys::UGender> = genders.iter().map(|&g| g.into()).collect();
+        let mut status = common::Error::OK_CODE;
+        let gender = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ugender_getListGender)(
+                self.rep.as_ptr(),
+                genders.as_ptr(),
+                genders.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(gender.into())
+    }
+}
+
+/// Returns the gender of a list of people with the given `genders`, following the rules of
+/// `locale`.
+///
+/// Implements `ugender_getListGender`.
+pub fn list_gender(locale: &uloc::ULoc, genders: &[Gender]) -> Result<Gender, common::Error> {
+    UGenderInfo::try_new(locale)?.list_gender(genders)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::convert::TryFrom, Gender::*};
+
+    #[test]
+    fn list_genders() {
+        struct TestCase {
+            locale: &'static str,
+            genders: Vec<Gender>,
+            expected: Gender,
+        }
+        let tests = vec![
+            // Lists take the gender of any man in them.
+            TestCase {
+                locale: "fr",
+                genders: vec![Female, Female],
+                expected: Female,
+            },
+            TestCase {
+                locale: "fr",
+                genders: vec![Female, Male, Female],
+                expected: Male,
+            },
+            TestCase {
+                locale: "es",
+                genders: vec![Female, Other],
+                expected: Male,
+            },
+            TestCase {
+                locale: "es",
+                genders: vec![Male],
+                expected: Male,
+            },
+            // Mixed lists are neutral.
+            TestCase {
+                locale: "el",
+                genders: vec![Male, Male],
+                expected: Male,
+            },
+            TestCase {
+                locale: "el",
+                genders: vec![Male, Female],
+                expected: Other,
+            },
+            // English lists have no gender.
+            TestCase {
+                locale: "en",
+                genders: vec![Female, Female],
+                expected: Other,
+            },
+            TestCase {
+                locale: "fr",
+                genders: vec![],
+                expected: Other,
+            },
+        ];
+        for test in tests {
+            let locale = uloc::ULoc::try_from(test.locale).unwrap();
+            assert_eq!(
+                test.expected,
+                list_gender(&locale, &test.genders).unwrap(),
+                "locale: {}, genders: {:?}",
+                test.locale,
+                test.genders
+            );
+        }
+    }
+
+    #[test]
+    fn sys_round_trip() {
+        for gender in [Male, Female, Other] {
+            assert_eq!(gender, Gender::from(sys::UGender::from(gender)));
+        }
+        assert_eq!("female", Female.keyword());
+    }
+}
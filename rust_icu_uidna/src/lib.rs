@@ -0,0 +1,455 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU internationalized domain name support for rust
+//!
+//! This crate converts internationalized domain names between their Unicode and ASCII (Punycode)
+//! forms, following [UTS #46](https://www.unicode.org/reports/tr46/), as implemented by the ICU
+//! library.  Specifically the functionality exposed through its C API, as available in the
+//! [header `uidna.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/uidna_8h.html).
+//!
+//! Conversion problems, such as disallowed characters or labels that are too long, do not fail
+//! the conversion.  Instead, they are reported in the [Info] that is returned along with the
+//! converted text.  A name should be considered invalid if [Info::errors] is not empty.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_uidna as uidna;
+//!
+//! let idna = uidna::Idna::try_new(
+//!     uidna::Options::NONTRANSITIONAL_TO_ASCII | uidna::Options::CHECK_BIDI,
+//! )
+//! .expect("idna");
+//!
+//! let (ascii, info) = idna.name_to_ascii("Bücher.example").expect("name_to_ascii");
+//! assert_eq!("xn--bcher-kva.example", ascii);
+//! assert!(info.errors.is_empty());
+//!
+//! let (_, info) = idna.name_to_ascii("-bad-.example").expect("name_to_ascii");
+//! assert!(info.errors.contains(uidna::Errors::LEADING_HYPHEN));
+//! assert!(info.errors.contains(uidna::Errors::TRAILING_HYPHEN));
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::{flag_set, simple_drop_impl},
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    std::{mem, os::raw, ptr},
+};
+
+flag_set! {
+    /// Options for [Idna::try_new].
+    Options {
+        /// The default options, with none of the optional checks enabled.
+        DEFAULT = 0,
+        /// Apply the STD3 rules, which disallow ASCII characters other than letters, digits and
+        /// hyphen-minus.
+        USE_STD3_RULES = 0x2,
+        /// Check the rules of RFC 5893 for labels with right-to-left characters.
+        CHECK_BIDI = 0x4,
+        /// Check the CONTEXTJ rules of RFC 5892, for ZWJ and ZWNJ.
+        CHECK_CONTEXTJ = 0x8,
+        /// Use nontransitional processing when converting to ASCII, keeping deviation
+        /// characters such as ß and ς.
+        NONTRANSITIONAL_TO_ASCII = 0x10,
+        /// Use nontransitional processing when converting to Unicode.
+        NONTRANSITIONAL_TO_UNICODE = 0x20,
+        /// Check the CONTEXTO rules of RFC 5892.
+        CHECK_CONTEXTO = 0x40,
+    }
+}
+
+flag_set! {
+    /// The problems found while converting a name or a label.
+    Errors {
+        /// A non-final domain name label, or the whole domain name, is empty.
+        EMPTY_LABEL = 0x1,
+        /// A domain name label is longer than 63 bytes.
+        LABEL_TOO_LONG = 0x2,
+        /// A domain name is longer than 255 bytes in its storage form.
+        DOMAIN_NAME_TOO_LONG = 0x4,
+        /// A label starts with a hyphen-minus.
+        LEADING_HYPHEN = 0x8,
+        /// A label ends with a hyphen-minus.
+        TRAILING_HYPHEN = 0x10,
+        /// A label contains hyphen-minus in the third and fourth positions.
+        HYPHEN_3_4 = 0x20,
+        /// A label starts with a combining mark.
+        LEADING_COMBINING_MARK = 0x40,
+        /// A label or domain name contains disallowed characters.
+        DISALLOWED = 0x80,
+        /// A label starts with "xn--" but does not contain valid Punycode.
+        PUNYCODE = 0x100,
+        /// A label contains a dot.  Only reported by the label functions.
+        LABEL_HAS_DOT = 0x200,
+        /// An ACE label does not contain a valid label string.
+        INVALID_ACE_LABEL = 0x400,
+        /// A label does not meet the IDNA BiDi requirements.
+        BIDI = 0x800,
+        /// A label does not meet the IDNA CONTEXTJ requirements.
+        CONTEXTJ = 0x1000,
+        /// A label does not meet the IDNA CONTEXTO requirements for punctuation characters.
+        CONTEXTO_PUNCTUATION = 0x2000,
+        /// A label does not meet the IDNA CONTEXTO requirements for digits.
+        CONTEXTO_DIGITS = 0x4000,
+    }
+}
+
+/// Information about the conversion of a name or a label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Info {
+    /// The problems found in the input.  The input is valid if this is empty.
+    pub errors: Errors,
+    /// True if transitional and nontransitional processing produce different results for the
+    /// input.
+    pub is_transitional_different: bool,
+}
+
+impl Info {
+    fn new_raw() -> sys::UIDNAInfo {
+        // Equivalent to UIDNA_INFO_INITIALIZER.
+        sys::UIDNAInfo {
+            size: mem::size_of::<sys::UIDNAInfo>() as i16,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<sys::UIDNAInfo> for Info {
+    fn from(info: sys::UIDNAInfo) -> Self {
+        Info {
+            errors: Errors::from_bits(info.errors),
+            is_transitional_different: info.isTransitionalDifferent != 0,
+        }
+    }
+}
+
+type Utf8Fn = unsafe extern "C" fn(
+    *const sys::UIDNA,
+    *const raw::c_char,
+    i32,
+    *mut raw::c_char,
+    i32,
+    *mut sys::UIDNAInfo,
+    *mut sys::UErrorCode,
+) -> i32;
+
+type Utf16Fn = unsafe extern "C" fn(
+    *const sys::UIDNA,
+    *const sys::UChar,
+    i32,
+    *mut sys::UChar,
+    i32,
+    *mut sys::UIDNAInfo,
+    *mut sys::UErrorCode,
+) -> i32;
+
+/// A converter for internationalized domain names, following UTS #46.
+///
+/// The converter is immutable once created, and can be shared between threads.
+#[derive(Debug)]
+pub struct Idna {
+    rep: ptr::NonNull<sys::UIDNA>,
+}
+
+// Implements `uidna_close`
+simple_drop_impl!(Idna, uidna_close);
+
+// Safety: ICU documents the UTS #46 instance as immutable and thread-safe.
+unsafe impl Send for Idna {}
+unsafe impl Sync for Idna {}
+
+impl Idna {
+    /// Creates a converter with the given options.
+    ///
+    /// Implements `uidna_openUTS46`
+    pub fn try_new(options: Options) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uidna_openUTS46)(options.bits(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Idna {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Converts a whole domain name to its ASCII form.
+    ///
+    /// Implements `uidna_nameToASCII_UTF8`
+    pub fn name_to_ascii(&self, name: &str) -> Result<(String, Info), common::Error> {
+        self.process_utf8(versioned_function!(uidna_nameToASCII_UTF8), name)
+    }
+
+    /// Converts a whole domain name to its Unicode form.
+    ///
+    /// Implements `uidna_nameToUnicodeUTF8`
+    pub fn name_to_unicode(&self, name: &str) -> Result<(String, Info), common::Error> {
+        self.process_utf8(versioned_function!(uidna_nameToUnicodeUTF8), name)
+    }
+
+    /// Converts a single label to its ASCII form.
+    ///
+    /// Implements `uidna_labelToASCII_UTF8`
+    pub fn label_to_ascii(&self, label: &str) -> Result<(String, Info), common::Error> {
+        self.process_utf8(versioned_function!(uidna_labelToASCII_UTF8), label)
+    }
+
+    /// Converts a single label to its Unicode form.
+    ///
+    /// Implements `uidna_labelToUnicodeUTF8`
+    pub fn label_to_unicode(&self, label: &str) -> Result<(String, Info), common::Error> {
+        self.process_utf8(versioned_function!(uidna_labelToUnicodeUTF8), label)
+    }
+
+    /// Implements `uidna_nameToASCII`
+    pub fn name_to_ascii_ustring(
+        &self,
+        name: &ustring::UChar,
+    ) -> Result<(ustring::UChar, Info), common::Error> {
+        self.process_utf16(versioned_function!(uidna_nameToASCII), name)
+    }
+
+    /// Implements `uidna_nameToUnicode`
+    pub fn name_to_unicode_ustring(
+        &self,
+        name: &ustring::UChar,
+    ) -> Result<(ustring::UChar, Info), common::Error> {
+        self.process_utf16(versioned_function!(uidna_nameToUnicode), name)
+    }
+
+    /// Implements `uidna_labelToASCII`
+    pub fn label_to_ascii_ustring(
+        &self,
+        label: &ustring::UChar,
+    ) -> Result<(ustring::UChar, Info), common::Error> {
+        self.process_utf16(versioned_function!(uidna_labelToASCII), label)
+    }
+
+    /// Implements `uidna_labelToUnicode`
+    pub fn label_to_unicode_ustring(
+        &self,
+        label: &ustring::UChar,
+    ) -> Result<(ustring::UChar, Info), common::Error> {
+        self.process_utf16(versioned_function!(uidna_labelToUnicode), label)
+    }
+
+    // Calls one of the UTF-8 conversion functions, first to find out the length of the
+    // result, and then to produce it.
+    fn process_utf8(&self, method: Utf8Fn, input: &str) -> Result<(String, Info), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let mut info = Info::new_raw();
+        let len = unsafe {
+            assert!(common::Error::is_ok(status));
+            method(
+                self.rep.as_ptr(),
+                input.as_ptr() as *const raw::c_char,
+                input.len() as i32,
+                ptr::null_mut(),
+                0,
+                &mut info,
+                &mut status,
+            )
+        };
+        common::Error::ok_preflight(status)?;
+        if len == 0 {
+            // Passing an empty buffer would look to ICU as if the output overlapped the input.
+            return Ok((String::new(), Info::from(info)));
+        }
+
+        let mut buf: Vec<u8> = vec![0; len as usize];
+        status = common::Error::OK_CODE;
+        info = Info::new_raw();
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            method(
+                self.rep.as_ptr(),
+                input.as_ptr() as *const raw::c_char,
+                input.len() as i32,
+                buf.as_mut_ptr() as *mut raw::c_char,
+                len,
+                &mut info,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok((String::from_utf8(buf)?, Info::from(info)))
+    }
+
+    // The UTF-16 counterpart of `process_utf8`.
+    fn process_utf16(
+        &self,
+        method: Utf16Fn,
+        input: &ustring::UChar,
+    ) -> Result<(ustring::UChar, Info), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let mut info = Info::new_raw();
+        let len = unsafe {
+            assert!(common::Error::is_ok(status));
+            method(
+                self.rep.as_ptr(),
+                input.as_c_ptr(),
+                input.len() as i32,
+                ptr::null_mut(),
+                0,
+                &mut info,
+                &mut status,
+            )
+        };
+        common::Error::ok_preflight(status)?;
+        if len == 0 {
+            return Ok((ustring::UChar::new_with_capacity(0), Info::from(info)));
+        }
+
+        let mut buf = ustring::UChar::new_with_capacity(len as usize);
+        status = common::Error::OK_CODE;
+        info = Info::new_raw();
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            method(
+                self.rep.as_ptr(),
+                input.as_c_ptr(),
+                input.len() as i32,
+                buf.as_mut_c_ptr(),
+                len,
+                &mut info,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok((buf, Info::from(info)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn name_conversion() {
+        let idna = Idna::try_new(Options::DEFAULT).expect("idna");
+        let (ascii, info) = idna.name_to_ascii("www.Bücher.example").unwrap();
+        assert_eq!("www.xn--bcher-kva.example", ascii);
+        assert_eq!(Errors::default(), info.errors);
+
+        let (unicode, info) = idna.name_to_unicode("www.xn--bcher-kva.example").unwrap();
+        assert_eq!("www.bücher.example", unicode);
+        assert!(info.errors.is_empty());
+
+        let (ascii, _) = idna.name_to_ascii("").unwrap();
+        assert_eq!("", ascii);
+    }
+
+    #[test]
+    fn utf16_conversion() {
+        let idna = Idna::try_new(Options::DEFAULT).expect("idna");
+        let name = ustring::UChar::try_from("普遍.例子").unwrap();
+        let (ascii, info) = idna.name_to_ascii_ustring(&name).unwrap();
+        assert_eq!("xn--tkvz54g.xn--fsqu00a", String::try_from(&ascii).unwrap());
+        assert!(info.errors.is_empty());
+        let (unicode, _) = idna.name_to_unicode_ustring(&ascii).unwrap();
+        assert_eq!("普遍.例子", String::try_from(&unicode).unwrap());
+
+        let label = ustring::UChar::try_from("Bücher").unwrap();
+        let (ascii, _) = idna.label_to_ascii_ustring(&label).unwrap();
+        assert_eq!("xn--bcher-kva", String::try_from(&ascii).unwrap());
+        let (unicode, _) = idna.label_to_unicode_ustring(&ascii).unwrap();
+        assert_eq!("bücher", String::try_from(&unicode).unwrap());
+    }
+
+    #[test]
+    fn labels() {
+        let idna = Idna::try_new(Options::DEFAULT).expect("idna");
+        let (ascii, info) = idna.label_to_ascii("Bücher").unwrap();
+        assert_eq!("xn--bcher-kva", ascii);
+        assert!(info.errors.is_empty());
+        let (unicode, _) = idna.label_to_unicode("xn--bcher-kva").unwrap();
+        assert_eq!("bücher", unicode);
+
+        let (_, info) = idna.label_to_ascii("a.b").unwrap();
+        assert_eq!(Errors::LABEL_HAS_DOT, info.errors);
+        let (_, info) = idna.label_to_ascii(&"a".repeat(64)).unwrap();
+        assert_eq!(Errors::LABEL_TOO_LONG, info.errors);
+        let (_, info) = idna.label_to_unicode("xn--ab-").unwrap();
+        assert_eq!(Errors::INVALID_ACE_LABEL, info.errors);
+    }
+
+    #[test]
+    fn nontransitional() {
+        let idna = Idna::try_new(Options::NONTRANSITIONAL_TO_ASCII).expect("idna");
+        let (ascii, info) = idna.name_to_ascii("faß.de").unwrap();
+        assert_eq!("xn--fa-hia.de", ascii);
+        assert!(info.is_transitional_different);
+
+        let (_, info) = idna.name_to_ascii("fass.de").unwrap();
+        assert!(!info.is_transitional_different);
+    }
+
+    #[test]
+    fn checks() {
+        let idna = Idna::try_new(Options::DEFAULT).expect("idna");
+        let (_, info) = idna.name_to_ascii("a..b").unwrap();
+        assert_eq!(Errors::EMPTY_LABEL, info.errors);
+        let (_, info) = idna.name_to_ascii("ab--c.com").unwrap();
+        assert_eq!(Errors::HYPHEN_3_4, info.errors);
+        let (_, info) = idna.name_to_ascii("a_b.com").unwrap();
+        assert!(info.errors.is_empty());
+        let (_, info) = idna.name_to_ascii("\u{0301}a.com").unwrap();
+        assert_eq!(Errors::LEADING_COMBINING_MARK, info.errors);
+
+        let idna = Idna::try_new(
+            Options::USE_STD3_RULES
+                | Options::CHECK_BIDI
+                | Options::CHECK_CONTEXTJ
+                | Options::CHECK_CONTEXTO
+                | Options::NONTRANSITIONAL_TO_UNICODE,
+        )
+        .expect("idna");
+        let (_, info) = idna.name_to_ascii("a_b.com").unwrap();
+        assert_eq!(Errors::DISALLOWED, info.errors);
+        // A Hebrew label that ends in a Latin letter.
+        let (_, info) = idna.name_to_ascii("אa.com").unwrap();
+        assert_eq!(Errors::BIDI, info.errors);
+        // A zero width joiner between Latin letters.
+        let (_, info) = idna.name_to_unicode("a\u{200d}b.com").unwrap();
+        assert_eq!(Errors::CONTEXTJ, info.errors);
+        // A middle dot that is not between two letters l.
+        let (_, info) = idna.name_to_unicode("a\u{00b7}b.com").unwrap();
+        assert_eq!(Errors::CONTEXTO_PUNCTUATION, info.errors);
+    }
+
+    #[test]
+    fn flag_set() {
+        let errors = Errors::BIDI | Errors::DISALLOWED;
+        assert!(errors.contains(Errors::BIDI));
+        assert!(!errors.contains(Errors::BIDI | Errors::CONTEXTJ));
+        assert_eq!(Errors::BIDI, errors & Errors::BIDI);
+        assert_eq!(0x880, errors.bits());
+        assert_eq!("Errors(DISALLOWED | BIDI)", format!("{:?}", errors));
+        assert_eq!("Errors()", format!("{:?}", Errors::default()));
+        assert_eq!(
+            "Options(CHECK_BIDI | 0x10000)",
+            format!("{:?}", Options::from_bits(0x10004))
+        );
+    }
+}
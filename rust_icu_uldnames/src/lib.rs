@@ -0,0 +1,403 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU locale display names for rust
+//!
+//! This crate provides the names of locales, and of their parts such as languages, scripts and
+//! regions, spelled out in the language of a display locale.  It is a wrapper around the ICU
+//! library, specifically the functionality exposed through its C API, as available in the
+//! [header
+//! `uldnames.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/uldnames_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_uldnames as uldnames;
+//!
+//! let names =
+//!     uldnames::ULocaleDisplayNames::try_new("en-US", sys::UDialectHandling::ULDN_STANDARD_NAMES)
+//!         .unwrap();
+//! assert_eq!("German (Switzerland)", names.locale_display_name("de_CH").unwrap());
+//! assert_eq!("Cyrillic", names.script_display_name("Cyrl").unwrap());
+//! assert_eq!("Japan", names.region_display_name("JP").unwrap());
+//!
+//! // Dialect names use a single name for some languages with a region.
+//! let names =
+//!     uldnames::ULocaleDisplayNames::try_new("en-US", sys::UDialectHandling::ULDN_DIALECT_NAMES)
+//!         .unwrap();
+//! assert_eq!("British English", names.locale_display_name("en_GB").unwrap());
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ffi, ptr,
+    },
+};
+
+/// The initial capacity of the buffers that receive the display names.
+const CAPACITY: usize = 50;
+
+/// Generates a pair of methods that look up the display name of a code, one returning a
+/// [String] and the other an [ustring::UChar].
+macro_rules! display_name_method {
+    ($(#[$doc:meta])* $method_name:ident, $ustring_method_name:ident, $function_name:ident) => {
+        $(#[$doc])*
+        pub fn $method_name(&self, code: &str) -> Result<String, common::Error> {
+            let result = self.$ustring_method_name(code)?;
+            String::try_from(&result)
+        }
+
+        $(#[$doc])*
+        pub fn $ustring_method_name(&self, code: &str) -> Result<ustring::UChar, common::Error> {
+            let code = ffi::CString::new(code)?;
+            buffered_uchar_method_with_retry!(
+                display_name_impl,
+                CAPACITY,
+                [rep: *const sys::ULocaleDisplayNames, code: *const std::os::raw::c_char,],
+                []
+            );
+            display_name_impl(
+                versioned_function!($function_name),
+                self.rep.as_ptr(),
+                code.as_ptr(),
+            )
+        }
+    };
+}
+
+/// Provides the display names of locales and their parts, in the language of a single display
+/// locale.
+///
+/// When the display contexts include `UDISPCTX_NO_SUBSTITUTE`, a lookup for which ICU has no
+/// name fails with `U_ILLEGAL_ARGUMENT_ERROR`, instead of returning the code that was looked
+/// up.
+#[derive(Debug)]
+pub struct ULocaleDisplayNames {
+    rep: ptr::NonNull<sys::ULocaleDisplayNames>,
+}
+
+// Implements `uldn_close`.
+simple_drop_impl!(ULocaleDisplayNames, uldn_close);
+
+impl ULocaleDisplayNames {
+    /// Creates display names in the language of `locale`, using either standard names such as
+    /// "English (United Kingdom)", or dialect names such as "British English".
+    ///
+    /// Implements `uldn_open`.
+    pub fn try_new(
+        locale: &str,
+        dialect_handling: sys::UDialectHandling,
+    ) -> Result<Self, common::Error> {
+        let locale = ffi::CString::new(locale)?;
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uldn_open)(locale.as_ptr(), dialect_handling, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(ULocaleDisplayNames {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Creates display names in the language of `locale`, configured by a list of display
+    /// contexts.  The contexts may set the dialect handling, the capitalization, the display
+    /// length, and whether a missing name is substituted by its code.  The contexts that are
+    /// not given keep their defaults.
+    ///
+    /// Implements `uldn_openForContext`.
+    pub fn try_new_with_contexts(
+        locale: &str,
+        contexts: &[sys::UDisplayContext],
+    ) -> Result<Self, common::Error> {
+        let locale = ffi::CString::new(locale)?;
+        let mut contexts = contexts.to_vec();
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uldn_openForContext)(
+                locale.as_ptr(),
+                contexts.as_mut_ptr(),
+                contexts.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(ULocaleDisplayNames {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Returns the locale in whose language the names are displayed.
+    ///
+    /// Implements `uldn_getLocale`.
+    pub fn locale(&self) -> String {
+        // Unsafety note: ICU returns a valid C string owned by `self`.
+        unsafe {
+            let locale = versioned_function!(uldn_getLocale)(self.rep.as_ptr());
+            ffi::CStr::from_ptr(locale).to_string_lossy().into_owned()
+        }
+    }
+
+    /// Returns whether standard or dialect names are used.
+    ///
+    /// Implements `uldn_getDialectHandling`.
+    pub fn dialect_handling(&self) -> sys::UDialectHandling {
+        unsafe { versioned_function!(uldn_getDialectHandling)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the display context of type `context_type` that is in effect, for example the
+    /// capitalization.
+    ///
+    /// Implements `uldn_getContext`.
+    pub fn context(
+        &self,
+        context_type: sys::UDisplayContextType,
+    ) -> Result<sys::UDisplayContext, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let context = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uldn_getContext)(self.rep.as_ptr(), context_type, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(context)
+    }
+
+    display_name_method!(
+        /// Returns the display name of a whole locale, for example "English (United States)"
+        /// for `en_US`.
+        ///
+        /// Implements `uldn_localeDisplayName`.
+        locale_display_name,
+        locale_display_name_ustring,
+        uldn_localeDisplayName
+    );
+
+    display_name_method!(
+        /// Returns the display name of a language code, for example "French" for `fr`.
+        ///
+        /// Implements `uldn_languageDisplayName`.
+        language_display_name,
+        language_display_name_ustring,
+        uldn_languageDisplayName
+    );
+
+    display_name_method!(
+        /// Returns the display name of a script code, for example "Latin" for `Latn`.
+        ///
+        /// Implements `uldn_scriptDisplayName`.
+        script_display_name,
+        script_display_name_ustring,
+        uldn_scriptDisplayName
+    );
+
+    display_name_method!(
+        /// Returns the display name of a region code, for example "Brazil" for `BR`.
+        ///
+        /// Implements `uldn_regionDisplayName`.
+        region_display_name,
+        region_display_name_ustring,
+        uldn_regionDisplayName
+    );
+
+    display_name_method!(
+        /// Returns the display name of a variant code, for example "Traditional German
+        /// orthography" for `1901`.
+        ///
+        /// Implements `uldn_variantDisplayName`.
+        variant_display_name,
+        variant_display_name_ustring,
+        uldn_variantDisplayName
+    );
+
+    display_name_method!(
+        /// Returns the display name of a locale keyword, for example "Calendar" for
+        /// `calendar`.
+        ///
+        /// Implements `uldn_keyDisplayName`.
+        key_display_name,
+        key_display_name_ustring,
+        uldn_keyDisplayName
+    );
+
+    /// Returns the display name of a script, given by its script code.
+    ///
+    /// ICU looks the name up by the long name of the script, such as "Greek", rather than by
+    /// its four letter code.  Since the locale data has no entries for those, the result is
+    /// the long name itself, regardless of the display locale.  Use
+    /// [ULocaleDisplayNames::script_display_name] with the four letter code instead, to get a
+    /// translated name.
+    ///
+    /// Implements `uldn_scriptCodeDisplayName`.
+    pub fn script_code_display_name(
+        &self,
+        script: sys::UScriptCode,
+    ) -> Result<String, common::Error> {
+        let result = self.script_code_display_name_ustring(script)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `uldn_scriptCodeDisplayName`.
+    pub fn script_code_display_name_ustring(
+        &self,
+        script: sys::UScriptCode,
+    ) -> Result<ustring::UChar, common::Error> {
+        buffered_uchar_method_with_retry!(
+            script_code_display_name_impl,
+            CAPACITY,
+            [rep: *const sys::ULocaleDisplayNames, script: sys::UScriptCode,],
+            []
+        );
+        script_code_display_name_impl(
+            versioned_function!(uldn_scriptCodeDisplayName),
+            self.rep.as_ptr(),
+            script,
+        )
+    }
+
+    /// Returns the display name of the `value` of a locale keyword `key`, for example
+    /// "Gregorian Calendar" for the value `gregorian` of the key `calendar`.
+    ///
+    /// Implements `uldn_keyValueDisplayName`.
+    pub fn key_value_display_name(&self, key: &str, value: &str) -> Result<String, common::Error> {
+        let result = self.key_value_display_name_ustring(key, value)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `uldn_keyValueDisplayName`.
+    pub fn key_value_display_name_ustring(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<ustring::UChar, common::Error> {
+        let key = ffi::CString::new(key)?;
+        let value = ffi::CString::new(value)?;
+        buffered_uchar_method_with_retry!(
+            key_value_display_name_impl,
+            CAPACITY,
+            [
+                rep: *const sys::ULocaleDisplayNames,
+                key: *const std::os::raw::c_char,
+                value: *const std::os::raw::c_char,
+            ],
+            []
+        );
+        key_value_display_name_impl(
+            versioned_function!(uldn_keyValueDisplayName),
+            self.rep.as_ptr(),
+            key.as_ptr(),
+            value.as_ptr(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_and_dialect_names() {
+        let standard =
+            ULocaleDisplayNames::try_new("en", sys::UDialectHandling::ULDN_STANDARD_NAMES).unwrap();
+        let dialect =
+            ULocaleDisplayNames::try_new("en", sys::UDialectHandling::ULDN_DIALECT_NAMES).unwrap();
+        assert_eq!(
+            "English (United States)",
+            standard.locale_display_name("en_US").unwrap()
+        );
+        assert_eq!(
+            "American English",
+            dialect.locale_display_name("en_US").unwrap()
+        );
+        assert_eq!(
+            sys::UDialectHandling::ULDN_DIALECT_NAMES,
+            dialect.dialect_handling()
+        );
+        assert_eq!("en", dialect.locale());
+    }
+
+    #[test]
+    fn names_of_parts() {
+        let names =
+            ULocaleDisplayNames::try_new("de", sys::UDialectHandling::ULDN_STANDARD_NAMES).unwrap();
+        assert_eq!("Französisch", names.language_display_name("fr").unwrap());
+        assert_eq!("Griechisch", names.script_display_name("Grek").unwrap());
+        // The lookup by script code yields the English name of the script.
+        assert_eq!(
+            "Greek",
+            names
+                .script_code_display_name(sys::UScriptCode::USCRIPT_GREEK)
+                .unwrap()
+        );
+        assert_eq!("Brasilien", names.region_display_name("BR").unwrap());
+        assert_eq!("Kalender", names.key_display_name("calendar").unwrap());
+        assert_eq!(
+            "Gregorianischer Kalender",
+            names
+                .key_value_display_name("calendar", "gregorian")
+                .unwrap()
+        );
+        let variant = names.variant_display_name_ustring("1901").unwrap();
+        assert_eq!(
+            "Alte deutsche Rechtschreibung",
+            String::try_from(&variant).unwrap()
+        );
+    }
+
+    #[test]
+    fn contexts() {
+        let names = ULocaleDisplayNames::try_new_with_contexts(
+            "en",
+            &[
+                sys::UDisplayContext::UDISPCTX_LENGTH_SHORT,
+                sys::UDisplayContext::UDISPCTX_NO_SUBSTITUTE,
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            sys::UDisplayContext::UDISPCTX_LENGTH_SHORT,
+            names
+                .context(sys::UDisplayContextType::UDISPCTX_TYPE_DISPLAY_LENGTH)
+                .unwrap()
+        );
+        assert_eq!(
+            sys::UDisplayContext::UDISPCTX_STANDARD_NAMES,
+            names
+                .context(sys::UDisplayContextType::UDISPCTX_TYPE_DIALECT_HANDLING)
+                .unwrap()
+        );
+        assert_eq!("US", names.region_display_name("US").unwrap());
+        assert!(names.region_display_name("XY").is_err());
+
+        let names = ULocaleDisplayNames::try_new_with_contexts(
+            "en",
+            &[sys::UDisplayContext::UDISPCTX_SUBSTITUTE],
+        )
+        .unwrap();
+        assert_eq!("United States", names.region_display_name("US").unwrap());
+        assert_eq!("XY", names.region_display_name("XY").unwrap());
+    }
+}
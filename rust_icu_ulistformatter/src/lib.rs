@@ -29,9 +29,11 @@
 //! > TBD
 
 use {
-    rust_icu_common as common, rust_icu_sys as sys,
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
     rust_icu_sys::versioned_function,
-    rust_icu_ustring as ustring,
+    rust_icu_uformattedvalue as ufmtval, rust_icu_ustring as ustring,
     std::{convert::TryFrom, convert::TryInto, ffi, ptr},
 };
 
@@ -149,6 +151,89 @@ impl UListFormatter {
         }
         Ok(ustring::UChar::from(buf))
     }
+
+    /// Same as [UListFormatter::format], but returns the result with its fields.  Since ICU
+    /// 64.
+    ///
+    /// Implements `ulistfmt_formatStringsToResult`.
+    pub fn format_to_result(&self, list: &[&str]) -> Result<UFormattedList, common::Error> {
+        let list_ustr = UCharArray::try_from(list)?;
+        let result = UFormattedList::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ulistfmt_formatStringsToResult)(
+                self.rep.as_ptr(),
+                list_ustr.pointers.as_ptr(),
+                list_ustr.strlens.as_ptr(),
+                list_ustr.len() as i32,
+                result.rep.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+}
+
+/// The result of formatting a list, with its fields.
+///
+/// The fields are in the `UFIELD_CATEGORY_LIST` category, with the values of
+/// [sys::UListFormatterField], and in the `UFIELD_CATEGORY_LIST_SPAN` category, where the
+/// field is the index of the list element that the span holds.
+#[derive(Debug)]
+pub struct UFormattedList {
+    rep: ptr::NonNull<sys::UFormattedList>,
+}
+
+// Implements `ulistfmt_closeResult`.
+simple_drop_impl!(UFormattedList, ulistfmt_closeResult);
+
+impl UFormattedList {
+    // Implements `ulistfmt_openResult`.
+    fn try_new() -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ulistfmt_openResult)(&mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UFormattedList {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Returns the formatted string and its fields.
+    ///
+    /// Implements `ulistfmt_resultAsValue`.
+    pub fn as_value(&self) -> Result<ufmtval::UFormattedValue<'_>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let value = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ulistfmt_resultAsValue)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: the value is owned by `self`, which it borrows.
+        Ok(unsafe { ufmtval::UFormattedValue::from_raw(value) })
+    }
+}
+
+impl TryFrom<&UFormattedList> for ustring::UChar {
+    type Error = common::Error;
+
+    /// Implements `ulistfmt_resultAsValue`.
+    fn try_from(result: &UFormattedList) -> Result<ustring::UChar, common::Error> {
+        ustring::UChar::try_from(&result.as_value()?)
+    }
+}
+
+impl TryFrom<&UFormattedList> for String {
+    type Error = common::Error;
+
+    /// Implements `ulistfmt_resultAsValue`.
+    fn try_from(result: &UFormattedList) -> Result<String, common::Error> {
+        String::try_from(&result.as_value()?)
+    }
 }
 
 /// A helper array that deconstructs [ustring::UChar] into constituent raw parts
@@ -302,4 +387,26 @@ mod testing {
         let result = formatter.format(&array).expect("formatting succeeds");
         assert_eq!("Kwik, Kwek of Kwak", result);
     }
+
+    #[test]
+    fn test_format_to_result() {
+        let formatter = crate::UListFormatter::try_new("en-US").expect("has list format");
+        let result = formatter
+            .format_to_result(&["Huey", "Dewey", "Louie"])
+            .expect("formatting succeeds");
+        assert_eq!(
+            "Huey, Dewey, and Louie",
+            String::try_from(&result).expect("has a string")
+        );
+        let elements = result
+            .as_value()
+            .expect("has a value")
+            .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_LIST)
+            .expect("has fields")
+            .into_iter()
+            .filter(|f| f.field == sys::UListFormatterField::ULISTFMT_ELEMENT_FIELD as i32)
+            .map(|f| f.range)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0..4, 6..11, 17..22], elements);
+    }
 }
@@ -0,0 +1,349 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU locale data for rust
+//!
+//! This crate provides assorted CLDR data about a locale: the characters that its language is
+//! written with, its quotation marks, its measurement system and paper size, and the patterns
+//! used to display locale names.  It is a wrapper around the ICU library, specifically the
+//! functionality exposed through its C API, as available in the [header
+//! `ulocdata.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ulocdata_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_uloc as uloc;
+//! use rust_icu_ulocdata as ulocdata;
+//! use std::convert::TryFrom;
+//!
+//! let locale = uloc::ULoc::try_from("de-DE").unwrap();
+//! let data = ulocdata::ULocaleData::try_new(&locale).unwrap();
+//! assert_eq!(
+//!     "„",
+//!     data.delimiter(sys::ULocaleDataDelimiterType::ULOCDATA_QUOTATION_START).unwrap()
+//! );
+//! assert_eq!(
+//!     sys::UMeasurementSystem::UMS_SI,
+//!     ulocdata::measurement_system(&locale).unwrap()
+//! );
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_uloc as uloc, rust_icu_uset as uset, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ptr,
+    },
+};
+
+/// The size of a sheet of paper, in millimeters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaperSize {
+    /// The height of the paper.
+    pub height: i32,
+    /// The width of the paper.
+    pub width: i32,
+}
+
+/// The data of a single locale.
+#[derive(Debug)]
+pub struct ULocaleData {
+    rep: ptr::NonNull<sys::ULocaleData>,
+}
+
+// Implements `ulocdata_close`.
+simple_drop_impl!(ULocaleData, ulocdata_close);
+
+impl ULocaleData {
+    /// Opens the data of `locale`.
+    ///
+    /// Implements `ulocdata_open`.
+    pub fn try_new(locale: &uloc::ULoc) -> Result<Self, common::Error> {
+        let locale = locale.as_c_str();
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ulocdata_open)(locale.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(ULocaleData {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Sets whether data that the locale lacks is substituted by that of the root locale.  With
+    /// `no_substitute` set, the lookups of missing data return an empty set or string instead.
+    ///
+    /// Implements `ulocdata_setNoSubstitute`.
+    pub fn set_no_substitute(&mut self, no_substitute: bool) {
+        unsafe {
+            versioned_function!(ulocdata_setNoSubstitute)(
+                self.rep.as_ptr(),
+                no_substitute as sys::UBool,
+            )
+        };
+    }
+
+    /// Returns true if data that the locale lacks is not substituted.
+    ///
+    /// Implements `ulocdata_getNoSubstitute`.
+    pub fn no_substitute(&self) -> bool {
+        unsafe { versioned_function!(ulocdata_getNoSubstitute)(self.rep.as_ptr()) != 0 }
+    }
+
+    /// Returns the set of characters of the given type that the language of the locale is
+    /// written with.  For example, the standard set of English contains the letters "a" to "z",
+    /// and its auxiliary set contains letters used in loan words, such as "é".  The set is
+    /// modified by `options` as a set pattern would be, and white space is always ignored.
+    ///
+    /// Implements `ulocdata_getExemplarSet`.
+    pub fn exemplar_set(
+        &self,
+        options: uset::PatternOptions,
+        set_type: sys::ULocaleDataExemplarSetType,
+    ) -> Result<uset::USet, common::Error> {
+        let mut set = uset::USet::new_empty();
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ulocdata_getExemplarSet)(
+                self.rep.as_ptr(),
+                set.as_mut_ptr(),
+                options.bits(),
+                set_type,
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(set)
+    }
+
+    /// Returns a quotation mark of the locale, for example "“" for the start of a quotation
+    /// in English.  The alternate quotation marks are those used for a quotation within a
+    /// quotation.
+    ///
+    /// Implements `ulocdata_getDelimiter`.
+    pub fn delimiter(
+        &self,
+        delimiter_type: sys::ULocaleDataDelimiterType,
+    ) -> Result<String, common::Error> {
+        const CAPACITY: usize = 10;
+        buffered_uchar_method_with_retry!(
+            delimiter_impl,
+            CAPACITY,
+            [
+                rep: *mut sys::ULocaleData,
+                delimiter_type: sys::ULocaleDataDelimiterType,
+            ],
+            []
+        );
+        let result = delimiter_impl(
+            versioned_function!(ulocdata_getDelimiter),
+            self.rep.as_ptr(),
+            delimiter_type,
+        )?;
+        String::try_from(&result)
+    }
+
+    /// Returns the pattern that combines a language with the other parts of a locale name,
+    /// such as "{0} ({1})" in English, as in "English (United States)".
+    ///
+    /// Implements `ulocdata_getLocaleDisplayPattern`.
+    pub fn locale_display_pattern(&self) -> Result<String, common::Error> {
+        const CAPACITY: usize = 20;
+        buffered_uchar_method_with_retry!(
+            locale_display_pattern_impl,
+            CAPACITY,
+            [rep: *mut sys::ULocaleData,],
+            []
+        );
+        let result = locale_display_pattern_impl(
+            versioned_function!(ulocdata_getLocaleDisplayPattern),
+            self.rep.as_ptr(),
+        )?;
+        String::try_from(&result)
+    }
+
+    /// Returns the separator between the parts of a locale name that follow the language, such
+    /// as ", " in English, as in "English (Latin, United States)".
+    ///
+    /// Implements `ulocdata_getLocaleSeparator`.
+    pub fn locale_separator(&self) -> Result<String, common::Error> {
+        const CAPACITY: usize = 20;
+        buffered_uchar_method_with_retry!(
+            locale_separator_impl,
+            CAPACITY,
+            [rep: *mut sys::ULocaleData,],
+            []
+        );
+        let result = locale_separator_impl(
+            versioned_function!(ulocdata_getLocaleSeparator),
+            self.rep.as_ptr(),
+        )?;
+        String::try_from(&result)
+    }
+}
+
+/// Returns the measurement system used in the region of `locale`.
+///
+/// Implements `ulocdata_getMeasurementSystem`.
+pub fn measurement_system(locale: &uloc::ULoc) -> Result<sys::UMeasurementSystem, common::Error> {
+    let locale = locale.as_c_str();
+    let mut status = common::Error::OK_CODE;
+    let result = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ulocdata_getMeasurementSystem)(locale.as_ptr(), &mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(result)
+}
+
+/// Returns the size of the paper used for business letters in the region of `locale`, such as
+/// A4 or US Letter.
+///
+/// Implements `ulocdata_getPaperSize`.
+pub fn paper_size(locale: &uloc::ULoc) -> Result<PaperSize, common::Error> {
+    let locale = locale.as_c_str();
+    let (mut height, mut width) = (0, 0);
+    let mut status = common::Error::OK_CODE;
+    unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ulocdata_getPaperSize)(
+            locale.as_ptr(),
+            &mut height,
+            &mut width,
+            &mut status,
+        );
+    }
+    common::Error::ok_or_warning(status)?;
+    Ok(PaperSize { height, width })
+}
+
+/// Returns the version of the CLDR data that ICU uses, such as `[42, 0, 0, 0]`.
+///
+/// Implements `ulocdata_getCLDRVersion`.
+pub fn cldr_version() -> Result<sys::UVersionInfo, common::Error> {
+    let mut version: sys::UVersionInfo = Default::default();
+    let mut status = common::Error::OK_CODE;
+    unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(ulocdata_getCLDRVersion)(version.as_mut_ptr(), &mut status);
+    }
+    common::Error::ok_or_warning(status)?;
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sys::ULocaleDataDelimiterType::*;
+    use sys::ULocaleDataExemplarSetType::*;
+
+    fn data(locale: &str) -> ULocaleData {
+        ULocaleData::try_new(&uloc::ULoc::try_from(locale).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn exemplar_sets() {
+        let en = data("en");
+        let none = uset::PatternOptions::default();
+        let standard = en.exemplar_set(none, ULOCDATA_ES_STANDARD).unwrap();
+        assert!(standard.contains('a' as sys::UChar32));
+        assert!(!standard.contains('A' as sys::UChar32));
+        assert!(!standard.contains('é' as sys::UChar32));
+        let auxiliary = en.exemplar_set(none, ULOCDATA_ES_AUXILIARY).unwrap();
+        assert!(auxiliary.contains('é' as sys::UChar32));
+        let index = en.exemplar_set(none, ULOCDATA_ES_INDEX).unwrap();
+        assert_eq!(26, index.size());
+        assert!(index.contains('Q' as sys::UChar32));
+        let punctuation = en.exemplar_set(none, ULOCDATA_ES_PUNCTUATION).unwrap();
+        assert!(punctuation.contains('!' as sys::UChar32));
+
+        let case_insensitive = en
+            .exemplar_set(uset::PatternOptions::CASE_INSENSITIVE, ULOCDATA_ES_STANDARD)
+            .unwrap();
+        assert!(case_insensitive.contains('A' as sys::UChar32));
+    }
+
+    #[test]
+    fn delimiters() {
+        let en = data("en");
+        let quotes: Vec<String> = vec![
+            ULOCDATA_QUOTATION_START,
+            ULOCDATA_QUOTATION_END,
+            ULOCDATA_ALT_QUOTATION_START,
+            ULOCDATA_ALT_QUOTATION_END,
+        ]
+        .into_iter()
+        .map(|t| en.delimiter(t).unwrap())
+        .collect();
+        assert_eq!(vec!["“", "”", "‘", "’"], quotes);
+        let fr = data("fr");
+        assert_eq!("«", fr.delimiter(ULOCDATA_QUOTATION_START).unwrap());
+    }
+
+    #[test]
+    fn display_pattern() {
+        let en = data("en");
+        assert_eq!("{0} ({1})", en.locale_display_pattern().unwrap());
+        assert_eq!(", ", en.locale_separator().unwrap());
+        let ja = data("ja");
+        assert_eq!("{0} ({1})", ja.locale_display_pattern().unwrap());
+        assert_eq!("、", ja.locale_separator().unwrap());
+    }
+
+    #[test]
+    fn no_substitute() {
+        let mut en = data("en");
+        assert!(!en.no_substitute());
+        en.set_no_substitute(true);
+        assert!(en.no_substitute());
+    }
+
+    #[test]
+    fn region_data() {
+        use sys::UMeasurementSystem::*;
+        let tests = vec![
+            ("en-US", UMS_US, 279, 216),
+            ("en-GB", UMS_UK, 297, 210),
+            ("de-DE", UMS_SI, 297, 210),
+            ("es-MX", UMS_SI, 279, 216),
+        ];
+        for (locale, system, height, width) in tests {
+            let locale = uloc::ULoc::try_from(locale).unwrap();
+            assert_eq!(system, measurement_system(&locale).unwrap(), "{}", locale);
+            assert_eq!(
+                PaperSize { height, width },
+                paper_size(&locale).unwrap(),
+                "{}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn cldr() {
+        assert!(cldr_version().unwrap()[0] >= 34);
+    }
+}
@@ -87,8 +87,8 @@
 //! ```
 
 use {
-    rust_icu_common as common, rust_icu_sys as sys, rust_icu_sys::*, rust_icu_uloc as uloc,
-    rust_icu_ustring as ustring, std::convert::TryFrom,
+    rust_icu_common as common, rust_icu_sys as sys, rust_icu_sys::*, rust_icu_ugender as ugender,
+    rust_icu_uloc as uloc, rust_icu_ustring as ustring, std::convert::TryFrom,
 };
 
 use sealed::Sealed;
@@ -96,7 +96,10 @@ use sealed::Sealed;
 mod pattern;
 
 #[doc(hidden)]
-pub use {rust_icu_sys as __sys, rust_icu_ustring as __ustring, std as __std};
+pub use {
+    rust_icu_sys as __sys, rust_icu_ugender as __ugender, rust_icu_ustring as __ustring,
+    std as __std,
+};
 
 /// The implementation of the ICU `UMessageFormat*`.
 ///
@@ -211,6 +214,7 @@ impl UMessageFormat {
     /// | `i32` | `Integer` |
     /// | `i64` | `Long` |
     /// | [rust_icu_ustring::UChar] | `String` |
+    /// | [rust_icu_ugender::Gender] | `Gender`, a `String` holding the gender's `select` keyword |
     ///
     /// For example, `(43.4_f64, 31337_i32)` binds a double to parameter `{0}` and an integer to
     /// parameter `{1}`. A single argument uses a one-element tuple, e.g. `(43.4_f64,)`.
@@ -330,6 +334,7 @@ impl UMessageFormat {
 /// | String | [rust_icu_ustring::UChar] | |
 /// | Integer | `i32` | |
 /// | Date | [rust_icu_sys::UDate] (alias for `f64`) | Is used to format dates.  Depending on the date format requested in the pattern used in [UMessageFormat], the end result of date formatting could be one of a wide variety of [date formats](http://userguide.icu-project.org/formatparse/datetime).|
+/// | Gender | [rust_icu_ugender::Gender] | Is passed as the string "male", "female" or "other", for use with a `select` argument.  See section below on Genders. |
 ///
 /// ## Double as numeric parameter
 ///
@@ -343,6 +348,13 @@ impl UMessageFormat {
 ///
 /// We determined by code inspection that the string format must be `rust_icu_ustring::UChar`.
 ///
+/// ## Genders
+///
+/// A [rust_icu_ugender::Gender], for example the gender of a list of people computed by
+/// `rust_icu_ugender::list_gender`, selects among the `male`, `female` and `other` cases of a
+/// `select` argument, such as `{0,select,female{elles} other{ils}}`.  It takes the place of a
+/// string in the pattern, so it must not be used where a number is expected.
+///
 /// # Example use
 ///
 /// ```
@@ -434,6 +446,10 @@ macro_rules! checkarg {
         let x: $crate::__sys::UDate = $e;
         x
     }};
+    ($e:expr, Gender) => {{
+        let x: $crate::__ugender::Gender = $e;
+        x
+    }};
 }
 
 #[doc(hidden)]
@@ -521,6 +537,24 @@ impl FormatArg for ustring::UChar {
     }
 }
 
+// The `select` keywords of the genders, as NUL-terminated UTF-16 strings, since
+// `umsg_format` reads string arguments up to a NUL.
+static MALE: [UChar; 5] = [0x6d, 0x61, 0x6c, 0x65, 0];
+static FEMALE: [UChar; 7] = [0x66, 0x65, 0x6d, 0x61, 0x6c, 0x65, 0];
+static OTHER: [UChar; 6] = [0x6f, 0x74, 0x68, 0x65, 0x72, 0];
+
+impl Sealed for ugender::Gender {}
+impl FormatArg for ugender::Gender {
+    type Raw = *const UChar;
+    fn to_raw(&self) -> Self::Raw {
+        match self {
+            ugender::Gender::Male => MALE.as_ptr(),
+            ugender::Gender::Female => FEMALE.as_ptr(),
+            ugender::Gender::Other => OTHER.as_ptr(),
+        }
+    }
+}
+
 impl Sealed for i32 {}
 impl FormatArg for i32 {
     type Raw = i32;
@@ -698,6 +732,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn select_by_list_gender() -> Result<(), common::Error> {
+        use ugender::Gender::*;
+        struct TestCase {
+            locale: &'static str,
+            pattern: &'static str,
+            genders: Vec<ugender::Gender>,
+            expected: &'static str,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "fr",
+                pattern: "{0,select,female{Elles sont venues} other{Ils sont venus}}.",
+                genders: vec![Female, Female],
+                expected: "Elles sont venues.",
+            },
+            TestCase {
+                locale: "fr",
+                pattern: "{0,select,female{Elles sont venues} other{Ils sont venus}}.",
+                genders: vec![Female, Male],
+                expected: "Ils sont venus.",
+            },
+            TestCase {
+                locale: "es",
+                pattern: "{1} {0,select,female{están cansadas} other{están cansados}}.",
+                genders: vec![Female, Female, Female],
+                expected: "Ellas están cansadas.",
+            },
+            TestCase {
+                locale: "es",
+                pattern: "{1} {0,select,female{están cansadas} other{están cansados}}.",
+                genders: vec![Female, Other],
+                expected: "Ellos están cansados.",
+            },
+        ];
+        for test in tests {
+            let loc = uloc::ULoc::try_from(test.locale)?;
+            let msg = ustring::UChar::try_from(test.pattern)?;
+            let fmt = crate::UMessageFormat::try_from(&msg, &loc)?;
+            let gender = ugender::list_gender(&loc, &test.genders)?;
+            let mut pronoun = ustring::UChar::try_from(match gender {
+                Female => "Ellas",
+                _ => "Ellos",
+            })?;
+            pronoun.make_z();
+            let result = message_format!(fmt, { gender => Gender }, { pronoun => String })?;
+            assert_eq!(test.expected, result, "genders: {:?}", test.genders);
+        }
+        Ok(())
+    }
+
     #[test]
     fn clone() -> Result<(), common::Error> {
         let loc = uloc::ULoc::try_from("en-US-u-tz-uslax")?;
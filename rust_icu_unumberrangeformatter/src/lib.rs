@@ -0,0 +1,439 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU number range formatting support for rust
+//!
+//! This crate formats ranges of numbers, such as "$3 – $5" or "~5 kg", in a locale-sensitive
+//! way.  It is a wrapper around the ICU library, specifically the functionality exposed through
+//! its C API, as available in the [header
+//! `unumberrangeformatter.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/unumberrangeformatter_8h.html).
+//!
+//! The formatter is built from a [number skeleton][skel], just like the formatter of
+//! `rust_icu_unumberformatter`.  Number range formatters are available since ICU 68.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_unumberrangeformatter as unumrf;
+//! use std::convert::TryFrom;
+//!
+//! let fmt = unumrf::UNumberRangeFormatter::try_new(
+//!     "currency/USD precision-integer",
+//!     sys::UNumberRangeCollapse::UNUM_RANGE_COLLAPSE_AUTO,
+//!     sys::UNumberRangeIdentityFallback::UNUM_IDENTITY_FALLBACK_APPROXIMATELY,
+//!     "en-US",
+//! )
+//! .unwrap();
+//! let result = fmt.format_double_range(3.0, 5.0).unwrap();
+//! assert_eq!("$3 – $5", String::try_from(&result).unwrap());
+//!
+//! let result = fmt.format_double_range(5.0, 5.0).unwrap();
+//! assert_eq!("~$5", String::try_from(&result).unwrap());
+//! ```
+//!
+//! [skel]: https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_uformattedvalue as ufmtval, rust_icu_uloc as uloc, rust_icu_ustring as ustring,
+    std::{convert::TryFrom, ptr},
+};
+
+/// Formats ranges of numbers, according to a skeleton and a locale.
+///
+/// Use [UNumberRangeFormatter::try_new] to create a new instance of this type.
+#[derive(Debug)]
+pub struct UNumberRangeFormatter {
+    rep: ptr::NonNull<sys::UNumberRangeFormatter>,
+}
+
+// Implements `unumrf_close`.
+simple_drop_impl!(UNumberRangeFormatter, unumrf_close);
+
+impl UNumberRangeFormatter {
+    /// Makes a new [UNumberRangeFormatter], using ICU types.
+    ///
+    /// See [UNumberRangeFormatter::try_new] for the description of the parameters.
+    ///
+    /// Implements `unumrf_openForSkeletonWithCollapseAndIdentityFallback`.
+    pub fn try_new_ustring(
+        skeleton: &ustring::UChar,
+        collapse: sys::UNumberRangeCollapse,
+        identity_fallback: sys::UNumberRangeIdentityFallback,
+        locale: &uloc::ULoc,
+    ) -> Result<UNumberRangeFormatter, common::Error> {
+        let locale = locale.as_c_str();
+        let mut status = common::Error::OK_CODE;
+        let mut parse_status = common::NO_PARSE_ERROR;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumrf_openForSkeletonWithCollapseAndIdentityFallback)(
+                skeleton.as_c_ptr(),
+                skeleton.len() as i32,
+                collapse,
+                identity_fallback,
+                locale.as_ptr(),
+                &mut parse_status,
+                &mut status,
+            )
+        };
+        common::parse_ok(parse_status)?;
+        common::Error::ok_or_warning(status)?;
+        Ok(UNumberRangeFormatter {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Makes a new [UNumberRangeFormatter], using Rust types.
+    ///
+    /// The `skeleton` describes how each number of the range is formatted, with the same
+    /// syntax as for `rust_icu_unumberformatter::UNumberFormatter::try_new`.  The `collapse`
+    /// option sets which repeated parts, such as the currency symbol or the unit, appear only
+    /// once in the result.  The `identity_fallback` option sets what is shown when both ends
+    /// of the range are the same after rounding, for example "~$5" instead of "$5–$5".
+    ///
+    /// Implements `unumrf_openForSkeletonWithCollapseAndIdentityFallback`.
+    pub fn try_new(
+        skeleton: &str,
+        collapse: sys::UNumberRangeCollapse,
+        identity_fallback: sys::UNumberRangeIdentityFallback,
+        locale: &str,
+    ) -> Result<UNumberRangeFormatter, common::Error> {
+        let locale = uloc::ULoc::try_from(locale)?;
+        let skeleton = ustring::UChar::try_from(skeleton)?;
+        UNumberRangeFormatter::try_new_ustring(&skeleton, collapse, identity_fallback, &locale)
+    }
+
+    /// Formats the range from `first` to `second`.
+    ///
+    /// Implements `unumrf_formatDoubleRange`.
+    pub fn format_double_range(
+        &self,
+        first: f64,
+        second: f64,
+    ) -> Result<UFormattedNumberRange, common::Error> {
+        let result = UFormattedNumberRange::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumrf_formatDoubleRange)(
+                self.rep.as_ptr(),
+                first,
+                second,
+                result.rep.as_ptr(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+
+    /// Formats the range from `first` to `second`, which are decimal numbers given as strings,
+    /// such as "9999999999999999.99".  This keeps the precision that a `f64` would lose.
+    ///
+    /// Implements `unumrf_formatDecimalRange`.
+    pub fn format_decimal_range(
+        &self,
+        first: &str,
+        second: &str,
+    ) -> Result<UFormattedNumberRange, common::Error> {
+        let result = UFormattedNumberRange::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumrf_formatDecimalRange)(
+                self.rep.as_ptr(),
+                first.as_ptr() as *const std::os::raw::c_char,
+                first.len() as i32,
+                second.as_ptr() as *const std::os::raw::c_char,
+                second.len() as i32,
+                result.rep.as_ptr(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+}
+
+/// Stores a formatted number range.
+///
+/// These objects are produced by [UNumberRangeFormatter::format_double_range] and
+/// [UNumberRangeFormatter::format_decimal_range].
+#[derive(Debug)]
+pub struct UFormattedNumberRange {
+    rep: ptr::NonNull<sys::UFormattedNumberRange>,
+}
+
+// Implements `unumrf_closeResult`.
+simple_drop_impl!(UFormattedNumberRange, unumrf_closeResult);
+
+impl UFormattedNumberRange {
+    // Implements `unumrf_openResult`.
+    fn try_new() -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumrf_openResult)(&mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UFormattedNumberRange {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Returns the formatted string and its fields.  The fields include the parts of both
+    /// numbers, as well as two spans in the category `UFIELD_CATEGORY_NUMBER_RANGE_SPAN`,
+    /// numbered 0 and 1, which cover the first and the second number.
+    ///
+    /// Implements `unumrf_resultAsValue`.
+    pub fn as_value(&self) -> Result<ufmtval::UFormattedValue<'_>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let value = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumrf_resultAsValue)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: the value is owned by `self`, which it borrows.
+        Ok(unsafe { ufmtval::UFormattedValue::from_raw(value) })
+    }
+
+    /// Returns whether the two ends of the range were equal, before or after rounding.  When
+    /// they were, the identity fallback option decides how the range was shown, for example
+    /// "~5 kg".
+    ///
+    /// Implements `unumrf_resultGetIdentityResult`.
+    pub fn identity_result(&self) -> Result<sys::UNumberRangeIdentityResult, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumrf_resultGetIdentityResult)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+
+    /// Returns the first number of the range, as a decimal number string after scaling and
+    /// rounding, such as "3.5".
+    ///
+    /// Implements `unumrf_resultGetFirstDecimalNumber`.
+    pub fn first_decimal_number(&self) -> Result<String, common::Error> {
+        const CAPACITY: usize = 30;
+        common::buffered_string_method_with_retry(
+            |buf, len, error| unsafe {
+                versioned_function!(unumrf_resultGetFirstDecimalNumber)(
+                    self.rep.as_ptr(),
+                    buf,
+                    len,
+                    error,
+                )
+            },
+            CAPACITY,
+        )
+    }
+
+    /// Returns the second number of the range, as a decimal number string after scaling and
+    /// rounding.
+    ///
+    /// Implements `unumrf_resultGetSecondDecimalNumber`.
+    pub fn second_decimal_number(&self) -> Result<String, common::Error> {
+        const CAPACITY: usize = 30;
+        common::buffered_string_method_with_retry(
+            |buf, len, error| unsafe {
+                versioned_function!(unumrf_resultGetSecondDecimalNumber)(
+                    self.rep.as_ptr(),
+                    buf,
+                    len,
+                    error,
+                )
+            },
+            CAPACITY,
+        )
+    }
+}
+
+impl TryFrom<&UFormattedNumberRange> for ustring::UChar {
+    type Error = common::Error;
+
+    /// Implements `unumrf_resultAsValue`.
+    fn try_from(result: &UFormattedNumberRange) -> Result<ustring::UChar, common::Error> {
+        ustring::UChar::try_from(&result.as_value()?)
+    }
+}
+
+impl TryFrom<&UFormattedNumberRange> for String {
+    type Error = common::Error;
+
+    /// Implements `unumrf_resultAsValue`.
+    fn try_from(result: &UFormattedNumberRange) -> Result<String, common::Error> {
+        String::try_from(&result.as_value()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatting_table() {
+        use sys::UNumberRangeCollapse as Collapse;
+        use sys::UNumberRangeIdentityFallback as Fallback;
+        #[derive(Debug)]
+        struct TestCase {
+            locale: &'static str,
+            skeleton: &'static str,
+            collapse: Collapse,
+            fallback: Fallback,
+            range: (f64, f64),
+            expected: &'static str,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "en-US",
+                skeleton: "currency/EUR",
+                collapse: Collapse::UNUM_RANGE_COLLAPSE_AUTO,
+                fallback: Fallback::UNUM_IDENTITY_FALLBACK_APPROXIMATELY,
+                range: (3.0, 5.0),
+                expected: "€3.00 – €5.00",
+            },
+            TestCase {
+                locale: "de-DE",
+                skeleton: "currency/EUR",
+                collapse: Collapse::UNUM_RANGE_COLLAPSE_AUTO,
+                fallback: Fallback::UNUM_IDENTITY_FALLBACK_APPROXIMATELY,
+                range: (3.0, 5.0),
+                expected: "3,00–5,00\u{a0}€",
+            },
+            TestCase {
+                locale: "en-US",
+                skeleton: "measure-unit/mass-kilogram",
+                collapse: Collapse::UNUM_RANGE_COLLAPSE_NONE,
+                fallback: Fallback::UNUM_IDENTITY_FALLBACK_APPROXIMATELY,
+                range: (3.0, 5.0),
+                expected: "3 kg – 5 kg",
+            },
+            TestCase {
+                locale: "en-US",
+                skeleton: "measure-unit/mass-kilogram",
+                collapse: Collapse::UNUM_RANGE_COLLAPSE_UNIT,
+                fallback: Fallback::UNUM_IDENTITY_FALLBACK_APPROXIMATELY,
+                range: (5.0, 5.0),
+                expected: "~5 kg",
+            },
+            TestCase {
+                locale: "en-US",
+                skeleton: "measure-unit/mass-kilogram",
+                collapse: Collapse::UNUM_RANGE_COLLAPSE_UNIT,
+                fallback: Fallback::UNUM_IDENTITY_FALLBACK_SINGLE_VALUE,
+                range: (5.0, 5.0),
+                expected: "5 kg",
+            },
+            TestCase {
+                locale: "en-US",
+                skeleton: "precision-integer",
+                collapse: Collapse::UNUM_RANGE_COLLAPSE_AUTO,
+                fallback: Fallback::UNUM_IDENTITY_FALLBACK_RANGE,
+                range: (4.9, 5.1),
+                expected: "5–5",
+            },
+        ];
+        for test in tests {
+            let fmt = UNumberRangeFormatter::try_new(
+                test.skeleton,
+                test.collapse,
+                test.fallback,
+                test.locale,
+            )
+            .unwrap_or_else(|_| panic!("for test {:?}", &test));
+            let result = fmt.format_double_range(test.range.0, test.range.1).unwrap();
+            assert_eq!(
+                test.expected,
+                String::try_from(&result).unwrap(),
+                "for test {:?}",
+                &test
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_range_and_identity() {
+        let fmt = UNumberRangeFormatter::try_new(
+            "precision-integer",
+            sys::UNumberRangeCollapse::UNUM_RANGE_COLLAPSE_AUTO,
+            sys::UNumberRangeIdentityFallback::UNUM_IDENTITY_FALLBACK_APPROXIMATELY,
+            "en-US",
+        )
+        .unwrap();
+        let result = fmt
+            .format_decimal_range("9999999999999999999.4", "10000000000000000000.6")
+            .unwrap();
+        assert_eq!(
+            "9,999,999,999,999,999,999–10,000,000,000,000,000,001",
+            String::try_from(&result).unwrap()
+        );
+        assert_eq!(
+            sys::UNumberRangeIdentityResult::UNUM_IDENTITY_RESULT_NOT_EQUAL,
+            result.identity_result().unwrap()
+        );
+        assert_eq!(
+            "9999999999999999999",
+            result.first_decimal_number().unwrap()
+        );
+        assert_eq!(
+            "10000000000000000001",
+            result.second_decimal_number().unwrap()
+        );
+
+        let result = fmt.format_double_range(5.2, 4.8).unwrap();
+        assert_eq!("~5", String::try_from(&result).unwrap());
+        assert_eq!(
+            sys::UNumberRangeIdentityResult::UNUM_IDENTITY_RESULT_EQUAL_AFTER_ROUNDING,
+            result.identity_result().unwrap()
+        );
+        let result = fmt.format_double_range(5.0, 5.0).unwrap();
+        assert_eq!(
+            sys::UNumberRangeIdentityResult::UNUM_IDENTITY_RESULT_EQUAL_BEFORE_ROUNDING,
+            result.identity_result().unwrap()
+        );
+    }
+
+    #[test]
+    fn range_spans() {
+        let fmt = UNumberRangeFormatter::try_new(
+            "",
+            sys::UNumberRangeCollapse::UNUM_RANGE_COLLAPSE_AUTO,
+            sys::UNumberRangeIdentityFallback::UNUM_IDENTITY_FALLBACK_APPROXIMATELY,
+            "en-US",
+        )
+        .unwrap();
+        let result = fmt.format_double_range(3.5, 12.0).unwrap();
+        let value = result.as_value().unwrap();
+        assert_eq!("3.5–12", String::try_from(&value).unwrap());
+        let spans: Vec<_> = value
+            .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_NUMBER_RANGE_SPAN)
+            .unwrap()
+            .into_iter()
+            .map(|f| (f.field, f.range))
+            .collect();
+        assert_eq!(vec![(0, 0..3), (1, 4..6)], spans);
+    }
+}
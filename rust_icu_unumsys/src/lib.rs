@@ -0,0 +1,316 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU numbering systems for rust
+//!
+//! This crate provides the numbering systems that numbers are written in, such as "latn" for
+//! the digits 0 to 9, "arab" for the Arabic-Indic digits, or "roman" for Roman numerals.  It
+//! is a wrapper around the ICU library, specifically the functionality exposed through its C
+//! API, as available in the [header
+//! `unumsys.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/unumsys_8h.html).
+//!
+//! The names of numbering systems are the values of the Unicode locale extension "nu", as in
+//! "th-TH-u-nu-thai", and of the `numberingSystem` options of ECMA-402.  Use
+//! [available_names] or [is_available] to check such values.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_uloc as uloc;
+//! use rust_icu_unumsys as unumsys;
+//! use std::convert::TryFrom;
+//!
+//! let locale = uloc::ULoc::try_from("ar-EG").unwrap();
+//! let numsys = unumsys::UNumberingSystem::try_new(&locale).unwrap();
+//! assert_eq!("arab", numsys.name());
+//! assert_eq!(10, numsys.radix());
+//! assert_eq!("٢٠٢٦", numsys.to_native_digits("2026").unwrap());
+//!
+//! assert!(unumsys::is_available("thai").unwrap());
+//! assert!(!unumsys::is_available("klingon").unwrap());
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_uenum as uenum, rust_icu_uloc as uloc, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ffi, ptr,
+    },
+};
+
+/// A numbering system, which defines how the digits of numbers are written.
+///
+/// A numbering system is either numeric, with a radix and one native digit per value below
+/// the radix, or algorithmic, with rules that turn numbers into text, such as Roman numerals.
+#[derive(Debug)]
+pub struct UNumberingSystem {
+    rep: ptr::NonNull<sys::UNumberingSystem>,
+}
+
+// Implements `unumsys_close`.
+simple_drop_impl!(UNumberingSystem, unumsys_close);
+
+impl UNumberingSystem {
+    /// Opens the default numbering system of `locale`, or the one that its "nu" keyword
+    /// selects.
+    ///
+    /// Implements `unumsys_open`.
+    pub fn try_new(locale: &uloc::ULoc) -> Result<Self, common::Error> {
+        let locale = locale.as_c_str();
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumsys_open)(locale.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UNumberingSystem {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Opens the numbering system named `name`, such as "latn" or "hanidec".  Fails with
+    /// `U_UNSUPPORTED_ERROR` if there is no numbering system of that name.
+    ///
+    /// Implements `unumsys_openByName`.
+    pub fn try_new_by_name(name: &str) -> Result<Self, common::Error> {
+        let name = ffi::CString::new(name)?;
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(unumsys_openByName)(name.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UNumberingSystem {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Returns the name of the numbering system, such as "latn".
+    ///
+    /// Implements `unumsys_getName`.
+    pub fn name(&self) -> String {
+        let name = unsafe { versioned_function!(unumsys_getName)(self.rep.as_ptr()) };
+        // Unsafety note: the name is a C string that the numbering system owns, which is
+        // copied before the numbering system can be closed.
+        unsafe { ffi::CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Returns the radix of the numbering system, for example 10 for decimal digits.
+    ///
+    /// Implements `unumsys_getRadix`.
+    pub fn radix(&self) -> i32 {
+        unsafe { versioned_function!(unumsys_getRadix)(self.rep.as_ptr()) }
+    }
+
+    /// Returns true if the numbering system is algorithmic, that is, if it writes numbers
+    /// by rules rather than with a fixed set of digits.
+    ///
+    /// Implements `unumsys_isAlgorithmic`.
+    pub fn is_algorithmic(&self) -> bool {
+        unsafe { versioned_function!(unumsys_isAlgorithmic)(self.rep.as_ptr()) != 0 }
+    }
+
+    /// Returns the description of the numbering system.  For a numeric system these are its
+    /// digits in order, such as "0123456789", and for an algorithmic system the name of the
+    /// rule set that it uses, such as "%roman-upper".
+    ///
+    /// Implements `unumsys_getDescription`.
+    pub fn description(&self) -> Result<String, common::Error> {
+        let result = self.description_ustring()?;
+        String::try_from(&result)
+    }
+
+    /// Returns the description of the numbering system as a [ustring::UChar].
+    ///
+    /// Implements `unumsys_getDescription`.
+    pub fn description_ustring(&self) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 16;
+        buffered_uchar_method_with_retry!(
+            description_impl,
+            CAPACITY,
+            [rep: *const sys::UNumberingSystem,],
+            []
+        );
+        description_impl(
+            versioned_function!(unumsys_getDescription),
+            self.rep.as_ptr(),
+        )
+    }
+
+    /// Returns the native digits of a decimal numbering system, from zero to nine.  Fails with
+    /// `U_UNSUPPORTED_ERROR` for algorithmic systems and for other radixes.
+    pub fn digits(&self) -> Result<Vec<char>, common::Error> {
+        if self.is_algorithmic() || self.radix() != 10 {
+            return Err(common::Error::Sys(sys::UErrorCode::U_UNSUPPORTED_ERROR));
+        }
+        let digits: Vec<char> = self.description()?.chars().collect();
+        if digits.len() != 10 {
+            return Err(common::Error::Sys(sys::UErrorCode::U_INVALID_FORMAT_ERROR));
+        }
+        Ok(digits)
+    }
+
+    /// Replaces the ASCII digits in `text` by the native digits of the numbering system, and
+    /// keeps all other characters.  Fails as [UNumberingSystem::digits] does.
+    pub fn to_native_digits(&self, text: &str) -> Result<String, common::Error> {
+        let digits = self.digits()?;
+        Ok(text
+            .chars()
+            .map(|c| match c.to_digit(10) {
+                Some(d) => digits[d as usize],
+                None => c,
+            })
+            .collect())
+    }
+
+    /// Replaces the native digits of the numbering system in `text` by ASCII digits, and
+    /// keeps all other characters.  Fails as [UNumberingSystem::digits] does.
+    pub fn from_native_digits(&self, text: &str) -> Result<String, common::Error> {
+        let digits = self.digits()?;
+        Ok(text
+            .chars()
+            .map(|c| match digits.iter().position(|&d| d == c) {
+                Some(d) => (b'0' + d as u8) as char,
+                None => c,
+            })
+            .collect())
+    }
+}
+
+/// Returns the names of all available numbering systems.
+///
+/// Implements `unumsys_openAvailableNames`.
+pub fn available_names() -> Result<uenum::Enumeration, common::Error> {
+    let mut status = common::Error::OK_CODE;
+    let rep = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(unumsys_openAvailableNames)(&mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    Ok(unsafe { uenum::Enumeration::from_raw_parts(None, rep) })
+}
+
+/// Returns true if `name` is the name of an available numbering system.  Names are case
+/// sensitive, as in ICU.
+pub fn is_available(name: &str) -> Result<bool, common::Error> {
+    for available in available_names()? {
+        if available? == name {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_for_locale() {
+        struct TestCase {
+            locale: &'static str,
+            name: &'static str,
+        }
+        let tests = vec![
+            TestCase {
+                locale: "en-US",
+                name: "latn",
+            },
+            TestCase {
+                locale: "ar-EG",
+                name: "arab",
+            },
+            TestCase {
+                locale: "th-TH-u-nu-thai",
+                name: "thai",
+            },
+            TestCase {
+                locale: "ja-JP-u-nu-jpan",
+                name: "jpan",
+            },
+        ];
+        for test in tests {
+            let locale = uloc::ULoc::try_from(test.locale).unwrap();
+            let numsys = UNumberingSystem::try_new(&locale)
+                .unwrap_or_else(|_| panic!("locale: {}", test.locale));
+            assert_eq!(test.name, numsys.name(), "locale: {}", test.locale);
+        }
+    }
+
+    #[test]
+    fn numeric_system() {
+        let numsys = UNumberingSystem::try_new_by_name("latn").unwrap();
+        assert_eq!("latn", numsys.name());
+        assert_eq!(10, numsys.radix());
+        assert!(!numsys.is_algorithmic());
+        assert_eq!("0123456789", numsys.description().unwrap());
+        assert_eq!("1,234.5", numsys.to_native_digits("1,234.5").unwrap());
+    }
+
+    #[test]
+    fn algorithmic_system() {
+        let numsys = UNumberingSystem::try_new_by_name("roman").unwrap();
+        assert!(numsys.is_algorithmic());
+        assert_eq!("%roman-upper", numsys.description().unwrap());
+        assert!(matches!(
+            numsys.to_native_digits("12"),
+            Err(common::Error::Sys(sys::UErrorCode::U_UNSUPPORTED_ERROR))
+        ));
+    }
+
+    #[test]
+    fn unknown_system() {
+        assert!(matches!(
+            UNumberingSystem::try_new_by_name("klingon"),
+            Err(common::Error::Sys(sys::UErrorCode::U_UNSUPPORTED_ERROR))
+        ));
+    }
+
+    #[test]
+    fn native_digits() {
+        let numsys = UNumberingSystem::try_new_by_name("thai").unwrap();
+        assert_eq!("๑๒,๓๔๕", numsys.to_native_digits("12,345").unwrap());
+        assert_eq!("12,345", numsys.from_native_digits("๑๒,๓๔๕").unwrap());
+
+        // The mathematical bold digits are outside of the basic multilingual plane.
+        let numsys = UNumberingSystem::try_new_by_name("mathbold").unwrap();
+        assert_eq!("𝟐𝟎𝟐𝟔", numsys.to_native_digits("2026").unwrap());
+        assert_eq!("2026", numsys.from_native_digits("𝟐𝟎𝟐𝟔").unwrap());
+    }
+
+    #[test]
+    fn available() {
+        let names: Vec<String> = available_names()
+            .unwrap()
+            .map(|name| name.unwrap())
+            .collect();
+        assert!(names.len() > 50);
+        assert!(names.contains(&"latn".to_string()));
+        assert!(names.contains(&"hanidec".to_string()));
+        assert!(is_available("arab").unwrap());
+        assert!(!is_available("ARAB").unwrap());
+        assert!(!is_available("").unwrap());
+    }
+}
@@ -0,0 +1,844 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU regular expression support for rust
+//!
+//! This crate provides regular expressions, as implemented by the ICU library.  Specifically the
+//! functionality exposed through its C API, as available in the [header
+//! `uregex.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/uregex_8h.html).
+//!
+//! The ICU regular expression syntax is described in the [ICU user
+//! guide](https://unicode-org.github.io/icu/userguide/strings/regexp.html).  It is compatible with
+//! the one used by Java's `java.util.regex`, and supports Unicode properties and UAX#29 word
+//! boundaries.
+//!
+//! The main type is [URegex].  As in ICU, a [URegex] holds both the compiled pattern and the state
+//! of matching it against an input text.  The text is set with [URegex::set_text], after which
+//! matches can be found with [URegex::find_next], and inspected with [URegex::group],
+//! [URegex::start] and [URegex::end].
+//!
+//! When the text is set from a `&str`, all indexes used by the API are byte offsets into the
+//! `&str`.  When it is set from a [ustring::UChar], the indexes are UTF-16 code unit offsets.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_uregex as uregex;
+//!
+//! let mut re = uregex::URegex::try_new(
+//!     r"(?<word>\p{L}+)-(\d+)",
+//!     sys::URegexpFlag::UREGEX_CASE_INSENSITIVE as u32,
+//! )
+//! .expect("regex");
+//! re.set_text("Straße-12, Ωμέγα-7").expect("set_text");
+//! assert!(re.find_next().expect("find_next"));
+//! assert_eq!(Some("Straße".to_string()), re.group_by_name("word").expect("group"));
+//! assert_eq!(Some("12".to_string()), re.group(2).expect("group"));
+//! assert!(re.find_next().expect("find_next"));
+//! assert_eq!(Some(12..22), re.range(1).expect("range"));
+//! assert!(!re.find_next().expect("find_next"));
+//!
+//! assert_eq!("12:Straße, 7:Ωμέγα", re.replace_all("$2:${word}").expect("replace_all"));
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ops,
+        os::raw,
+        ptr, slice,
+    },
+};
+
+// The text that the regular expression is currently matched against.  ICU does not copy the
+// text, so it is kept here for as long as the regular expression uses it.
+#[derive(Debug)]
+enum Input {
+    Utf8(String),
+    Utf16(ustring::UChar),
+}
+
+/// A compiled regular expression, together with the state of matching it against a text.
+#[derive(Debug)]
+pub struct URegex {
+    rep: ptr::NonNull<sys::URegularExpression>,
+    input: Input,
+}
+
+// Implements `uregex_close`
+simple_drop_impl!(URegex, uregex_close);
+
+// Safety: the regular expression has no affinity to the thread that created it, and the input
+// text is owned.
+unsafe impl Send for URegex {}
+
+impl URegex {
+    /// Compiles the regular expression `pattern`.
+    ///
+    /// `flags` is a bitmask of [sys::URegexpFlag] values, for example
+    /// `UREGEX_CASE_INSENSITIVE as u32 | UREGEX_MULTILINE as u32`, or 0 for no flags.
+    ///
+    /// Implements `uregex_open`
+    pub fn try_new(pattern: &str, flags: u32) -> Result<Self, common::Error> {
+        let pattern = ustring::UChar::try_from(pattern)?;
+        Self::try_new_ustring(&pattern, flags)
+    }
+
+    /// Implements `uregex_open`
+    pub fn try_new_ustring(pattern: &ustring::UChar, flags: u32) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let mut parse_status = common::NO_PARSE_ERROR;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_open)(
+                pattern.as_c_ptr(),
+                pattern.len() as i32,
+                flags,
+                &mut parse_status,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        common::parse_ok(parse_status)?;
+        Ok(URegex {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            input: Input::Utf16(ustring::UChar::from(vec![])),
+        })
+    }
+
+    /// Makes a copy of this regular expression.  The copy has no text set.
+    ///
+    /// Implements `uregex_clone`
+    pub fn try_clone(&self) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_clone)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(URegex {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            input: Input::Utf16(ustring::UChar::from(vec![])),
+        })
+    }
+
+    /// Returns the source pattern of this regular expression.
+    ///
+    /// Implements `uregex_pattern`
+    pub fn pattern(&self) -> Result<String, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let mut len: i32 = 0;
+        let pattern = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_pattern)(self.rep.as_ptr(), &mut len, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: ICU returns a pointer to its own copy of the pattern, which is valid
+        // for as long as the regular expression is.
+        let pattern =
+            unsafe { ustring::UChar::clone_from_raw_parts(pattern as *mut sys::UChar, len) };
+        String::try_from(&pattern)
+    }
+
+    /// Returns the flags that this regular expression was compiled with.
+    ///
+    /// Implements `uregex_flags`
+    pub fn flags(&self) -> Result<u32, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let flags = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_flags)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(flags as u32)
+    }
+
+    /// Sets the text to match against, and resets the matching state.  All indexes reported
+    /// after this call are byte offsets into `text`.
+    ///
+    /// Implements `uregex_setUText`
+    pub fn set_text(&mut self, text: &str) -> Result<(), common::Error> {
+        let text = text.to_string();
+        let mut status = common::Error::OK_CODE;
+        // Unsafety note: uregex_setUText makes a shallow copy of the UText, which keeps pointing
+        // at the bytes of `text`.  `text` is moved into `self.input` below, which keeps the
+        // bytes in place until the text is replaced or the regular expression is closed.
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            let utext = versioned_function!(utext_openUTF8)(
+                ptr::null_mut(),
+                text.as_ptr() as *const raw::c_char,
+                text.len() as i64,
+                &mut status,
+            );
+            common::Error::ok_or_warning(status)?;
+            versioned_function!(uregex_setUText)(self.rep.as_ptr(), utext, &mut status);
+            versioned_function!(utext_close)(utext);
+        }
+        common::Error::ok_or_warning(status)?;
+        self.input = Input::Utf8(text);
+        Ok(())
+    }
+
+    /// Sets the text to match against, and resets the matching state.  All indexes reported
+    /// after this call are UTF-16 code unit offsets into `text`.
+    ///
+    /// Implements `uregex_setText`
+    pub fn set_text_ustring(&mut self, text: &ustring::UChar) -> Result<(), common::Error> {
+        let text = text.clone();
+        let mut status = common::Error::OK_CODE;
+        // Unsafety note: ICU keeps pointing at the contents of `text`, which is kept in
+        // `self.input` for as long as ICU uses it.
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_setText)(
+                self.rep.as_ptr(),
+                text.as_c_ptr(),
+                text.len() as i32,
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        self.input = Input::Utf16(text);
+        Ok(())
+    }
+
+    /// Returns true if the entire region of the text matches the pattern.
+    ///
+    /// Implements `uregex_matches64`
+    pub fn matches(&mut self) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_matches64)(self.rep.as_ptr(), -1, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Returns true if the pattern matches a prefix of the region of the text.
+    ///
+    /// Implements `uregex_lookingAt64`
+    pub fn looking_at(&mut self) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_lookingAt64)(self.rep.as_ptr(), -1, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Resets the matching state, and finds the first match at or after `start_index`.  The
+    /// region, if any, is ignored.  Returns true if a match was found.
+    ///
+    /// Implements `uregex_find64`
+    pub fn find(&mut self, start_index: usize) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_find64)(self.rep.as_ptr(), start_index as i64, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Finds the next match in the region of the text, after the previous match if any.
+    /// Returns true if a match was found.
+    ///
+    /// Implements `uregex_findNext`
+    pub fn find_next(&mut self) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_findNext)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Sets `text` as the text to match, and returns the ranges of all matches in it, as byte
+    /// offsets.
+    pub fn find_all(&mut self, text: &str) -> Result<Vec<ops::Range<usize>>, common::Error> {
+        self.set_text(text)?;
+        self.collect_matches()
+    }
+
+    /// Sets `text` as the text to match, and returns the ranges of all matches in it, as UTF-16
+    /// code unit offsets.
+    pub fn find_all_ustring(
+        &mut self,
+        text: &ustring::UChar,
+    ) -> Result<Vec<ops::Range<usize>>, common::Error> {
+        self.set_text_ustring(text)?;
+        self.collect_matches()
+    }
+
+    fn collect_matches(&mut self) -> Result<Vec<ops::Range<usize>>, common::Error> {
+        let mut result = vec![];
+        while self.find_next()? {
+            // A successful match always has a range for group 0.
+            result.push(self.range(0)?.unwrap());
+        }
+        Ok(result)
+    }
+
+    /// Returns the number of capturing groups in the pattern.
+    ///
+    /// Implements `uregex_groupCount`
+    pub fn group_count(&self) -> Result<usize, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_groupCount)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result as usize)
+    }
+
+    /// Returns the number of the capturing group named `name`.
+    ///
+    /// Implements `uregex_groupNumberFromName`
+    pub fn group_number_from_name(&self, name: &str) -> Result<usize, common::Error> {
+        let name = ustring::UChar::try_from(name)?;
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_groupNumberFromName)(
+                self.rep.as_ptr(),
+                name.as_c_ptr(),
+                name.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result as usize)
+    }
+
+    /// Returns the index in the text at which capture group `group` of the current match
+    /// starts, or `None` if the group did not participate in the match.  Group 0 is the entire
+    /// match.
+    ///
+    /// Implements `uregex_start64`
+    pub fn start(&self, group: usize) -> Result<Option<usize>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_start64)(self.rep.as_ptr(), group as i32, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result.try_into().ok())
+    }
+
+    /// Returns the index in the text at which capture group `group` of the current match ends,
+    /// or `None` if the group did not participate in the match.  Group 0 is the entire match.
+    ///
+    /// Implements `uregex_end64`
+    pub fn end(&self, group: usize) -> Result<Option<usize>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_end64)(self.rep.as_ptr(), group as i32, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result.try_into().ok())
+    }
+
+    /// Returns the range of the text matched by capture group `group` of the current match, or
+    /// `None` if the group did not participate in the match.
+    pub fn range(&self, group: usize) -> Result<Option<ops::Range<usize>>, common::Error> {
+        match (self.start(group)?, self.end(group)?) {
+            (Some(start), Some(end)) => Ok(Some(start..end)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the text matched by capture group `group` of the current match, or `None` if the
+    /// group did not participate in the match.  Group 0 is the entire match.
+    pub fn group(&self, group: usize) -> Result<Option<String>, common::Error> {
+        let range = match self.range(group)? {
+            None => return Ok(None),
+            Some(range) => range,
+        };
+        let text = match &self.input {
+            Input::Utf8(text) => text[range].to_string(),
+            Input::Utf16(text) => {
+                // Unsafety note: ICU only reports ranges within the text.
+                let units = unsafe { slice::from_raw_parts(text.as_c_ptr(), text.len()) };
+                String::try_from(&ustring::UChar::from(units[range].to_vec()))?
+            }
+        };
+        Ok(Some(text))
+    }
+
+    /// Returns the text matched by the capture group named `name`, or `None` if the group did
+    /// not participate in the match.
+    pub fn group_by_name(&self, name: &str) -> Result<Option<String>, common::Error> {
+        let group = self.group_number_from_name(name)?;
+        self.group(group)
+    }
+
+    /// Resets the matching state, so that the next call to [URegex::find_next] starts at
+    /// `index`.  The region is reset to the entire text.
+    ///
+    /// Implements `uregex_reset64`
+    pub fn reset(&mut self, index: usize) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_reset64)(self.rep.as_ptr(), index as i64, &mut status)
+        };
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Limits matching to the range `start..end` of the text, and resets the matching state.
+    ///
+    /// Implements `uregex_setRegion64`
+    pub fn set_region(&mut self, start: usize, end: usize) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_setRegion64)(
+                self.rep.as_ptr(),
+                start as i64,
+                end as i64,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Returns the start index of the region of the text that is matched.
+    ///
+    /// Implements `uregex_regionStart64`
+    pub fn region_start(&self) -> Result<usize, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_regionStart64)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result as usize)
+    }
+
+    /// Returns the end index of the region of the text that is matched.
+    ///
+    /// Implements `uregex_regionEnd64`
+    pub fn region_end(&self) -> Result<usize, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_regionEnd64)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result as usize)
+    }
+
+    /// Sets whether `^` and `$` match at the bounds of the region, rather than only at the
+    /// bounds of the text.  This is on by default.
+    ///
+    /// Implements `uregex_useAnchoringBounds`
+    pub fn use_anchoring_bounds(&mut self, anchoring: bool) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_useAnchoringBounds)(
+                self.rep.as_ptr(),
+                anchoring as sys::UBool,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Implements `uregex_hasAnchoringBounds`
+    pub fn has_anchoring_bounds(&self) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_hasAnchoringBounds)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Sets whether lookahead, lookbehind and word boundaries can see the text outside of the
+    /// region.  This is off by default.
+    ///
+    /// Implements `uregex_useTransparentBounds`
+    pub fn use_transparent_bounds(&mut self, transparent: bool) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_useTransparentBounds)(
+                self.rep.as_ptr(),
+                transparent as sys::UBool,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Implements `uregex_hasTransparentBounds`
+    pub fn has_transparent_bounds(&self) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_hasTransparentBounds)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Returns true if the end of the text was reached by the most recent match operation.
+    ///
+    /// Implements `uregex_hitEnd`
+    pub fn hit_end(&self) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_hitEnd)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Returns true if more input could change a positive match into a negative one.
+    ///
+    /// Implements `uregex_requireEnd`
+    pub fn require_end(&self) -> Result<bool, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_requireEnd)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result != 0)
+    }
+
+    /// Returns the text with every match replaced by `replacement`, in which `$n` and `${name}`
+    /// refer to capture groups.  Resets the matching state.
+    ///
+    /// Implements `uregex_replaceAll`
+    pub fn replace_all(&mut self, replacement: &str) -> Result<String, common::Error> {
+        let replacement = ustring::UChar::try_from(replacement)?;
+        let result = self.replace_all_ustring(&replacement)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `uregex_replaceAll`
+    pub fn replace_all_ustring(
+        &mut self,
+        replacement: &ustring::UChar,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 200;
+        buffered_uchar_method_with_retry!(
+            replace_all_raw,
+            CAPACITY,
+            [
+                rep: *mut sys::URegularExpression,
+                replacement: *const sys::UChar,
+                replacement_length: i32,
+            ],
+            []
+        );
+        replace_all_raw(
+            versioned_function!(uregex_replaceAll),
+            self.rep.as_ptr(),
+            replacement.as_c_ptr(),
+            replacement.len() as i32,
+        )
+    }
+
+    /// Returns the text with the first match replaced by `replacement`, in which `$n` and
+    /// `${name}` refer to capture groups.  Resets the matching state.
+    ///
+    /// Implements `uregex_replaceFirst`
+    pub fn replace_first(&mut self, replacement: &str) -> Result<String, common::Error> {
+        let replacement = ustring::UChar::try_from(replacement)?;
+        let result = self.replace_first_ustring(&replacement)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `uregex_replaceFirst`
+    pub fn replace_first_ustring(
+        &mut self,
+        replacement: &ustring::UChar,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 200;
+        buffered_uchar_method_with_retry!(
+            replace_first_raw,
+            CAPACITY,
+            [
+                rep: *mut sys::URegularExpression,
+                replacement: *const sys::UChar,
+                replacement_length: i32,
+            ],
+            []
+        );
+        replace_first_raw(
+            versioned_function!(uregex_replaceFirst),
+            self.rep.as_ptr(),
+            replacement.as_c_ptr(),
+            replacement.len() as i32,
+        )
+    }
+
+    /// Splits the text into fields separated by matches of the pattern.  Text matched by capture
+    /// groups in the pattern is included as fields of its own.  Resets the matching state.
+    ///
+    /// Implements `uregex_split`
+    pub fn split(&mut self) -> Result<Vec<String>, common::Error> {
+        self.split_ustring()?.iter().map(String::try_from).collect()
+    }
+
+    /// Implements `uregex_split`
+    pub fn split_ustring(&mut self) -> Result<Vec<ustring::UChar>, common::Error> {
+        // ICU puts whatever does not fit into the fields array into the last field, so make
+        // room for a field per capture group per match, plus the trailing field.
+        let group_count = self.group_count()?;
+        self.reset(0)?;
+        let mut match_count = 0;
+        while self.find_next()? {
+            match_count += 1;
+        }
+        let fields_capacity = match_count * (group_count + 1) + 1;
+        let mut fields: Vec<*mut sys::UChar> = vec![ptr::null_mut(); fields_capacity];
+
+        let mut status = common::Error::OK_CODE;
+        let mut required: i32 = 0;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_split)(
+                self.rep.as_ptr(),
+                ptr::null_mut(),
+                0,
+                &mut required,
+                fields.as_mut_ptr(),
+                fields_capacity as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_preflight(status)?;
+
+        let mut buf: Vec<sys::UChar> = vec![0; required as usize];
+        status = common::Error::OK_CODE;
+        let count = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uregex_split)(
+                self.rep.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len() as i32,
+                &mut required,
+                fields.as_mut_ptr(),
+                fields_capacity as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+
+        // Each field is followed by a terminating NUL in `buf`, and the fields are in order, so
+        // a field ends just before the next one starts.
+        let count = count as usize;
+        let offsets = fields[..count]
+            .iter()
+            .map(|field| {
+                (*field as usize - buf.as_ptr() as usize) / std::mem::size_of::<sys::UChar>()
+            })
+            .chain(std::iter::once(required as usize))
+            .collect::<Vec<usize>>();
+        Ok(offsets
+            .windows(2)
+            .map(|w| ustring::UChar::from(buf[w[0]..w[1] - 1].to_vec()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_and_flags() {
+        let flags = sys::URegexpFlag::UREGEX_CASE_INSENSITIVE as u32
+            | sys::URegexpFlag::UREGEX_COMMENTS as u32;
+        let re = URegex::try_new("a b # comment", flags).expect("regex");
+        assert_eq!("a b # comment", re.pattern().expect("pattern"));
+        assert_eq!(flags, re.flags().expect("flags"));
+
+        URegex::try_new("(unclosed", 0).expect_err("malformed pattern");
+    }
+
+    #[test]
+    fn matches_and_looking_at() {
+        let mut re = URegex::try_new(r"\d+", 0).expect("regex");
+        re.set_text("123").unwrap();
+        assert!(re.matches().unwrap());
+        re.set_text("123abc").unwrap();
+        assert!(!re.matches().unwrap());
+        assert!(re.looking_at().unwrap());
+        re.set_text("abc123").unwrap();
+        assert!(!re.looking_at().unwrap());
+    }
+
+    #[test]
+    fn case_insensitive_and_comments() {
+        let mut re = URegex::try_new(
+            "straße  # a street",
+            sys::URegexpFlag::UREGEX_CASE_INSENSITIVE as u32
+                | sys::URegexpFlag::UREGEX_COMMENTS as u32,
+        )
+        .expect("regex");
+        assert_eq!(vec![4..11], re.find_all("Die STRASSE").unwrap());
+        assert_eq!(vec![4..11], re.find_all("Die Straße!").unwrap());
+    }
+
+    #[test]
+    fn multiline() {
+        let text = "one\ntwo\nthree";
+        let mut re = URegex::try_new(r"^\w+$", 0).expect("regex");
+        assert!(re.find_all(text).unwrap().is_empty());
+        let mut re =
+            URegex::try_new(r"^\w+$", sys::URegexpFlag::UREGEX_MULTILINE as u32).expect("regex");
+        assert_eq!(vec![0..3, 4..7, 8..13], re.find_all(text).unwrap());
+    }
+
+    #[test]
+    fn uword_boundaries() {
+        let text = "can't stop";
+        let mut re = URegex::try_new(r"\b\w+\b", 0).expect("regex");
+        assert_eq!(vec![0..3, 4..5, 6..10], re.find_all(text).unwrap());
+        let mut re =
+            URegex::try_new(r"\b\S+\b", sys::URegexpFlag::UREGEX_UWORD as u32).expect("regex");
+        assert_eq!(vec![0..5, 6..10], re.find_all(text).unwrap());
+    }
+
+    #[test]
+    fn utf8_and_utf16_offsets() {
+        let mut re = URegex::try_new(r"\p{Greek}+", 0).expect("regex");
+        assert_eq!(vec![4..14], re.find_all("abc Ωμέγα def").unwrap());
+
+        let text = ustring::UChar::try_from("abc Ωμέγα def").unwrap();
+        assert_eq!(vec![4..9], re.find_all_ustring(&text).unwrap());
+        re.find(0).unwrap();
+        assert_eq!(Some("Ωμέγα".to_string()), re.group(0).unwrap());
+    }
+
+    #[test]
+    fn capture_groups() {
+        let mut re = URegex::try_new(r"(?<key>\w+)=(\w+)?(;)?", 0).expect("regex");
+        assert_eq!(3, re.group_count().unwrap());
+        assert_eq!(1, re.group_number_from_name("key").unwrap());
+        re.group_number_from_name("nope")
+            .expect_err("no such group");
+
+        re.set_text("a=1; b=").unwrap();
+        assert!(re.find_next().unwrap());
+        assert_eq!(Some("a".to_string()), re.group_by_name("key").unwrap());
+        assert_eq!(Some("1".to_string()), re.group(2).unwrap());
+        assert_eq!(Some(3..4), re.range(3).unwrap());
+        assert!(re.find_next().unwrap());
+        assert_eq!(Some("b".to_string()), re.group(1).unwrap());
+        assert_eq!(None, re.group(2).unwrap());
+        assert_eq!(None, re.start(3).unwrap());
+        re.group(4).expect_err("group out of range");
+        assert!(!re.find_next().unwrap());
+        assert!(re.hit_end().unwrap());
+    }
+
+    #[test]
+    fn region_and_anchoring() {
+        let mut re = URegex::try_new(r"^\d+$", 0).expect("regex");
+        re.set_text("ab123cd").unwrap();
+        assert!(!re.find_next().unwrap());
+
+        re.set_region(2, 5).unwrap();
+        assert_eq!(2, re.region_start().unwrap());
+        assert_eq!(5, re.region_end().unwrap());
+        assert!(re.has_anchoring_bounds().unwrap());
+        assert!(re.matches().unwrap());
+
+        re.use_anchoring_bounds(false).unwrap();
+        assert!(!re.has_anchoring_bounds().unwrap());
+        re.set_region(2, 5).unwrap();
+        assert!(!re.find_next().unwrap());
+
+        let mut re = URegex::try_new(r"\b\d+", 0).expect("regex");
+        re.set_text("ab123").unwrap();
+        re.set_region(2, 5).unwrap();
+        assert!(re.find_next().unwrap());
+        re.use_transparent_bounds(true).unwrap();
+        assert!(re.has_transparent_bounds().unwrap());
+        re.set_region(2, 5).unwrap();
+        assert!(!re.find_next().unwrap());
+
+        re.reset(0).unwrap();
+        assert_eq!(0, re.region_start().unwrap());
+        assert_eq!(5, re.region_end().unwrap());
+        assert!(!re.find(0).unwrap());
+    }
+
+    #[test]
+    fn replace() {
+        let mut re = URegex::try_new(r"(\w+)@(\w+)", 0).expect("regex");
+        re.set_text("alice@example, bob@test").unwrap();
+        assert_eq!(
+            "example@alice, bob@test",
+            re.replace_first("$2@$1").unwrap()
+        );
+        assert_eq!("example@alice, test@bob", re.replace_all("$2@$1").unwrap());
+
+        let long = "x@y ".repeat(100);
+        re.set_text(&long).unwrap();
+        assert_eq!("y@x ".repeat(100), re.replace_all("$2@$1").unwrap());
+        re.replace_all("$3").expect_err("no such group");
+    }
+
+    #[test]
+    fn split() {
+        let mut re = URegex::try_new(r"\s*,\s*", 0).expect("regex");
+        re.set_text("a , b,c,,d").unwrap();
+        assert_eq!(vec!["a", "b", "c", "", "d"], re.split().unwrap());
+
+        let mut re = URegex::try_new(r"(-)", 0).expect("regex");
+        re.set_text_ustring(&ustring::UChar::try_from("1-2-3").unwrap())
+            .unwrap();
+        assert_eq!(vec!["1", "-", "2", "-", "3"], re.split().unwrap());
+
+        re.set_text("no separators").unwrap();
+        assert_eq!(vec!["no separators"], re.split().unwrap());
+    }
+
+    #[test]
+    fn clone() {
+        let mut re = URegex::try_new(r"\d", 0).expect("regex");
+        re.set_text("a1").unwrap();
+        let mut copy = re.try_clone().expect("clone");
+        assert_eq!(r"\d", copy.pattern().unwrap());
+        assert_eq!(vec![1..2], copy.find_all("b2").unwrap());
+        assert!(re.find_next().unwrap());
+        assert_eq!(Some("1".to_string()), re.group(0).unwrap());
+    }
+}
@@ -0,0 +1,479 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU relative date and time formatting for rust
+//!
+//! This crate formats offsets from the present, such as "3 hours ago", "in 2 weeks" or
+//! "yesterday", in any locale.  It is a wrapper around the ICU library, specifically the
+//! functionality exposed through its C API, as available in the [header
+//! `ureldatefmt.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ureldatefmt_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_ureldatefmt as ureldatefmt;
+//!
+//! let fmt = ureldatefmt::URelativeDateTimeFormatter::try_new(
+//!     "en-US",
+//!     sys::UDateRelativeDateTimeFormatterStyle::UDAT_STYLE_LONG,
+//!     sys::UDisplayContext::UDISPCTX_CAPITALIZATION_NONE,
+//! )
+//! .unwrap();
+//! let unit = sys::URelativeDateTimeUnit::UDAT_REL_UNIT_HOUR;
+//! assert_eq!("3 hours ago", fmt.format_numeric(-3.0, unit).unwrap());
+//!
+//! // The "auto" style uses phrases such as "yesterday" where the locale has them.
+//! let unit = sys::URelativeDateTimeUnit::UDAT_REL_UNIT_DAY;
+//! assert_eq!("yesterday", fmt.format(-1.0, unit).unwrap());
+//! assert_eq!("1 day ago", fmt.format_numeric(-1.0, unit).unwrap());
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_uformattedvalue as ufmtval, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ffi, ptr,
+    },
+};
+
+/// Formats relative dates and times, such as "in 3 days" or "last month".
+#[derive(Debug)]
+pub struct URelativeDateTimeFormatter {
+    rep: ptr::NonNull<sys::URelativeDateTimeFormatter>,
+}
+
+// Implements `ureldatefmt_close`.
+simple_drop_impl!(URelativeDateTimeFormatter, ureldatefmt_close);
+
+impl URelativeDateTimeFormatter {
+    /// Creates a formatter for `locale`, with the given `width` of the unit names, and the
+    /// `capitalization` context in which the results are used, for example
+    /// `UDISPCTX_CAPITALIZATION_FOR_BEGINNING_OF_SENTENCE`.
+    ///
+    /// The formatter uses the default number format of the locale.
+    ///
+    /// Implements `ureldatefmt_open`.
+    pub fn try_new(
+        locale: &str,
+        width: sys::UDateRelativeDateTimeFormatterStyle,
+        capitalization: sys::UDisplayContext,
+    ) -> Result<Self, common::Error> {
+        let locale = ffi::CString::new(locale)?;
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ureldatefmt_open)(
+                locale.as_ptr(),
+                ptr::null_mut(),
+                width,
+                capitalization,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(URelativeDateTimeFormatter {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Formats `offset` units from now, always using a number, as in "in 1 day" or "1 day
+    /// ago".  A negative offset is in the past.
+    ///
+    /// Implements `ureldatefmt_formatNumeric`.
+    pub fn format_numeric(
+        &self,
+        offset: f64,
+        unit: sys::URelativeDateTimeUnit,
+    ) -> Result<String, common::Error> {
+        let result = self.format_numeric_ustring(offset, unit)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `ureldatefmt_formatNumeric`.
+    pub fn format_numeric_ustring(
+        &self,
+        offset: f64,
+        unit: sys::URelativeDateTimeUnit,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 50;
+        buffered_uchar_method_with_retry!(
+            format_numeric_impl,
+            CAPACITY,
+            [
+                rep: *const sys::URelativeDateTimeFormatter,
+                offset: f64,
+                unit: sys::URelativeDateTimeUnit,
+            ],
+            []
+        );
+        format_numeric_impl(
+            versioned_function!(ureldatefmt_formatNumeric),
+            self.rep.as_ptr(),
+            offset,
+            unit,
+        )
+    }
+
+    /// Formats `offset` units from now, using a phrase such as "tomorrow" or "next week"
+    /// where the locale has one, and a number otherwise.  This is the "auto" style of
+    /// ECMA-402.
+    ///
+    /// Implements `ureldatefmt_format`.
+    pub fn format(
+        &self,
+        offset: f64,
+        unit: sys::URelativeDateTimeUnit,
+    ) -> Result<String, common::Error> {
+        let result = self.format_ustring(offset, unit)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `ureldatefmt_format`.
+    pub fn format_ustring(
+        &self,
+        offset: f64,
+        unit: sys::URelativeDateTimeUnit,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 50;
+        buffered_uchar_method_with_retry!(
+            format_impl,
+            CAPACITY,
+            [
+                rep: *const sys::URelativeDateTimeFormatter,
+                offset: f64,
+                unit: sys::URelativeDateTimeUnit,
+            ],
+            []
+        );
+        format_impl(
+            versioned_function!(ureldatefmt_format),
+            self.rep.as_ptr(),
+            offset,
+            unit,
+        )
+    }
+
+    /// Same as [URelativeDateTimeFormatter::format_numeric], but returns the result with
+    /// its fields.  Since ICU 64.
+    ///
+    /// Implements `ureldatefmt_formatNumericToResult`.
+    pub fn format_numeric_to_result(
+        &self,
+        offset: f64,
+        unit: sys::URelativeDateTimeUnit,
+    ) -> Result<UFormattedRelativeDateTime, common::Error> {
+        let result = UFormattedRelativeDateTime::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ureldatefmt_formatNumericToResult)(
+                self.rep.as_ptr(),
+                offset,
+                unit,
+                result.rep.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+
+    /// Same as [URelativeDateTimeFormatter::format], but returns the result with its fields.
+    /// Since ICU 64.
+    ///
+    /// Implements `ureldatefmt_formatToResult`.
+    pub fn format_to_result(
+        &self,
+        offset: f64,
+        unit: sys::URelativeDateTimeUnit,
+    ) -> Result<UFormattedRelativeDateTime, common::Error> {
+        let result = UFormattedRelativeDateTime::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ureldatefmt_formatToResult)(
+                self.rep.as_ptr(),
+                offset,
+                unit,
+                result.rep.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+
+    /// Combines a relative date, such as "tomorrow", with a time, such as "3:45 PM", as
+    /// appropriate for the locale.
+    ///
+    /// Implements `ureldatefmt_combineDateAndTime`.
+    pub fn combine_date_and_time(
+        &self,
+        relative_date: &str,
+        time: &str,
+    ) -> Result<String, common::Error> {
+        let relative_date = ustring::UChar::try_from(relative_date)?;
+        let time = ustring::UChar::try_from(time)?;
+        let result = self.combine_date_and_time_ustring(&relative_date, &time)?;
+        String::try_from(&result)
+    }
+
+    /// Implements `ureldatefmt_combineDateAndTime`.
+    pub fn combine_date_and_time_ustring(
+        &self,
+        relative_date: &ustring::UChar,
+        time: &ustring::UChar,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 50;
+        buffered_uchar_method_with_retry!(
+            combine_impl,
+            CAPACITY,
+            [
+                rep: *const sys::URelativeDateTimeFormatter,
+                relative_date: *const sys::UChar,
+                relative_date_len: i32,
+                time: *const sys::UChar,
+                time_len: i32,
+            ],
+            []
+        );
+        combine_impl(
+            versioned_function!(ureldatefmt_combineDateAndTime),
+            self.rep.as_ptr(),
+            relative_date.as_c_ptr(),
+            relative_date.len() as i32,
+            time.as_c_ptr(),
+            time.len() as i32,
+        )
+    }
+}
+
+/// The result of formatting a relative date or time, with its fields.
+///
+/// The fields are in the `UFIELD_CATEGORY_RELATIVE_DATETIME` category, with the values of
+/// [sys::URelativeDateTimeFormatterField], and in the `UFIELD_CATEGORY_NUMBER` category for
+/// the parts of the number.
+#[derive(Debug)]
+pub struct UFormattedRelativeDateTime {
+    rep: ptr::NonNull<sys::UFormattedRelativeDateTime>,
+}
+
+// Implements `ureldatefmt_closeResult`.
+simple_drop_impl!(UFormattedRelativeDateTime, ureldatefmt_closeResult);
+
+impl UFormattedRelativeDateTime {
+    // Implements `ureldatefmt_openResult`.
+    fn try_new() -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ureldatefmt_openResult)(&mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UFormattedRelativeDateTime {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Returns the formatted string and its fields.
+    ///
+    /// Implements `ureldatefmt_resultAsValue`.
+    pub fn as_value(&self) -> Result<ufmtval::UFormattedValue<'_>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let value = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(ureldatefmt_resultAsValue)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: the value is owned by `self`, which it borrows.
+        Ok(unsafe { ufmtval::UFormattedValue::from_raw(value) })
+    }
+}
+
+impl TryFrom<&UFormattedRelativeDateTime> for ustring::UChar {
+    type Error = common::Error;
+
+    /// Implements `ureldatefmt_resultAsValue`.
+    fn try_from(result: &UFormattedRelativeDateTime) -> Result<ustring::UChar, common::Error> {
+        ustring::UChar::try_from(&result.as_value()?)
+    }
+}
+
+impl TryFrom<&UFormattedRelativeDateTime> for String {
+    type Error = common::Error;
+
+    /// Implements `ureldatefmt_resultAsValue`.
+    fn try_from(result: &UFormattedRelativeDateTime) -> Result<String, common::Error> {
+        String::try_from(&result.as_value()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sys::{
+        UDateRelativeDateTimeFormatterStyle as Style, UDisplayContext as Context,
+        URelativeDateTimeUnit as Unit,
+    };
+
+    fn formatter(locale: &str, width: Style) -> URelativeDateTimeFormatter {
+        URelativeDateTimeFormatter::try_new(locale, width, Context::UDISPCTX_CAPITALIZATION_NONE)
+            .expect("formatter")
+    }
+
+    #[test]
+    fn units_and_widths() {
+        let long = formatter("en-US", Style::UDAT_STYLE_LONG);
+        let short = formatter("en-US", Style::UDAT_STYLE_SHORT);
+        let narrow = formatter("en-US", Style::UDAT_STYLE_NARROW);
+        let tests = vec![
+            (Unit::UDAT_REL_UNIT_YEAR, 2.0, "in 2 years", "in 2 yr."),
+            (
+                Unit::UDAT_REL_UNIT_QUARTER,
+                -1.0,
+                "1 quarter ago",
+                "1 qtr. ago",
+            ),
+            (Unit::UDAT_REL_UNIT_MONTH, 3.0, "in 3 months", "in 3 mo."),
+            (Unit::UDAT_REL_UNIT_WEEK, -2.0, "2 weeks ago", "2 wk. ago"),
+            (Unit::UDAT_REL_UNIT_DAY, 1.0, "in 1 day", "in 1 day"),
+            (Unit::UDAT_REL_UNIT_HOUR, -3.0, "3 hours ago", "3 hr. ago"),
+            (Unit::UDAT_REL_UNIT_MINUTE, 5.0, "in 5 minutes", "in 5 min."),
+            (
+                Unit::UDAT_REL_UNIT_SECOND,
+                -10.0,
+                "10 seconds ago",
+                "10 sec. ago",
+            ),
+            (Unit::UDAT_REL_UNIT_MONDAY, 1.0, "in 1 Monday", "in 1 Mon."),
+        ];
+        for (unit, offset, expected_long, expected_short) in tests {
+            assert_eq!(expected_long, long.format_numeric(offset, unit).unwrap());
+            assert_eq!(expected_short, short.format_numeric(offset, unit).unwrap());
+            assert!(!narrow.format_numeric(offset, unit).unwrap().is_empty());
+        }
+        assert_eq!(
+            "in 1.5 hours",
+            long.format_numeric(1.5, Unit::UDAT_REL_UNIT_HOUR).unwrap()
+        );
+    }
+
+    #[test]
+    fn auto_style() {
+        let fmt = formatter("en-US", Style::UDAT_STYLE_LONG);
+        assert_eq!(
+            "tomorrow",
+            fmt.format(1.0, Unit::UDAT_REL_UNIT_DAY).unwrap()
+        );
+        assert_eq!("today", fmt.format(0.0, Unit::UDAT_REL_UNIT_DAY).unwrap());
+        assert_eq!(
+            "last week",
+            fmt.format(-1.0, Unit::UDAT_REL_UNIT_WEEK).unwrap()
+        );
+        assert_eq!(
+            "next Friday",
+            fmt.format(1.0, Unit::UDAT_REL_UNIT_FRIDAY).unwrap()
+        );
+        assert_eq!(
+            "in 3 days",
+            fmt.format(3.0, Unit::UDAT_REL_UNIT_DAY).unwrap()
+        );
+
+        let fmt = formatter("de-DE", Style::UDAT_STYLE_LONG);
+        assert_eq!(
+            "vorgestern",
+            fmt.format(-2.0, Unit::UDAT_REL_UNIT_DAY).unwrap()
+        );
+        assert_eq!(
+            "vor 3 Stunden",
+            fmt.format(-3.0, Unit::UDAT_REL_UNIT_HOUR).unwrap()
+        );
+
+        let fmt = formatter("ja-JP", Style::UDAT_STYLE_LONG);
+        assert_eq!("昨日", fmt.format(-1.0, Unit::UDAT_REL_UNIT_DAY).unwrap());
+    }
+
+    #[test]
+    fn capitalization() {
+        let fmt = URelativeDateTimeFormatter::try_new(
+            "en-US",
+            Style::UDAT_STYLE_LONG,
+            Context::UDISPCTX_CAPITALIZATION_FOR_BEGINNING_OF_SENTENCE,
+        )
+        .unwrap();
+        assert_eq!(
+            "Yesterday",
+            fmt.format(-1.0, Unit::UDAT_REL_UNIT_DAY).unwrap()
+        );
+
+        // Only capitalization contexts are accepted.
+        assert!(URelativeDateTimeFormatter::try_new(
+            "en-US",
+            Style::UDAT_STYLE_LONG,
+            Context::UDISPCTX_STANDARD_NAMES,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn combine_date_and_time() {
+        let fmt = formatter("en-US", Style::UDAT_STYLE_LONG);
+        let day = fmt.format(1.0, Unit::UDAT_REL_UNIT_DAY).unwrap();
+        assert_eq!(
+            "tomorrow, 3:45 PM",
+            fmt.combine_date_and_time(&day, "3:45 PM").unwrap()
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let fmt = formatter("en-US", Style::UDAT_STYLE_LONG);
+        let result = fmt
+            .format_numeric_to_result(-1234.0, Unit::UDAT_REL_UNIT_HOUR)
+            .unwrap();
+        assert_eq!("1,234 hours ago", String::try_from(&result).unwrap());
+        let value = result.as_value().unwrap();
+        let fields: Vec<_> = value
+            .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_RELATIVE_DATETIME)
+            .unwrap()
+            .into_iter()
+            .map(|f| (f.field, f.range))
+            .collect();
+        let literal = sys::URelativeDateTimeFormatterField::UDAT_REL_LITERAL_FIELD as i32;
+        let numeric = sys::URelativeDateTimeFormatterField::UDAT_REL_NUMERIC_FIELD as i32;
+        assert_eq!(vec![(numeric, 0..5), (literal, 6..15)], fields);
+        assert!(!value
+            .fields_in(sys::UFieldCategory::UFIELD_CATEGORY_NUMBER)
+            .unwrap()
+            .is_empty());
+
+        let result = fmt.format_to_result(-1.0, Unit::UDAT_REL_UNIT_DAY).unwrap();
+        let text = ustring::UChar::try_from(&result).unwrap();
+        assert_eq!("yesterday", String::try_from(&text).unwrap());
+        let fields = result.as_value().unwrap().fields().unwrap();
+        assert_eq!(1, fields.len());
+        assert_eq!(literal, fields[0].field);
+        assert_eq!(0..9, fields[0].range);
+    }
+}
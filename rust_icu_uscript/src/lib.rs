@@ -0,0 +1,363 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU Unicode script support for rust
+//!
+//! This crate finds the script that characters are written in, such as Latin or Cyrillic, and
+//! provides the properties of scripts, such as whether they are written right to left.  It is
+//! a wrapper around the ICU library, specifically the functionality exposed through its C API,
+//! as available in the [header
+//! `uscript.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/uscript_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_uscript as uscript;
+//!
+//! let script = uscript::Script::of('ש' as sys::UChar32).unwrap();
+//! assert_eq!(uscript::Script::from(sys::UScriptCode::USCRIPT_HEBREW), script);
+//! assert_eq!("Hebr", script.short_name());
+//! assert!(script.is_right_to_left());
+//!
+//! // The Japanese prolonged sound mark is used in both kana scripts.
+//! let extensions = uscript::Script::extensions_of('ー' as sys::UChar32).unwrap();
+//! assert_eq!(vec!["Hira", "Kana"], extensions.iter().map(|s| s.short_name()).collect::<Vec<_>>());
+//! ```
+
+use {
+    rust_icu_common as common, rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_uloc as uloc, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        ffi,
+    },
+};
+
+/// A Unicode script, such as Latin or Han.
+///
+/// Converts to and from the ICU script codes of [sys::UScriptCode].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Script {
+    // Always a valid sys::UScriptCode, see from_code.  The code is kept as an integer since
+    // ICU versions newer than the bindings return codes that the enum does not have.
+    rep: i32,
+}
+
+impl From<sys::UScriptCode> for Script {
+    fn from(code: sys::UScriptCode) -> Script {
+        Script { rep: code as i32 }
+    }
+}
+
+impl From<Script> for sys::UScriptCode {
+    fn from(script: Script) -> sys::UScriptCode {
+        script.code()
+    }
+}
+
+// Calls `fill` to write script codes into a buffer, and retries with a larger buffer if the
+// first one is too small.  ICU writes the codes as integers, which are checked by from_code.
+fn script_codes<F>(mut fill: F) -> Result<Vec<Script>, common::Error>
+where
+    F: FnMut(*mut i32, i32, *mut sys::UErrorCode) -> i32,
+{
+    const CAPACITY: usize = 4;
+    let mut codes: Vec<i32> = vec![0; CAPACITY];
+    let mut status = common::Error::OK_CODE;
+    let mut len = fill(codes.as_mut_ptr(), CAPACITY as i32, &mut status);
+    if status == sys::UErrorCode::U_BUFFER_OVERFLOW_ERROR {
+        codes.resize(len as usize, 0);
+        status = common::Error::OK_CODE;
+        len = fill(codes.as_mut_ptr(), len, &mut status);
+    }
+    common::Error::ok_or_warning(status)?;
+    codes.truncate(len as usize);
+    codes.into_iter().map(checked_code).collect()
+}
+
+impl Script {
+    /// Returns the script of the code point `c`, which is `USCRIPT_COMMON` for characters
+    /// used with many scripts, such as digits, and `USCRIPT_INHERITED` for characters that
+    /// take the script of the preceding character, such as combining marks.
+    ///
+    /// Returns `U_UNSUPPORTED_ERROR` if ICU returns a script that is newer than the bindings.
+    ///
+    /// Implements `uscript_getScript`.
+    pub fn of(c: sys::UChar32) -> Result<Script, common::Error> {
+        // Declares the result as an integer, so that it is checked before it becomes a
+        // sys::UScriptCode.
+        type GetScript = unsafe extern "C" fn(sys::UChar32, *mut sys::UErrorCode) -> i32;
+        let mut status = common::Error::OK_CODE;
+        let code = unsafe {
+            assert!(common::Error::is_ok(status));
+            let get_script: GetScript =
+                std::mem::transmute(versioned_function!(uscript_getScript) as *const ());
+            get_script(c, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        checked_code(code)
+    }
+
+    /// Returns the Script_Extensions of the code point `c`, which are the scripts that it is
+    /// used with.  For most code points this is just their script.  Returns
+    /// `U_UNSUPPORTED_ERROR` like [Script::of].
+    ///
+    /// Implements `uscript_getScriptExtensions`.
+    pub fn extensions_of(c: sys::UChar32) -> Result<Vec<Script>, common::Error> {
+        script_codes(|codes, capacity, status| unsafe {
+            versioned_function!(uscript_getScriptExtensions)(
+                c,
+                codes as *mut sys::UScriptCode,
+                capacity,
+                status,
+            )
+        })
+    }
+
+    /// Returns the script with the given long name, such as "Cyrillic", or the ISO 15924
+    /// code, such as "Cyrl".  Returns `None` if there is no such script.  Unlike
+    /// [Script::for_locale], this never looks up `name` as a locale.
+    ///
+    /// Implements `u_getPropertyValueEnum` for `UCHAR_SCRIPT`.
+    pub fn from_name(name: &str) -> Result<Option<Script>, common::Error> {
+        let name = ffi::CString::new(name)?;
+        let code = unsafe {
+            versioned_function!(u_getPropertyValueEnum)(sys::UProperty::UCHAR_SCRIPT, name.as_ptr())
+        };
+        Ok(from_code(code))
+    }
+
+    /// Returns the scripts that the language of `locale` is written in, such as Katakana,
+    /// Hiragana and Han for "ja".  Returns `U_UNSUPPORTED_ERROR` like [Script::of].
+    ///
+    /// Implements `uscript_getCode`.
+    pub fn for_locale(locale: &uloc::ULoc) -> Result<Vec<Script>, common::Error> {
+        let locale = locale.as_c_str();
+        script_codes(|codes, capacity, status| unsafe {
+            versioned_function!(uscript_getCode)(
+                locale.as_ptr(),
+                codes as *mut sys::UScriptCode,
+                capacity,
+                status,
+            )
+        })
+    }
+
+    /// Returns the long name of the script, such as "Cyrillic", or its ISO 15924 code if it
+    /// has no long name.
+    ///
+    /// Implements `uscript_getName`.
+    pub fn name(&self) -> String {
+        let name = unsafe { versioned_function!(uscript_getName)(self.code()) };
+        to_string(name)
+    }
+
+    /// Returns the ISO 15924 code of the script, such as "Cyrl".
+    ///
+    /// Implements `uscript_getShortName`.
+    pub fn short_name(&self) -> String {
+        let name = unsafe { versioned_function!(uscript_getShortName)(self.code()) };
+        to_string(name)
+    }
+
+    /// Returns true if the code point `c` is used with this script, according to its
+    /// Script_Extensions.
+    ///
+    /// Implements `uscript_hasScript`.
+    pub fn has(&self, c: sys::UChar32) -> bool {
+        unsafe { versioned_function!(uscript_hasScript)(c, self.code()) != 0 }
+    }
+
+    /// Returns a sample string of the script, usually a single character that is typical of
+    /// it, or an empty string if there is none.
+    ///
+    /// Implements `uscript_getSampleString`.
+    pub fn sample_string(&self) -> Result<String, common::Error> {
+        const CAPACITY: usize = 4;
+        buffered_uchar_method_with_retry!(
+            sample_string_impl,
+            CAPACITY,
+            [script: sys::UScriptCode,],
+            []
+        );
+        let result = sample_string_impl(versioned_function!(uscript_getSampleString), self.code())?;
+        String::try_from(&result)
+    }
+
+    /// Returns how the script is used, following UAX #31, for example
+    /// `USCRIPT_USAGE_RECOMMENDED` for scripts in modern customary use, and
+    /// `USCRIPT_USAGE_EXCLUDED` for historic scripts.
+    ///
+    /// Implements `uscript_getUsage`.
+    pub fn usage(&self) -> sys::UScriptUsage {
+        unsafe { versioned_function!(uscript_getUsage)(self.code()) }
+    }
+
+    /// Returns true if the script is written right to left, such as Arabic and Hebrew.
+    ///
+    /// Implements `uscript_isRightToLeft`.
+    pub fn is_right_to_left(&self) -> bool {
+        unsafe { versioned_function!(uscript_isRightToLeft)(self.code()) != 0 }
+    }
+
+    /// Returns true if lines of the script may break between any two letters, as in Chinese
+    /// and Thai, rather than only between words.
+    ///
+    /// Implements `uscript_breaksBetweenLetters`.
+    pub fn breaks_between_letters(&self) -> bool {
+        unsafe { versioned_function!(uscript_breaksBetweenLetters)(self.code()) != 0 }
+    }
+
+    /// Returns true if the script customarily distinguishes upper and lower case, as Latin
+    /// and Greek do.
+    ///
+    /// Implements `uscript_isCased`.
+    pub fn is_cased(&self) -> bool {
+        unsafe { versioned_function!(uscript_isCased)(self.code()) != 0 }
+    }
+
+    fn code(&self) -> sys::UScriptCode {
+        // Unsafety note: rep is always a valid script code, see from_code.
+        unsafe { std::mem::transmute::<i32, sys::UScriptCode>(self.rep) }
+    }
+}
+
+// Converts a script code that ICU returned as an integer, or `None` for codes that are not
+// script codes, such as `UCHAR_INVALID_CODE`.
+fn from_code(code: i32) -> Option<Script> {
+    // The script codes are contiguous from 0 to USCRIPT_CODE_LIMIT.
+    if !(0..sys::UScriptCode::USCRIPT_CODE_LIMIT as i32).contains(&code) {
+        return None;
+    }
+    Some(Script { rep: code })
+}
+
+// Converts a script code that ICU returned for a code point or a locale, which is only missing
+// from sys::UScriptCode if ICU is newer than the bindings.
+fn checked_code(code: i32) -> Result<Script, common::Error> {
+    from_code(code).ok_or(common::Error::Sys(sys::UErrorCode::U_UNSUPPORTED_ERROR))
+}
+
+// Copies a script name that ICU returned as a static C string.
+fn to_string(name: *const std::os::raw::c_char) -> String {
+    if name.is_null() {
+        return String::new();
+    }
+    // Unsafety note: ICU returns a valid C string of static data.
+    unsafe { ffi::CStr::from_ptr(name) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sys::UScriptCode::*;
+
+    fn script_of(c: char) -> sys::UScriptCode {
+        Script::of(c as sys::UChar32).unwrap().into()
+    }
+
+    #[test]
+    fn script_of_code_points() {
+        assert_eq!(USCRIPT_LATIN, script_of('a'));
+        assert_eq!(USCRIPT_CYRILLIC, script_of('ж'));
+        assert_eq!(USCRIPT_HAN, script_of('字'));
+        assert_eq!(USCRIPT_COMMON, script_of('1'));
+        assert_eq!(USCRIPT_INHERITED, script_of('\u{301}'));
+    }
+
+    #[test]
+    fn script_extensions() {
+        let extensions: Vec<sys::UScriptCode> = Script::extensions_of('a' as sys::UChar32)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.into())
+            .collect();
+        assert_eq!(vec![USCRIPT_LATIN], extensions);
+
+        // The Arabic tatweel is used with many scripts, more than fit in the initial buffer.
+        let extensions = Script::extensions_of('\u{640}' as sys::UChar32).unwrap();
+        assert!(extensions.len() > 4);
+        assert!(extensions.contains(&Script::from(USCRIPT_ARABIC)));
+        assert!(extensions.contains(&Script::from(USCRIPT_SYRIAC)));
+        assert!(Script::from(USCRIPT_SYRIAC).has(0x640));
+        assert!(!Script::from(USCRIPT_LATIN).has(0x640));
+    }
+
+    #[test]
+    fn names_and_codes() {
+        let cyrillic = Script::from(USCRIPT_CYRILLIC);
+        assert_eq!("Cyrillic", cyrillic.name());
+        assert_eq!("Cyrl", cyrillic.short_name());
+        assert_eq!(Some(cyrillic), Script::from_name("Cyrillic").unwrap());
+        assert_eq!(Some(cyrillic), Script::from_name("Cyrl").unwrap());
+        assert_eq!(None, Script::from_name("Nonexistent").unwrap());
+        assert_eq!(None, Script::from_name("sr").unwrap());
+        // Names are not looked up as locales.
+        assert_eq!(None, Script::from_name("sr_RS").unwrap());
+
+        let for_locale = |locale: &str| -> Vec<sys::UScriptCode> {
+            let locale = uloc::ULoc::try_from(locale).unwrap();
+            Script::for_locale(&locale)
+                .unwrap()
+                .into_iter()
+                .map(|s| s.into())
+                .collect()
+        };
+        assert_eq!(
+            vec![USCRIPT_KATAKANA, USCRIPT_HIRAGANA, USCRIPT_HAN],
+            for_locale("ja")
+        );
+        assert_eq!(vec![USCRIPT_CYRILLIC], for_locale("sr"));
+        assert_eq!(vec![USCRIPT_LATIN], for_locale("sr_Latn_RS"));
+    }
+
+    #[test]
+    fn properties() {
+        let latin = Script::from(USCRIPT_LATIN);
+        let arabic = Script::from(USCRIPT_ARABIC);
+        let thai = Script::from(USCRIPT_THAI);
+        let gothic = Script::from(USCRIPT_GOTHIC);
+
+        assert_eq!(sys::UScriptUsage::USCRIPT_USAGE_RECOMMENDED, latin.usage());
+        assert_eq!(sys::UScriptUsage::USCRIPT_USAGE_EXCLUDED, gothic.usage());
+        assert!(!latin.is_right_to_left());
+        assert!(arabic.is_right_to_left());
+        assert!(!latin.breaks_between_letters());
+        assert!(thai.breaks_between_letters());
+        assert!(latin.is_cased());
+        assert!(!arabic.is_cased());
+        assert_eq!("ท", thai.sample_string().unwrap());
+    }
+
+    // Ol Onal is new in Unicode 16, and its script code is beyond the ones of earlier ICU
+    // versions.
+    #[cfg(feature = "icu_version_76_plus")]
+    #[test]
+    fn unicode_16_script() {
+        let ol_onal = Script::of(0x1E5D0).unwrap();
+        assert_eq!("Onao", ol_onal.short_name());
+        assert_eq!(Some(ol_onal), Script::from_name("Ol_Onal").unwrap());
+        assert_eq!(vec![ol_onal], Script::extensions_of(0x1E5D0).unwrap());
+        assert!(ol_onal.has(0x1E5D0));
+    }
+}
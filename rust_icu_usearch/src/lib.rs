@@ -0,0 +1,465 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU collation-aware string search for rust
+//!
+//! This crate finds a pattern in a text using the rules of a collator, so that for example
+//! "resume" can match "résumé" when accents are ignored.  It is a wrapper around the ICU
+//! library, specifically the functionality exposed through its C API, as available in the
+//! [header `usearch.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/usearch_8h.html).
+//!
+//! A [StringSearch] is an iterator over the matches of the pattern.  Each [Match] reports its
+//! range in the text both in UTF-16 code units, as used by ICU, and in UTF-8 bytes, for
+//! slicing a rust `&str`.
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_ucol as ucol;
+//! use rust_icu_usearch as usearch;
+//! use std::convert::TryFrom;
+//!
+//! let mut collator = ucol::UCollator::try_from("fr").expect("collator");
+//! collator.set_strength(sys::UCollationStrength::UCOL_PRIMARY);
+//!
+//! let text = "Résumé, RESUME, résume";
+//! let search = usearch::StringSearch::try_from_collator("resume", text, &collator)
+//!     .expect("search");
+//! let found: Vec<&str> = search.map(|m| &text[m.expect("match").utf8]).collect();
+//! assert_eq!(vec!["Résumé", "RESUME", "résume"], found);
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::simple_drop_impl,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ucol as ucol, rust_icu_ustring as ustring,
+    std::{
+        convert::{TryFrom, TryInto},
+        ffi,
+        marker::PhantomData,
+        ops, ptr,
+    },
+};
+
+/// A match of the pattern in the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The range of the match, in UTF-16 code units.
+    pub utf16: ops::Range<usize>,
+    /// The range of the match, in UTF-8 bytes.
+    pub utf8: ops::Range<usize>,
+}
+
+/// Searches a text for a pattern, using the rules of a collator.
+///
+/// The search has a current position in the text, which the search methods move forwards or
+/// backwards.  Iterating over the search returns the matches after the current position.
+#[derive(Debug)]
+pub struct StringSearch<'a> {
+    rep: ptr::NonNull<sys::UStringSearch>,
+    // ICU does not copy the pattern or the text, so they are kept here.
+    _pattern: ustring::UChar,
+    _text: ustring::UChar,
+    // The UTF-8 offset of each UTF-16 offset in the text, including the end of the text.
+    utf8_offsets: Vec<usize>,
+    // The collator, if supplied by the caller, must outlive the search.
+    _collator: PhantomData<&'a ucol::UCollator>,
+}
+
+// Implements `usearch_close`
+simple_drop_impl!(StringSearch<'_>, usearch_close);
+
+impl StringSearch<'static> {
+    /// Creates a search for `pattern` in `text`, using the collation rules of `locale`.
+    ///
+    /// Implements `usearch_open`
+    pub fn try_new(pattern: &str, text: &str, locale: &str) -> Result<Self, common::Error> {
+        let pattern = ustring::UChar::try_from(pattern)?;
+        let text = ustring::UChar::try_from(text)?;
+        Self::try_new_ustring(&pattern, &text, locale)
+    }
+
+    /// Implements `usearch_open`
+    pub fn try_new_ustring(
+        pattern: &ustring::UChar,
+        text: &ustring::UChar,
+        locale: &str,
+    ) -> Result<Self, common::Error> {
+        let locale = ffi::CString::new(locale)?;
+        let pattern = pattern.clone();
+        let text = text.clone();
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(usearch_open)(
+                pattern.as_c_ptr(),
+                pattern.len() as i32,
+                text.as_c_ptr(),
+                text.len() as i32,
+                locale.as_ptr(),
+                ptr::null_mut(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(StringSearch::from_raw_parts(rep, pattern, text))
+    }
+}
+
+impl<'a> StringSearch<'a> {
+    /// Creates a search for `pattern` in `text`, using the rules of `collator`, including its
+    /// strength and other attributes.
+    ///
+    /// Implements `usearch_openFromCollator`
+    pub fn try_from_collator(
+        pattern: &str,
+        text: &str,
+        collator: &'a ucol::UCollator,
+    ) -> Result<Self, common::Error> {
+        let pattern = ustring::UChar::try_from(pattern)?;
+        let text = ustring::UChar::try_from(text)?;
+        Self::try_from_collator_ustring(&pattern, &text, collator)
+    }
+
+    /// Implements `usearch_openFromCollator`
+    pub fn try_from_collator_ustring(
+        pattern: &ustring::UChar,
+        text: &ustring::UChar,
+        collator: &'a ucol::UCollator,
+    ) -> Result<Self, common::Error> {
+        let pattern = pattern.clone();
+        let text = text.clone();
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(usearch_openFromCollator)(
+                pattern.as_c_ptr(),
+                pattern.len() as i32,
+                text.as_c_ptr(),
+                text.len() as i32,
+                collator.as_ptr(),
+                ptr::null_mut(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(StringSearch::from_raw_parts(rep, pattern, text))
+    }
+
+    fn from_raw_parts(
+        rep: *mut sys::UStringSearch,
+        pattern: ustring::UChar,
+        text: ustring::UChar,
+    ) -> Self {
+        // Unsafety note: `text` is a valid UTF-16 buffer of `text.len()` code units.
+        let units = unsafe { std::slice::from_raw_parts(text.as_c_ptr(), text.len()) };
+        let mut utf8_offsets = Vec::with_capacity(units.len() + 1);
+        let mut offset = 0;
+        for c in std::char::decode_utf16(units.iter().copied()) {
+            // Unpaired surrogates become U+FFFD when converting to UTF-8.
+            let c = c.unwrap_or(std::char::REPLACEMENT_CHARACTER);
+            utf8_offsets.push(offset);
+            if c.len_utf16() == 2 {
+                // There is no UTF-8 offset in the middle of a character, so map the trailing
+                // surrogate to the end of the character.
+                utf8_offsets.push(offset + c.len_utf8());
+            }
+            offset += c.len_utf8();
+        }
+        utf8_offsets.push(offset);
+        StringSearch {
+            rep: ptr::NonNull::new(rep).unwrap(),
+            _pattern: pattern,
+            _text: text,
+            utf8_offsets,
+            _collator: PhantomData,
+        }
+    }
+
+    /// Sets a search attribute, such as whether matches may overlap.
+    ///
+    /// Implements `usearch_setAttribute`
+    pub fn set_attribute(
+        &mut self,
+        attribute: sys::USearchAttribute,
+        value: sys::USearchAttributeValue,
+    ) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(usearch_setAttribute)(
+                self.rep.as_ptr(),
+                attribute,
+                value,
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Implements `usearch_getAttribute`
+    pub fn get_attribute(&self, attribute: sys::USearchAttribute) -> sys::USearchAttributeValue {
+        unsafe { versioned_function!(usearch_getAttribute)(self.rep.as_ptr(), attribute) }
+    }
+
+    /// Sets the current position in the text, in UTF-16 code units.
+    ///
+    /// Implements `usearch_setOffset`
+    pub fn set_offset(&mut self, position: usize) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(usearch_setOffset)(self.rep.as_ptr(), position as i32, &mut status);
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Returns the current position in the text, in UTF-16 code units.
+    ///
+    /// Implements `usearch_getOffset`
+    pub fn offset(&self) -> usize {
+        unsafe { versioned_function!(usearch_getOffset)(self.rep.as_ptr()) as usize }
+    }
+
+    /// Resets the search to the start of the text, and the attributes to their defaults.
+    ///
+    /// Implements `usearch_reset`
+    pub fn reset(&mut self) {
+        unsafe { versioned_function!(usearch_reset)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the first match in the text.
+    ///
+    /// Implements `usearch_first`
+    pub fn first_match(&mut self) -> Result<Option<Match>, common::Error> {
+        self.search(|rep, status| unsafe { versioned_function!(usearch_first)(rep, status) })
+    }
+
+    /// Returns the last match in the text.
+    ///
+    /// Implements `usearch_last`
+    pub fn last_match(&mut self) -> Result<Option<Match>, common::Error> {
+        self.search(|rep, status| unsafe { versioned_function!(usearch_last)(rep, status) })
+    }
+
+    /// Returns the first match that starts at or after `position`, in UTF-16 code units.
+    ///
+    /// Implements `usearch_following`
+    pub fn following(&mut self, position: usize) -> Result<Option<Match>, common::Error> {
+        self.search(|rep, status| unsafe {
+            versioned_function!(usearch_following)(rep, position as i32, status)
+        })
+    }
+
+    /// Returns the last match that ends at or before `position`, in UTF-16 code units.
+    ///
+    /// Implements `usearch_preceding`
+    pub fn preceding(&mut self, position: usize) -> Result<Option<Match>, common::Error> {
+        self.search(|rep, status| unsafe {
+            versioned_function!(usearch_preceding)(rep, position as i32, status)
+        })
+    }
+
+    /// Returns the next match after the current position.  This is also what iterating over
+    /// the search does.
+    ///
+    /// Implements `usearch_next`
+    pub fn next_match(&mut self) -> Result<Option<Match>, common::Error> {
+        self.search(|rep, status| unsafe { versioned_function!(usearch_next)(rep, status) })
+    }
+
+    /// Returns the previous match before the current position.
+    ///
+    /// Implements `usearch_previous`
+    pub fn previous_match(&mut self) -> Result<Option<Match>, common::Error> {
+        self.search(|rep, status| unsafe { versioned_function!(usearch_previous)(rep, status) })
+    }
+
+    // Calls one of the search functions, and returns the resulting match, if any.
+    //
+    // Implements `usearch_getMatchedLength`
+    fn search<F>(&mut self, method: F) -> Result<Option<Match>, common::Error>
+    where
+        F: FnOnce(*mut sys::UStringSearch, *mut sys::UErrorCode) -> i32,
+    {
+        let mut status = common::Error::OK_CODE;
+        let start = method(self.rep.as_ptr(), &mut status);
+        common::Error::ok_or_warning(status)?;
+        // USEARCH_DONE is -1.
+        let start: usize = match start.try_into() {
+            Ok(start) => start,
+            Err(_) => return Ok(None),
+        };
+        let length =
+            unsafe { versioned_function!(usearch_getMatchedLength)(self.rep.as_ptr()) } as usize;
+        let end = start + length;
+        Ok(Some(Match {
+            utf16: start..end,
+            utf8: self.utf8_offsets[start]..self.utf8_offsets[end],
+        }))
+    }
+}
+
+impl Iterator for StringSearch<'_> {
+    type Item = Result<Match, common::Error>;
+
+    /// Implements `usearch_next`
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_match().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf8_ranges(search: StringSearch) -> Vec<ops::Range<usize>> {
+        search.map(|m| m.expect("match").utf8).collect()
+    }
+
+    #[test]
+    fn locale_search() {
+        // Locale-based searches use tertiary strength, so accents and case are significant.
+        let search = StringSearch::try_new("abc", "ABC abc äbc", "en").expect("search");
+        assert_eq!(vec![4..7], utf8_ranges(search));
+
+        let search = StringSearch::try_new("Straße", "die STRASSE, die Strasse, die Straße", "de")
+            .expect("search");
+        assert_eq!(vec![30..37], utf8_ranges(search));
+    }
+
+    #[test]
+    fn collator_strength() {
+        let mut collator = ucol::UCollator::try_from("en").expect("collator");
+        let text = "résumé resume RESUME";
+        let search = StringSearch::try_from_collator("resume", text, &collator).expect("search");
+        assert_eq!(vec![9..15], utf8_ranges(search));
+
+        collator.set_strength(sys::UCollationStrength::UCOL_SECONDARY);
+        let search = StringSearch::try_from_collator("resume", text, &collator).expect("search");
+        assert_eq!(vec![9..15, 16..22], utf8_ranges(search));
+
+        collator.set_strength(sys::UCollationStrength::UCOL_PRIMARY);
+        let search = StringSearch::try_from_collator("resume", text, &collator).expect("search");
+        assert_eq!(vec![0..8, 9..15, 16..22], utf8_ranges(search));
+    }
+
+    #[test]
+    fn utf16_and_utf8_offsets() {
+        // The emoji takes two UTF-16 code units, and four UTF-8 bytes.
+        let mut search = StringSearch::try_new("ab", "😀ab é ab", "en").expect("search");
+        assert_eq!(
+            Some(Match {
+                utf16: 2..4,
+                utf8: 4..6
+            }),
+            search.first_match().unwrap()
+        );
+        assert_eq!(
+            Some(Match {
+                utf16: 7..9,
+                utf8: 10..12
+            }),
+            search.next_match().unwrap()
+        );
+        assert_eq!(None, search.next_match().unwrap());
+
+        let pattern = ustring::UChar::try_from("😀").unwrap();
+        let text = ustring::UChar::try_from("a😀b").unwrap();
+        let mut search = StringSearch::try_new_ustring(&pattern, &text, "en").expect("search");
+        assert_eq!(
+            Some(Match {
+                utf16: 1..3,
+                utf8: 1..5
+            }),
+            search.first_match().unwrap()
+        );
+    }
+
+    #[test]
+    fn navigation() {
+        let mut search = StringSearch::try_new("a", "a-a-a-a", "en").expect("search");
+        assert_eq!(Some(6..7), search.last_match().unwrap().map(|m| m.utf16));
+        assert_eq!(
+            Some(4..5),
+            search.previous_match().unwrap().map(|m| m.utf16)
+        );
+        assert_eq!(Some(2..3), search.following(1).unwrap().map(|m| m.utf16));
+        assert_eq!(Some(4..5), search.next_match().unwrap().map(|m| m.utf16));
+        assert_eq!(Some(2..3), search.preceding(4).unwrap().map(|m| m.utf16));
+
+        search.set_offset(3).unwrap();
+        assert_eq!(3, search.offset());
+        assert_eq!(
+            vec![4..5, 6..7],
+            search.map(|m| m.unwrap().utf16).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        let mut search = StringSearch::try_new("aa", "aaaa", "en").expect("search");
+        assert_eq!(
+            sys::USearchAttributeValue::USEARCH_OFF,
+            search.get_attribute(sys::USearchAttribute::USEARCH_OVERLAP)
+        );
+        assert_eq!(2, search.by_ref().count());
+
+        search
+            .set_attribute(
+                sys::USearchAttribute::USEARCH_OVERLAP,
+                sys::USearchAttributeValue::USEARCH_ON,
+            )
+            .unwrap();
+        assert_eq!(
+            sys::USearchAttributeValue::USEARCH_ON,
+            search.get_attribute(sys::USearchAttribute::USEARCH_OVERLAP)
+        );
+        search.set_offset(0).unwrap();
+        assert_eq!(3, search.by_ref().count());
+
+        search.reset();
+        assert_eq!(
+            sys::USearchAttributeValue::USEARCH_OFF,
+            search.get_attribute(sys::USearchAttribute::USEARCH_OVERLAP)
+        );
+    }
+
+    #[test]
+    fn element_comparison() {
+        let mut collator = ucol::UCollator::try_from("en").expect("collator");
+        collator.set_strength(sys::UCollationStrength::UCOL_SECONDARY);
+        let text = "cafe café";
+        // With the default comparison, an unaccented pattern only matches unaccented text.
+        let search = StringSearch::try_from_collator("cafe", text, &collator).expect("search");
+        assert_eq!(vec![0..4], utf8_ranges(search));
+
+        // Treating unaccented pattern characters as wildcards for accents matches both.
+        let mut search = StringSearch::try_from_collator("cafe", text, &collator).expect("search");
+        search
+            .set_attribute(
+                sys::USearchAttribute::USEARCH_ELEMENT_COMPARISON,
+                sys::USearchAttributeValue::USEARCH_PATTERN_BASE_WEIGHT_IS_WILDCARD,
+            )
+            .unwrap();
+        assert_eq!(vec![0..4, 5..10], utf8_ranges(search));
+    }
+}
@@ -0,0 +1,921 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU Unicode set support for rust
+//!
+//! This crate provides sets of Unicode code points and strings, as implemented by the ICU library.
+//! Specifically the functionality exposed through its C API, as available in the [header
+//! `uset.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/uset_8h.html).
+//!
+//! The main type is [USet], which is most commonly created from a pattern such as `[:Lu:]` or
+//! `[a-z\p{Greek}]` using `USet::try_from`.  A detailed discussion of the pattern syntax is
+//! available in the [ICU user guide](https://unicode-org.github.io/icu/userguide/strings/unicodeset.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_uset as uset;
+//! use std::convert::TryFrom;
+//!
+//! let mut set = uset::USet::try_from("[a-z\\p{Greek}]").expect("set");
+//! assert!(set.contains('q' as sys::UChar32));
+//! assert!(set.contains('λ' as sys::UChar32));
+//! assert!(!set.contains('Q' as sys::UChar32));
+//!
+//! set.remove_range('a' as sys::UChar32, 'x' as sys::UChar32);
+//! set.add_string("ch");
+//! let first = set.ranges().next().unwrap().unwrap();
+//! assert_eq!('y' as sys::UChar32..='z' as sys::UChar32, first);
+//! assert_eq!(vec!["ch".to_string()], set.strings().collect::<Result<Vec<_>, _>>().unwrap());
+//!
+//! let span = set.span_utf8("zyλ!", sys::USetSpanCondition::USET_SPAN_CONTAINED);
+//! assert_eq!(4, span);
+//! ```
+//!
+//! A set can be frozen, after which it is immutable and can be shared between threads.
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_uset as uset;
+//! use std::convert::TryFrom;
+//!
+//! let frozen = uset::USet::try_from("[:Lu:]").expect("set").freeze();
+//! let handle = std::thread::spawn(move || frozen.contains('A' as sys::UChar32));
+//! assert!(handle.join().unwrap());
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::{flag_set, simple_drop_impl},
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    std::{convert::TryFrom, ops, os::raw, ptr},
+};
+
+flag_set! {
+    /// Options for creating a set from a pattern, from the `USET_*` constants of `uset.h`.  At
+    /// most one of the case options applies.
+    PatternOptions {
+        /// Ignore white space within patterns unless quoted or escaped.
+        IGNORE_SPACE = 1,
+        /// Enable case insensitive matching, by applying a full case closure to the set, so
+        /// that it contains "A" if it contains "a".
+        CASE_INSENSITIVE = 2,
+        /// Add all case mappings of each element of the set.
+        ADD_CASE_MAPPINGS = 4,
+    }
+}
+
+/// A mutable set of Unicode code points and strings.
+///
+/// Code points are represented as [sys::UChar32], since a set may contain surrogate code
+/// points, which are not representable as a rust `char`.
+#[derive(Debug)]
+pub struct USet {
+    rep: ptr::NonNull<sys::USet>,
+}
+
+// Implements `uset_close`
+simple_drop_impl!(USet, uset_close);
+
+// Safety: a USet has no affinity to the thread that created it.  It is not `Sync` because
+// unfrozen sets lazily build internal caches, even through `const` methods.
+unsafe impl Send for USet {}
+
+impl TryFrom<&str> for USet {
+    type Error = common::Error;
+    /// Creates a set from the supplied pattern, e.g. `[:Lu:]` or `[a-z\p{Greek}]`.
+    ///
+    /// Implements `uset_openPattern`
+    fn try_from(pattern: &str) -> Result<USet, Self::Error> {
+        let pattern = ustring::UChar::try_from(pattern)?;
+        USet::try_from_pattern(&pattern)
+    }
+}
+
+impl Clone for USet {
+    /// Makes an independent, mutable copy of this set.
+    ///
+    /// Implements `uset_cloneAsThawed`
+    fn clone(&self) -> Self {
+        let rep = unsafe { versioned_function!(uset_cloneAsThawed)(self.rep.as_ptr()) };
+        USet::from_raw(rep)
+    }
+}
+
+impl PartialEq for USet {
+    /// Implements `uset_equals`
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { versioned_function!(uset_equals)(self.rep.as_ptr(), other.rep.as_ptr()) != 0 }
+    }
+}
+
+impl USet {
+    fn from_raw(rep: *mut sys::USet) -> USet {
+        USet {
+            rep: ptr::NonNull::new(rep).expect("ICU returned a null set"),
+        }
+    }
+
+    /// Creates an empty set.
+    ///
+    /// Implements `uset_openEmpty`
+    pub fn new_empty() -> USet {
+        let rep = unsafe { versioned_function!(uset_openEmpty)() };
+        USet::from_raw(rep)
+    }
+
+    /// Creates a set containing all code points in the range `start..=end`.
+    ///
+    /// Implements `uset_open`
+    pub fn new_range(start: sys::UChar32, end: sys::UChar32) -> USet {
+        let rep = unsafe { versioned_function!(uset_open)(start, end) };
+        USet::from_raw(rep)
+    }
+
+    /// Creates a set from the supplied pattern.
+    ///
+    /// Implements `uset_openPattern`
+    pub fn try_from_pattern(pattern: &ustring::UChar) -> Result<USet, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_openPattern)(
+                pattern.as_c_ptr(),
+                pattern.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(USet::from_raw(rep))
+    }
+
+    /// Creates a set from the supplied pattern, modified by `options`.
+    ///
+    /// Implements `uset_openPatternOptions`
+    pub fn try_from_pattern_with_options(
+        pattern: &str,
+        options: PatternOptions,
+    ) -> Result<USet, common::Error> {
+        let pattern = ustring::UChar::try_from(pattern)?;
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_openPatternOptions)(
+                pattern.as_c_ptr(),
+                pattern.len() as i32,
+                options.bits(),
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(USet::from_raw(rep))
+    }
+
+    /// Recreates a set from the output of [USet::serialize].
+    ///
+    /// Implements `uset_getSerializedSet`
+    /// Implements `uset_getSerializedRangeCount`
+    /// Implements `uset_getSerializedRange`
+    pub fn try_from_serialized(serialized: &[u16]) -> Result<USet, common::Error> {
+        let mut fill_set = sys::USerializedSet::default();
+        let valid = unsafe {
+            versioned_function!(uset_getSerializedSet)(
+                &mut fill_set,
+                serialized.as_ptr(),
+                serialized.len() as i32,
+            ) != 0
+        };
+        if !valid {
+            return Err(common::Error::Sys(sys::UErrorCode::U_INVALID_FORMAT_ERROR));
+        }
+        let mut set = USet::new_empty();
+        let count = unsafe { versioned_function!(uset_getSerializedRangeCount)(&fill_set) };
+        for index in 0..count {
+            let mut start: sys::UChar32 = 0;
+            let mut end: sys::UChar32 = 0;
+            unsafe {
+                versioned_function!(uset_getSerializedRange)(&fill_set, index, &mut start, &mut end)
+            };
+            set.add_range(start, end);
+        }
+        Ok(set)
+    }
+
+    /// Returns the serialized form of the code points in this set.  Strings in the set are not
+    /// serialized.
+    ///
+    /// Implements `uset_serialize`
+    pub fn serialize(&self) -> Result<Vec<u16>, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let len = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_serialize)(self.rep.as_ptr(), ptr::null_mut(), 0, &mut status)
+        };
+        common::Error::ok_preflight(status)?;
+        let mut result: Vec<u16> = vec![0; len as usize];
+        status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_serialize)(
+                self.rep.as_ptr(),
+                result.as_mut_ptr(),
+                result.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(result)
+    }
+
+    /// Returns a pattern string which describes this set.  If `escape_unprintable` is set,
+    /// unprintable characters are written as `\uxxxx` escapes.
+    ///
+    /// Implements `uset_toPattern`
+    pub fn to_pattern(&self, escape_unprintable: bool) -> Result<String, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let len = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_toPattern)(
+                self.rep.as_ptr(),
+                ptr::null_mut(),
+                0,
+                escape_unprintable as sys::UBool,
+                &mut status,
+            )
+        };
+        common::Error::ok_preflight(status)?;
+        let mut result = ustring::UChar::new_with_capacity(len as usize);
+        status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_toPattern)(
+                self.rep.as_ptr(),
+                result.as_mut_c_ptr(),
+                result.len() as i32,
+                escape_unprintable as sys::UBool,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        String::try_from(&result)
+    }
+
+    /// Returns the underlying ICU set, for use with other ICU APIs that accept a `USet`.
+    pub fn as_ptr(&self) -> *const sys::USet {
+        self.rep.as_ptr()
+    }
+
+    /// Returns the underlying ICU set, for use with other ICU APIs that fill in a `USet`.
+    pub fn as_mut_ptr(&mut self) -> *mut sys::USet {
+        self.rep.as_ptr()
+    }
+
+    /// Makes an independent, mutable copy of a set that is owned elsewhere, such as a set
+    /// returned by another ICU API.
+    ///
+    /// # Safety
+    ///
+    /// `rep` must point to a valid ICU set.
+    ///
+    /// Implements `uset_cloneAsThawed`
+    pub unsafe fn clone_from_raw(rep: *const sys::USet) -> USet {
+        USet::from_raw(versioned_function!(uset_cloneAsThawed)(rep))
+    }
+
+    /// Adds the code point `c` to this set.
+    ///
+    /// Implements `uset_add`
+    pub fn add(&mut self, c: sys::UChar32) {
+        unsafe { versioned_function!(uset_add)(self.rep.as_ptr(), c) };
+    }
+
+    /// Adds the code points in the range `start..=end` to this set.
+    ///
+    /// Implements `uset_addRange`
+    pub fn add_range(&mut self, start: sys::UChar32, end: sys::UChar32) {
+        unsafe { versioned_function!(uset_addRange)(self.rep.as_ptr(), start, end) };
+    }
+
+    /// Adds the string `s` to this set as a single element.
+    ///
+    /// Implements `uset_addString`
+    pub fn add_string(&mut self, s: &str) {
+        let s = ustring::UChar::from(s.encode_utf16().collect::<Vec<sys::UChar>>());
+        self.add_ustring(&s);
+    }
+
+    /// Adds the string `s` to this set as a single element.
+    ///
+    /// Implements `uset_addString`
+    pub fn add_ustring(&mut self, s: &ustring::UChar) {
+        unsafe {
+            versioned_function!(uset_addString)(self.rep.as_ptr(), s.as_c_ptr(), s.len() as i32)
+        };
+    }
+
+    /// Adds all elements of `other` to this set, making this set the union of the two.
+    ///
+    /// Implements `uset_addAll`
+    pub fn add_all(&mut self, other: &USet) {
+        unsafe { versioned_function!(uset_addAll)(self.rep.as_ptr(), other.rep.as_ptr()) };
+    }
+
+    /// Removes the code point `c` from this set.
+    ///
+    /// Implements `uset_remove`
+    pub fn remove(&mut self, c: sys::UChar32) {
+        unsafe { versioned_function!(uset_remove)(self.rep.as_ptr(), c) };
+    }
+
+    /// Removes the code points in the range `start..=end` from this set.
+    ///
+    /// Implements `uset_removeRange`
+    pub fn remove_range(&mut self, start: sys::UChar32, end: sys::UChar32) {
+        unsafe { versioned_function!(uset_removeRange)(self.rep.as_ptr(), start, end) };
+    }
+
+    /// Removes the string element `s` from this set.
+    ///
+    /// Implements `uset_removeString`
+    pub fn remove_string(&mut self, s: &str) {
+        let s = ustring::UChar::from(s.encode_utf16().collect::<Vec<sys::UChar>>());
+        self.remove_ustring(&s);
+    }
+
+    /// Removes the string element `s` from this set.
+    ///
+    /// Implements `uset_removeString`
+    pub fn remove_ustring(&mut self, s: &ustring::UChar) {
+        unsafe {
+            versioned_function!(uset_removeString)(self.rep.as_ptr(), s.as_c_ptr(), s.len() as i32)
+        };
+    }
+
+    /// Removes all elements of `other` from this set.
+    ///
+    /// Implements `uset_removeAll`
+    pub fn remove_all(&mut self, other: &USet) {
+        unsafe { versioned_function!(uset_removeAll)(self.rep.as_ptr(), other.rep.as_ptr()) };
+    }
+
+    /// Retains only the code points in the range `start..=end`.
+    ///
+    /// Implements `uset_retain`
+    pub fn retain_range(&mut self, start: sys::UChar32, end: sys::UChar32) {
+        unsafe { versioned_function!(uset_retain)(self.rep.as_ptr(), start, end) };
+    }
+
+    /// Retains only the elements that are also in `other`, making this set the intersection of
+    /// the two.
+    ///
+    /// Implements `uset_retainAll`
+    pub fn retain_all(&mut self, other: &USet) {
+        unsafe { versioned_function!(uset_retainAll)(self.rep.as_ptr(), other.rep.as_ptr()) };
+    }
+
+    /// Inverts the code points in this set.  String elements are removed.
+    ///
+    /// Implements `uset_complement`
+    pub fn complement(&mut self) {
+        unsafe { versioned_function!(uset_complement)(self.rep.as_ptr()) };
+    }
+
+    /// Removes all elements from this set.
+    ///
+    /// Implements `uset_clear`
+    pub fn clear(&mut self) {
+        unsafe { versioned_function!(uset_clear)(self.rep.as_ptr()) };
+    }
+
+    /// Reallocates the set's storage to reduce memory use.
+    ///
+    /// Implements `uset_compact`
+    pub fn compact(&mut self) {
+        unsafe { versioned_function!(uset_compact)(self.rep.as_ptr()) };
+    }
+
+    /// Freezes this set, making it immutable, and faster to query.  The returned set can be
+    /// shared between threads.
+    ///
+    /// Implements `uset_freeze`
+    pub fn freeze(self) -> FrozenUSet {
+        unsafe { versioned_function!(uset_freeze)(self.rep.as_ptr()) };
+        FrozenUSet(self)
+    }
+
+    /// Returns true if this set has no elements.
+    ///
+    /// Implements `uset_isEmpty`
+    pub fn is_empty(&self) -> bool {
+        unsafe { versioned_function!(uset_isEmpty)(self.rep.as_ptr()) != 0 }
+    }
+
+    /// Returns the number of code points and strings in this set.
+    ///
+    /// Implements `uset_size`
+    pub fn size(&self) -> usize {
+        unsafe { versioned_function!(uset_size)(self.rep.as_ptr()) as usize }
+    }
+
+    /// Returns true if this set contains the code point `c`.
+    ///
+    /// Implements `uset_contains`
+    pub fn contains(&self, c: sys::UChar32) -> bool {
+        unsafe { versioned_function!(uset_contains)(self.rep.as_ptr(), c) != 0 }
+    }
+
+    /// Returns true if this set contains all code points in the range `start..=end`.
+    ///
+    /// Implements `uset_containsRange`
+    pub fn contains_range(&self, start: sys::UChar32, end: sys::UChar32) -> bool {
+        unsafe { versioned_function!(uset_containsRange)(self.rep.as_ptr(), start, end) != 0 }
+    }
+
+    /// Returns true if this set contains `s`, either as a string element, or as a single code
+    /// point.
+    ///
+    /// Implements `uset_containsString`
+    pub fn contains_string(&self, s: &str) -> bool {
+        let s = ustring::UChar::from(s.encode_utf16().collect::<Vec<sys::UChar>>());
+        self.contains_ustring(&s)
+    }
+
+    /// Returns true if this set contains `s`, either as a string element, or as a single code
+    /// point.
+    ///
+    /// Implements `uset_containsString`
+    pub fn contains_ustring(&self, s: &ustring::UChar) -> bool {
+        unsafe {
+            versioned_function!(uset_containsString)(
+                self.rep.as_ptr(),
+                s.as_c_ptr(),
+                s.len() as i32,
+            ) != 0
+        }
+    }
+
+    /// Returns true if this set contains all elements of `other`.
+    ///
+    /// Implements `uset_containsAll`
+    pub fn contains_all(&self, other: &USet) -> bool {
+        unsafe { versioned_function!(uset_containsAll)(self.rep.as_ptr(), other.rep.as_ptr()) != 0 }
+    }
+
+    /// Returns true if this set contains none of the elements of `other`.
+    ///
+    /// Implements `uset_containsNone`
+    pub fn contains_none(&self, other: &USet) -> bool {
+        unsafe {
+            versioned_function!(uset_containsNone)(self.rep.as_ptr(), other.rep.as_ptr()) != 0
+        }
+    }
+
+    /// Returns true if this set contains some of the elements of `other`.
+    ///
+    /// Implements `uset_containsSome`
+    pub fn contains_some(&self, other: &USet) -> bool {
+        unsafe {
+            versioned_function!(uset_containsSome)(self.rep.as_ptr(), other.rep.as_ptr()) != 0
+        }
+    }
+
+    /// Returns the length, in UTF-16 code units, of the initial substring of `s` which consists
+    /// only of elements matching `condition`.
+    ///
+    /// Implements `uset_span`
+    pub fn span(&self, s: &ustring::UChar, condition: sys::USetSpanCondition) -> usize {
+        unsafe {
+            versioned_function!(uset_span)(
+                self.rep.as_ptr(),
+                s.as_c_ptr(),
+                s.len() as i32,
+                condition,
+            ) as usize
+        }
+    }
+
+    /// Returns the start index, in UTF-16 code units, of the trailing substring of `s` which
+    /// consists only of elements matching `condition`.
+    ///
+    /// Implements `uset_spanBack`
+    pub fn span_back(&self, s: &ustring::UChar, condition: sys::USetSpanCondition) -> usize {
+        unsafe {
+            versioned_function!(uset_spanBack)(
+                self.rep.as_ptr(),
+                s.as_c_ptr(),
+                s.len() as i32,
+                condition,
+            ) as usize
+        }
+    }
+
+    /// Returns the length, in bytes, of the initial substring of `s` which consists only of
+    /// elements matching `condition`.
+    ///
+    /// Implements `uset_spanUTF8`
+    pub fn span_utf8(&self, s: &str, condition: sys::USetSpanCondition) -> usize {
+        assert!(s.len() <= i32::MAX as usize);
+        unsafe {
+            versioned_function!(uset_spanUTF8)(
+                self.rep.as_ptr(),
+                s.as_ptr() as *const raw::c_char,
+                s.len() as i32,
+                condition,
+            ) as usize
+        }
+    }
+
+    /// Returns the start byte index of the trailing substring of `s` which consists only of
+    /// elements matching `condition`.
+    ///
+    /// Implements `uset_spanBackUTF8`
+    pub fn span_back_utf8(&self, s: &str, condition: sys::USetSpanCondition) -> usize {
+        assert!(s.len() <= i32::MAX as usize);
+        unsafe {
+            versioned_function!(uset_spanBackUTF8)(
+                self.rep.as_ptr(),
+                s.as_ptr() as *const raw::c_char,
+                s.len() as i32,
+                condition,
+            ) as usize
+        }
+    }
+
+    /// Returns an iterator over the code point ranges in this set, in ascending order.  An item
+    /// is an error if ICU fails to report the range, so that a failure is not mistaken for the
+    /// end of the set.
+    pub fn ranges(&self) -> Ranges<'_> {
+        Ranges {
+            set: self,
+            index: 0,
+            count: self.range_count(),
+        }
+    }
+
+    /// Returns an iterator over the string elements in this set, in ascending order.
+    pub fn strings(&self) -> Strings<'_> {
+        Strings {
+            set: self,
+            index: self.range_count(),
+            count: self.item_count(),
+        }
+    }
+
+    /// Implements `uset_getItemCount`
+    fn item_count(&self) -> i32 {
+        unsafe { versioned_function!(uset_getItemCount)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the number of ranges, which are the items before the strings.
+    ///
+    /// Implements `uset_getRangeCount`
+    fn range_count(&self) -> i32 {
+        unsafe { versioned_function!(uset_getRangeCount)(self.rep.as_ptr()) }
+    }
+
+    /// Returns the item at `index`, which is either a range of code points or a string.
+    ///
+    /// Implements `uset_getItem`
+    fn get_item(&self, index: i32) -> Result<Item, common::Error> {
+        let mut start: sys::UChar32 = 0;
+        let mut end: sys::UChar32 = 0;
+        let mut status = common::Error::OK_CODE;
+        let len = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_getItem)(
+                self.rep.as_ptr(),
+                index,
+                &mut start,
+                &mut end,
+                ptr::null_mut(),
+                0,
+                &mut status,
+            )
+        };
+        common::Error::ok_preflight(status)?;
+        // The length is also 0 for the empty string, so only the index tells them apart.
+        if index < self.range_count() {
+            return Ok(Item::Range(start..=end));
+        }
+        if len == 0 {
+            return Ok(Item::String(ustring::UChar::new_with_capacity(0)));
+        }
+        let mut result = ustring::UChar::new_with_capacity(len as usize);
+        status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uset_getItem)(
+                self.rep.as_ptr(),
+                index,
+                &mut start,
+                &mut end,
+                result.as_mut_c_ptr(),
+                result.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Item::String(result))
+    }
+}
+
+// An element of a set, as reported by `uset_getItem`.
+enum Item {
+    Range(ops::RangeInclusive<sys::UChar32>),
+    String(ustring::UChar),
+}
+
+/// An iterator over the code point ranges of a [USet], returned by [USet::ranges].
+pub struct Ranges<'a> {
+    set: &'a USet,
+    index: i32,
+    count: i32,
+}
+
+impl Iterator for Ranges<'_> {
+    type Item = Result<ops::RangeInclusive<sys::UChar32>, common::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let item = self.set.get_item(self.index);
+        self.index += 1;
+        match item {
+            Ok(Item::Range(range)) => Some(Ok(range)),
+            Ok(Item::String(_)) => unreachable!("strings come after the ranges"),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// An iterator over the string elements of a [USet], returned by [USet::strings].
+pub struct Strings<'a> {
+    set: &'a USet,
+    index: i32,
+    count: i32,
+}
+
+impl Iterator for Strings<'_> {
+    type Item = Result<String, common::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let item = self.set.get_item(self.index);
+        self.index += 1;
+        match item {
+            Ok(Item::Range(_)) => unreachable!("ranges come before the strings"),
+            Ok(Item::String(s)) => Some(String::try_from(&s)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// An immutable [USet], created by [USet::freeze].
+///
+/// All the read-only methods of [USet] are available on a frozen set.  A frozen set can be
+/// shared between threads.
+#[derive(Debug)]
+pub struct FrozenUSet(USet);
+
+// Safety: a frozen set is immutable, and ICU explicitly allows frozen sets to be used
+// concurrently from multiple threads.
+unsafe impl Sync for FrozenUSet {}
+
+impl FrozenUSet {
+    /// Returns a mutable copy of this set.
+    ///
+    /// Implements `uset_cloneAsThawed`
+    pub fn thaw(&self) -> USet {
+        self.0.clone()
+    }
+}
+
+impl Clone for FrozenUSet {
+    /// Implements `uset_clone`
+    fn clone(&self) -> Self {
+        let rep = unsafe { versioned_function!(uset_clone)(self.0.rep.as_ptr()) };
+        FrozenUSet(USet::from_raw(rep))
+    }
+}
+
+impl ops::Deref for FrozenUSet {
+    type Target = USet;
+
+    fn deref(&self) -> &USet {
+        &self.0
+    }
+}
+
+impl PartialEq for FrozenUSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cp(c: char) -> sys::UChar32 {
+        c as sys::UChar32
+    }
+
+    #[test]
+    fn from_pattern() {
+        let set = USet::try_from("[:Lu:]").expect("set");
+        assert!(set.contains(cp('A')));
+        assert!(set.contains(cp('Ω')));
+        assert!(!set.contains(cp('a')));
+        assert!(!set.is_empty());
+
+        let err = USet::try_from("[a-").expect_err("malformed pattern");
+        assert!(matches!(err, common::Error::Sys(_)));
+    }
+
+    #[test]
+    fn pattern_options() {
+        let set = USet::try_from_pattern_with_options(
+            "[ a b ]",
+            PatternOptions::IGNORE_SPACE | PatternOptions::CASE_INSENSITIVE,
+        )
+        .expect("set");
+        assert!(!set.contains(cp(' ')));
+        assert!(set.contains(cp('B')));
+        assert_eq!(4, set.size());
+    }
+
+    #[test]
+    fn add_remove_retain() {
+        let mut set = USet::new_empty();
+        assert!(set.is_empty());
+        set.add_range(cp('a'), cp('z'));
+        set.add(cp('!'));
+        set.add_string("ch");
+        assert_eq!(28, set.size());
+        assert!(set.contains_string("ch"));
+        assert!(set.contains_string("!"));
+        assert!(set.contains_range(cp('c'), cp('f')));
+
+        set.remove(cp('!'));
+        set.remove_range(cp('a'), cp('c'));
+        set.remove_string("ch");
+        assert!(!set.contains_string("ch"));
+        assert_eq!(23, set.size());
+
+        set.retain_range(cp('d'), cp('f'));
+        assert_eq!(USet::new_range(cp('d'), cp('f')), set);
+
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut set = USet::try_from("[a-m]").expect("set");
+        let other = USet::try_from("[h-z]").expect("other");
+        assert!(set.contains_some(&other));
+        assert!(!set.contains_all(&other));
+
+        let mut union = set.clone();
+        union.add_all(&other);
+        assert_eq!(USet::try_from("[a-z]").unwrap(), union);
+
+        set.retain_all(&other);
+        assert_eq!(USet::try_from("[h-m]").unwrap(), set);
+
+        union.remove_all(&set);
+        assert!(union.contains_none(&set));
+
+        set.complement();
+        assert!(set.contains(cp('a')));
+        assert!(!set.contains(cp('h')));
+        assert!(set.contains(0x10FFFF));
+    }
+
+    #[test]
+    fn span() {
+        let set = USet::try_from("[:L:]").expect("set");
+        let text = "abcλ123";
+        assert_eq!(
+            5,
+            set.span_utf8(text, sys::USetSpanCondition::USET_SPAN_CONTAINED)
+        );
+        assert_eq!(
+            0,
+            set.span_utf8(text, sys::USetSpanCondition::USET_SPAN_NOT_CONTAINED)
+        );
+        assert_eq!(
+            5,
+            set.span_back_utf8(text, sys::USetSpanCondition::USET_SPAN_NOT_CONTAINED)
+        );
+        assert_eq!(
+            8,
+            set.span_back_utf8(text, sys::USetSpanCondition::USET_SPAN_CONTAINED)
+        );
+
+        let text = ustring::UChar::try_from(text).expect("text");
+        assert_eq!(
+            4,
+            set.span(&text, sys::USetSpanCondition::USET_SPAN_CONTAINED)
+        );
+        assert_eq!(
+            4,
+            set.span_back(&text, sys::USetSpanCondition::USET_SPAN_NOT_CONTAINED)
+        );
+    }
+
+    #[test]
+    fn iterators() {
+        let set = USet::try_from("[a-c x-z \\U0001F600 {ch} {ll}]").expect("set");
+        let ranges = set.ranges().collect::<Result<Vec<_>, _>>().expect("ranges");
+        assert_eq!(
+            vec![cp('a')..=cp('c'), cp('x')..=cp('z'), 0x1F600..=0x1F600],
+            ranges
+        );
+        let strings = set
+            .strings()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("strings");
+        assert_eq!(vec!["ch".to_string(), "ll".to_string()], strings);
+    }
+
+    #[test]
+    fn iterators_with_empty_string() {
+        let set = USet::try_from("[a{}]").expect("set");
+        let ranges = set.ranges().collect::<Result<Vec<_>, _>>().expect("ranges");
+        assert_eq!(vec![cp('a')..=cp('a')], ranges);
+        let strings = set
+            .strings()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("strings");
+        assert_eq!(vec!["".to_string()], strings);
+    }
+
+    #[test]
+    fn to_pattern() {
+        let mut set = USet::new_range(cp('a'), cp('c'));
+        set.add_string("ch");
+        assert_eq!("[a-c{ch}]", set.to_pattern(false).expect("pattern"));
+    }
+
+    #[test]
+    fn serialize_roundtrip() {
+        let set = USet::try_from("[a-z\\p{Greek}\\U0001F600]").expect("set");
+        let serialized = set.serialize().expect("serialize");
+        assert!(!serialized.is_empty());
+        let copy = USet::try_from_serialized(&serialized).expect("deserialize");
+        assert_eq!(set, copy);
+
+        USet::try_from_serialized(&[]).expect_err("empty input");
+    }
+
+    #[test]
+    fn freeze() {
+        let frozen = USet::try_from("[:Nd:]").expect("set").freeze();
+        assert!(frozen.contains(cp('7')));
+        let copy = frozen.clone();
+        assert_eq!(frozen, copy);
+
+        let mut thawed = copy.thaw();
+        thawed.remove(cp('7'));
+        assert!(!thawed.contains(cp('7')));
+        assert!(frozen.contains(cp('7')));
+
+        let mut raw_copy = unsafe { USet::clone_from_raw(frozen.as_ptr()) };
+        assert_eq!(*frozen, raw_copy);
+        raw_copy.add(cp('x'));
+        assert!(!frozen.contains(cp('x')));
+
+        let shared = std::sync::Arc::new(frozen);
+        let handles = (0..2)
+            .map(|_| {
+                let shared = shared.clone();
+                std::thread::spawn(move || shared.contains(cp('3')))
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
+}
@@ -0,0 +1,434 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU Arabic shaping for rust
+//!
+//! This crate replaces Arabic letters with the presentation forms that they take in context,
+//! such as the initial, medial, final and isolated forms of a letter and the lam-alef
+//! ligatures, and back.  It also converts between European and Arabic-Indic digits.  This is
+//! useful for displays and printers that render each character with a fixed glyph, and have no
+//! shaping engine of their own.  It is a wrapper around the ICU library, specifically the
+//! functionality exposed through its C API, as available in the [header
+//! `ushape.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/ushape_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_ushape::{self as ushape, Digits, Letters, Options};
+//!
+//! // "Salam", with lam and alef joined into a single ligature.
+//! let options = Options {
+//!     letters: Letters::Shape,
+//!     ..Default::default()
+//! };
+//! let shaped = ushape::shape_arabic("سلام", options).unwrap();
+//! assert_eq!("\u{feb3}\u{fefc}\u{fee1}", shaped);
+//!
+//! let options = Options {
+//!     digits: Digits::En2An,
+//!     ..Default::default()
+//! };
+//! let digits = ushape::shape_arabic("2026", options).unwrap();
+//! assert_eq!("٢٠٢٦", digits);
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::flag_set,
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::convert::{TryFrom, TryInto},
+};
+
+/// How the length of the text may change, from the `U_SHAPE_LENGTH_*` and
+/// `U_SHAPE_LAMALEF_*` constants of `ushape.h`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Length {
+    /// The result may be shorter or longer than the source, as lam-alef ligatures replace two
+    /// letters, and unshaping expands them.
+    #[default]
+    GrowShrink,
+    /// Keep the length of the source, by using the space next to each lam-alef ligature.
+    FixedSpacesNear,
+    /// Keep the length of the source, by using spaces at the end of the text.
+    FixedSpacesAtEnd,
+    /// Keep the length of the source, by using spaces at the beginning of the text.
+    FixedSpacesAtBeginning,
+    /// When unshaping, use the space next to a ligature if there is one, then spaces at the
+    /// end, then at the beginning of the text, and grow the text otherwise.
+    LamalefAuto,
+}
+
+/// The order of the source text, from the `U_SHAPE_TEXT_DIRECTION_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextDirection {
+    /// The source is in logical order, or in visual right-to-left order, which ICU treats the
+    /// same.
+    #[default]
+    Logical,
+    /// The source is in visual left-to-right order.
+    VisualLtr,
+}
+
+/// What happens to the Arabic letters, from the `U_SHAPE_LETTERS_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Letters {
+    /// Leave the letters as they are.
+    #[default]
+    Noop,
+    /// Replace letters by their presentation forms in context.
+    Shape,
+    /// Replace presentation forms by the letters that they represent.
+    Unshape,
+    /// Shape letters, and replace tashkeel marks by their isolated forms, except for the
+    /// shadda combinations.
+    ShapeTashkeelIsolated,
+}
+
+/// What happens to the digits, from the `U_SHAPE_DIGITS_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Digits {
+    /// Leave the digits as they are.
+    #[default]
+    Noop,
+    /// Replace European digits by Arabic-Indic digits.
+    En2An,
+    /// Replace Arabic-Indic digits by European digits.
+    An2En,
+    /// Replace European digits by Arabic-Indic digits if the closest strong character before
+    /// them is Arabic, assuming left-to-right text at the start.
+    Alen2AnInitLr,
+    /// As [Digits::Alen2AnInitLr], but assuming Arabic text at the start.
+    Alen2AnInitAl,
+}
+
+/// The Arabic-Indic digits that [Digits] produces and replaces, from the
+/// `U_SHAPE_DIGIT_TYPE_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DigitType {
+    /// The Arabic-Indic digits U+0660 to U+0669.
+    #[default]
+    An,
+    /// The extended Arabic-Indic digits U+06F0 to U+06F9, as used in Persian and Urdu.
+    AnExtended,
+}
+
+/// What happens to the tashkeel marks when shaping, from the `U_SHAPE_TASHKEEL_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Tashkeel {
+    /// Keep the tashkeel marks.
+    #[default]
+    Keep,
+    /// Replace tashkeel marks by spaces at the beginning of the text.
+    Begin,
+    /// Replace tashkeel marks by spaces at the end of the text.
+    End,
+    /// Remove tashkeel marks, so the text shrinks.
+    Resize,
+    /// Replace tashkeel marks on letters that connect to the next by tatweels, and remove the
+    /// others.
+    ReplaceByTatweel,
+}
+
+flag_set! {
+    /// The options of Arabic shaping that can be set independently of each other, from the
+    /// `U_SHAPE_*` constants of `ushape.h`.
+    Flags {
+        /// Replace each shadda combined with another tashkeel mark by their ligature.
+        AGGREGATE_TASHKEEL = 0x4000,
+        /// Keep the presentation forms of the source when shaping, rather than unshaping
+        /// them first.
+        PRESERVE_PRESENTATION = 0x8000,
+        /// When shaping, represent each seen family letter by two cells, using the space next
+        /// to it.
+        SEEN_TWOCELL_NEAR = 0x200000,
+        /// When shaping, represent each yeh hamza by two cells, using the space next to it.
+        YEHHAMZA_TWOCELL_NEAR = 0x1000000,
+        /// With the length, seen, yeh hamza and tashkeel options, place the spaces at the
+        /// beginning and end of the text in logical order rather than in visual order.
+        SPACES_RELATIVE_TO_TEXT_BEGIN_END = 0x4000000,
+        /// Shape the seen family with the tail character U+FE73 of Unicode 4.1, rather than
+        /// with the tail of the old IBM code pages.
+        TAIL_NEW_UNICODE = 0x8000000,
+    }
+}
+
+/// Options for Arabic shaping.  Each field selects one of the alternatives of a group of
+/// options.  The default changes nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Options {
+    /// What happens to the letters.
+    pub letters: Letters,
+    /// What happens to the digits.
+    pub digits: Digits,
+    /// The Arabic-Indic digits that `digits` produces and replaces.
+    pub digit_type: DigitType,
+    /// How the length of the text may change.
+    pub length: Length,
+    /// The order of the source text.
+    pub text_direction: TextDirection,
+    /// What happens to the tashkeel marks.
+    pub tashkeel: Tashkeel,
+    /// The options that are not part of a group.
+    pub flags: Flags,
+}
+
+impl Options {
+    /// Returns the `U_SHAPE_*` bitmask of the options.
+    fn bits(&self) -> u32 {
+        let length = match self.length {
+            Length::GrowShrink => 0,
+            Length::FixedSpacesNear => 1,
+            Length::FixedSpacesAtEnd => 2,
+            Length::FixedSpacesAtBeginning => 3,
+            Length::LamalefAuto => 0x10000,
+        };
+        let text_direction = match self.text_direction {
+            TextDirection::Logical => 0,
+            TextDirection::VisualLtr => 4,
+        };
+        let letters = match self.letters {
+            Letters::Noop => 0,
+            Letters::Shape => 8,
+            Letters::Unshape => 0x10,
+            Letters::ShapeTashkeelIsolated => 0x18,
+        };
+        let digits = match self.digits {
+            Digits::Noop => 0,
+            Digits::En2An => 0x20,
+            Digits::An2En => 0x40,
+            Digits::Alen2AnInitLr => 0x60,
+            Digits::Alen2AnInitAl => 0x80,
+        };
+        let digit_type = match self.digit_type {
+            DigitType::An => 0,
+            DigitType::AnExtended => 0x100,
+        };
+        let tashkeel = match self.tashkeel {
+            Tashkeel::Keep => 0,
+            Tashkeel::Begin => 0x40000,
+            Tashkeel::End => 0x60000,
+            Tashkeel::Resize => 0x80000,
+            Tashkeel::ReplaceByTatweel => 0xc0000,
+        };
+        length | text_direction | letters | digits | digit_type | tashkeel | self.flags.bits()
+    }
+}
+
+/// Shapes the Arabic text `text` as selected by `options`.
+///
+/// Fails with `U_NO_SPACE_AVAILABLE` if the length must be kept but there are not enough
+/// spaces to unshape the ligatures into, and with `U_ILLEGAL_ARGUMENT_ERROR` if ICU does not
+/// support the combination of `options`.
+///
+/// Implements `u_shapeArabic`.
+pub fn shape_arabic(text: &str, options: Options) -> Result<String, common::Error> {
+    let text = ustring::UChar::try_from(text)?;
+    let result = shape_arabic_ustring(&text, options)?;
+    String::try_from(&result)
+}
+
+/// Shapes the Arabic text `text` as selected by `options`, as [shape_arabic] does for UTF-8
+/// text.
+///
+/// Implements `u_shapeArabic`.
+pub fn shape_arabic_ustring(
+    text: &ustring::UChar,
+    options: Options,
+) -> Result<ustring::UChar, common::Error> {
+    const CAPACITY: usize = 200;
+    buffered_uchar_method_with_retry!(
+        shape_arabic_impl,
+        CAPACITY,
+        [source: *const sys::UChar, source_length: i32,],
+        [options: u32,]
+    );
+    shape_arabic_impl(
+        versioned_function!(u_shapeArabic),
+        text.as_c_ptr(),
+        text.len() as i32,
+        options.bits(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape() {
+        struct TestCase {
+            source: &'static str,
+            options: Options,
+            expected: &'static str,
+        }
+        let shape = Options {
+            letters: Letters::Shape,
+            ..Default::default()
+        };
+        let tests = vec![
+            // Initial seen, lam-alef ligature and final meem.
+            TestCase {
+                source: "سلام",
+                options: shape,
+                expected: "\u{feb3}\u{fefc}\u{fee1}",
+            },
+            TestCase {
+                source: "سلام",
+                options: Options {
+                    length: Length::FixedSpacesNear,
+                    ..shape
+                },
+                expected: "\u{feb3}\u{fefc} \u{fee1}",
+            },
+            TestCase {
+                source: "سلام",
+                options: Options {
+                    length: Length::FixedSpacesAtEnd,
+                    ..shape
+                },
+                expected: "\u{feb3}\u{fefc}\u{fee1} ",
+            },
+            TestCase {
+                source: "سلام",
+                options: Options {
+                    length: Length::FixedSpacesAtBeginning,
+                    ..shape
+                },
+                expected: " \u{feb3}\u{fefc}\u{fee1}",
+            },
+            TestCase {
+                source: "\u{feb3}\u{fefc}\u{fee1}",
+                options: Options {
+                    letters: Letters::Unshape,
+                    ..Default::default()
+                },
+                expected: "سلام",
+            },
+            TestCase {
+                source: "Tel. 123",
+                options: Options {
+                    digits: Digits::En2An,
+                    ..Default::default()
+                },
+                expected: "Tel. ١٢٣",
+            },
+            TestCase {
+                source: "123",
+                options: Options {
+                    digits: Digits::En2An,
+                    digit_type: DigitType::AnExtended,
+                    ..Default::default()
+                },
+                expected: "۱۲۳",
+            },
+            TestCase {
+                source: "١٢٣",
+                options: Options {
+                    digits: Digits::An2En,
+                    ..Default::default()
+                },
+                expected: "123",
+            },
+            // Only the digits that follow Arabic letters are replaced.
+            TestCase {
+                source: "a 1 ب 2",
+                options: Options {
+                    digits: Digits::Alen2AnInitLr,
+                    ..Default::default()
+                },
+                expected: "a 1 ب ٢",
+            },
+            TestCase {
+                source: "1 ب 2",
+                options: Options {
+                    digits: Digits::Alen2AnInitAl,
+                    ..Default::default()
+                },
+                expected: "١ ب ٢",
+            },
+            // Beh with a fatha, whose tashkeel is removed.
+            TestCase {
+                source: "بَ",
+                options: Options {
+                    tashkeel: Tashkeel::Resize,
+                    ..shape
+                },
+                expected: "\u{fe8f}",
+            },
+        ];
+        for test in tests {
+            assert_eq!(
+                test.expected,
+                shape_arabic(test.source, test.options).unwrap(),
+                "source: {:?}, options: {:?}",
+                test.source,
+                test.options
+            );
+        }
+    }
+
+    #[test]
+    fn bits() {
+        assert_eq!(0, Options::default().bits());
+        let options = Options {
+            letters: Letters::ShapeTashkeelIsolated,
+            digits: Digits::Alen2AnInitAl,
+            digit_type: DigitType::AnExtended,
+            length: Length::LamalefAuto,
+            text_direction: TextDirection::VisualLtr,
+            tashkeel: Tashkeel::ReplaceByTatweel,
+            flags: Flags::AGGREGATE_TASHKEEL | Flags::TAIL_NEW_UNICODE,
+        };
+        assert_eq!(0x80d419c, options.bits());
+    }
+
+    #[test]
+    fn no_space_for_ligature() {
+        // Unshaping the lam-alef ligature needs a space next to it to keep the length.
+        let options = Options {
+            letters: Letters::Unshape,
+            length: Length::FixedSpacesNear,
+            ..Default::default()
+        };
+        let result = shape_arabic("\u{feb3}\u{fefc}\u{fee1}", options);
+        assert!(matches!(
+            result,
+            Err(common::Error::Sys(sys::UErrorCode::U_NO_SPACE_AVAILABLE))
+        ));
+    }
+
+    #[test]
+    fn ustring() {
+        let source = ustring::UChar::try_from("لا").unwrap();
+        let shape = Options {
+            letters: Letters::Shape,
+            ..Default::default()
+        };
+        let shaped = shape_arabic_ustring(&source, shape).unwrap();
+        assert_eq!("\u{fefb}", String::try_from(&shaped).unwrap());
+        let unshape = Options {
+            letters: Letters::Unshape,
+            ..Default::default()
+        };
+        let unshaped = shape_arabic_ustring(&shaped, unshape).unwrap();
+        assert_eq!("لا", String::try_from(&unshaped).unwrap());
+    }
+}
@@ -0,0 +1,638 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU spoof and confusable detection for rust
+//!
+//! This crate detects identifiers that are visually confusable with other identifiers, or that
+//! are otherwise suspicious, following [UTS #39](https://www.unicode.org/reports/tr39/), as
+//! implemented by the ICU library.  Specifically the functionality exposed through its C API, as
+//! available in the [header
+//! `uspoof.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/uspoof_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_uspoof as uspoof;
+//!
+//! let mut checker = uspoof::SpoofChecker::try_new().expect("checker");
+//! checker
+//!     .set_checks(uspoof::Checks::RESTRICTION_LEVEL | uspoof::Checks::CONFUSABLE)
+//!     .expect("set_checks");
+//! checker.set_restriction_level(sys::URestrictionLevel::USPOOF_HIGHLY_RESTRICTIVE);
+//!
+//! // "paypal", with a Cyrillic "а" in place of the first Latin "a".
+//! let result = checker.check("pаypal").expect("check");
+//! assert!(result.checks.contains(uspoof::Checks::RESTRICTION_LEVEL));
+//!
+//! let confusable = checker.are_confusable("pаypal", "paypal").expect("are_confusable");
+//! assert!(confusable.contains(uspoof::Checks::MIXED_SCRIPT_CONFUSABLE));
+//! assert_eq!(
+//!     checker.get_skeleton("pаypal").expect("skeleton"),
+//!     checker.get_skeleton("paypal").expect("skeleton"),
+//! );
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::{flag_set, simple_drop_impl},
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_uset as uset, rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{convert::TryInto, ffi, os::raw, ptr},
+};
+
+flag_set! {
+    /// The checks performed by a [SpoofChecker], and the checks that an identifier fails.
+    Checks {
+        /// The identifier is confusable with another identifier in the same script.
+        SINGLE_SCRIPT_CONFUSABLE = sys::USpoofChecks::USPOOF_SINGLE_SCRIPT_CONFUSABLE as u32,
+        /// The identifier is confusable with an identifier in a different script.
+        MIXED_SCRIPT_CONFUSABLE = sys::USpoofChecks::USPOOF_MIXED_SCRIPT_CONFUSABLE as u32,
+        /// The identifier is confusable with an identifier entirely in a different script.
+        WHOLE_SCRIPT_CONFUSABLE = sys::USpoofChecks::USPOOF_WHOLE_SCRIPT_CONFUSABLE as u32,
+        /// Compare identifiers for confusability regardless of letter case.
+        ANY_CASE = sys::USpoofChecks::USPOOF_ANY_CASE as u32,
+        /// The identifier does not meet the restriction level of the checker.
+        RESTRICTION_LEVEL = sys::USpoofChecks::USPOOF_RESTRICTION_LEVEL as u32,
+        /// The identifier contains invisible characters, such as repeated combining marks.
+        INVISIBLE = sys::USpoofChecks::USPOOF_INVISIBLE as u32,
+        /// The identifier contains characters outside of the allowed characters.
+        CHAR_LIMIT = sys::USpoofChecks::USPOOF_CHAR_LIMIT as u32,
+        /// The identifier contains digits from more than one numbering system.
+        MIXED_NUMBERS = sys::USpoofChecks::USPOOF_MIXED_NUMBERS as u32,
+        /// The identifier contains a combining dot above a character that already has one.
+        HIDDEN_OVERLAY = sys::USpoofChecks::USPOOF_HIDDEN_OVERLAY as u32,
+        /// Makes [SpoofChecker::check] also compute the restriction level of the identifier.
+        AUX_INFO = sys::USpoofChecks::USPOOF_AUX_INFO as u32,
+    }
+}
+
+impl Checks {
+    /// All three confusable checks.
+    pub const CONFUSABLE: Checks = Checks::from_bits(sys::USpoofChecks::USPOOF_CONFUSABLE as u32);
+
+    /// All checks.
+    pub const ALL_CHECKS: Checks = Checks::from_bits(sys::USpoofChecks::USPOOF_ALL_CHECKS as u32);
+}
+
+/// The result of checking an identifier with [SpoofChecker::check].
+#[derive(Debug)]
+pub struct CheckResult {
+    /// The checks that the identifier failed.  The identifier passed all checks if this is
+    /// empty.
+    pub checks: Checks,
+    /// The most restrictive restriction level that the identifier meets.  Only set if the
+    /// [Checks::RESTRICTION_LEVEL] check is enabled.
+    pub restriction_level: Option<sys::URestrictionLevel>,
+    /// The zero digits of the numbering systems used in the identifier.  Only set if the
+    /// [Checks::MIXED_NUMBERS] check is enabled.
+    pub numerics: Option<uset::USet>,
+}
+
+// Owns the USpoofCheckResult that a check writes its details into.
+struct RawCheckResult {
+    rep: ptr::NonNull<sys::USpoofCheckResult>,
+}
+
+// Implements `uspoof_closeCheckResult`
+simple_drop_impl!(RawCheckResult, uspoof_closeCheckResult);
+
+impl RawCheckResult {
+    // Implements `uspoof_openCheckResult`
+    fn try_new() -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_openCheckResult)(&mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(RawCheckResult {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    // Implements `uspoof_getCheckResultRestrictionLevel` and `uspoof_getCheckResultNumerics`
+    fn into_check_result(self, enabled: Checks) -> Result<CheckResult, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let checks = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_getCheckResultChecks)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+
+        let restriction_level = if enabled.contains(Checks::RESTRICTION_LEVEL) {
+            let level = unsafe {
+                versioned_function!(uspoof_getCheckResultRestrictionLevel)(
+                    self.rep.as_ptr(),
+                    &mut status,
+                )
+            };
+            common::Error::ok_or_warning(status)?;
+            Some(level)
+        } else {
+            None
+        };
+
+        let numerics = if enabled.contains(Checks::MIXED_NUMBERS) {
+            let set = unsafe {
+                versioned_function!(uspoof_getCheckResultNumerics)(self.rep.as_ptr(), &mut status)
+            };
+            common::Error::ok_or_warning(status)?;
+            // Unsafety note: the set is owned by the check result, which is still open here.
+            Some(unsafe { uset::USet::clone_from_raw(set) })
+        } else {
+            None
+        };
+
+        Ok(CheckResult {
+            // With AUX_INFO, ICU reports the restriction level in the upper bits as well.
+            checks: Checks::from_bits(checks as u32) & Checks::ALL_CHECKS,
+            restriction_level,
+            numerics,
+        })
+    }
+}
+
+/// Checks identifiers for security issues such as visually confusable characters.
+///
+/// A checker is configured with the `set_*` methods, after which it can be shared between
+/// threads to check identifiers.
+#[derive(Debug)]
+pub struct SpoofChecker {
+    rep: ptr::NonNull<sys::USpoofChecker>,
+}
+
+// Implements `uspoof_close`
+simple_drop_impl!(SpoofChecker, uspoof_close);
+
+// Safety: ICU allows a checker to be used concurrently from multiple threads once it is no
+// longer being configured, which requires `&mut self` here.
+unsafe impl Send for SpoofChecker {}
+unsafe impl Sync for SpoofChecker {}
+
+impl SpoofChecker {
+    /// Creates a checker with the default checks, which include the confusable checks.
+    ///
+    /// Implements `uspoof_open`
+    pub fn try_new() -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_open)(&mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(SpoofChecker {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Makes an independent copy of this checker, including its configuration.
+    ///
+    /// Implements `uspoof_clone`
+    pub fn try_clone(&self) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_clone)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(SpoofChecker {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Sets the checks to perform.
+    ///
+    /// Implements `uspoof_setChecks`
+    pub fn set_checks(&mut self, checks: Checks) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_setChecks)(
+                self.rep.as_ptr(),
+                checks.bits() as i32,
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Implements `uspoof_getChecks`
+    pub fn checks(&self) -> Result<Checks, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let checks = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_getChecks)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Checks::from_bits(checks as u32))
+    }
+
+    /// Sets the restriction level that identifiers must meet to pass the
+    /// [Checks::RESTRICTION_LEVEL] check.  Also enables that check.
+    ///
+    /// Implements `uspoof_setRestrictionLevel`
+    pub fn set_restriction_level(&mut self, level: sys::URestrictionLevel) {
+        unsafe { versioned_function!(uspoof_setRestrictionLevel)(self.rep.as_ptr(), level) }
+    }
+
+    /// Implements `uspoof_getRestrictionLevel`
+    pub fn restriction_level(&self) -> sys::URestrictionLevel {
+        unsafe { versioned_function!(uspoof_getRestrictionLevel)(self.rep.as_ptr()) }
+    }
+
+    /// Limits the allowed characters to those commonly used by the given comma-separated list of
+    /// locales, for example `"en, ru"`.  An empty list allows all characters.  Also enables the
+    /// [Checks::CHAR_LIMIT] check.
+    ///
+    /// Implements `uspoof_setAllowedLocales`
+    pub fn set_allowed_locales(&mut self, locales: &str) -> Result<(), common::Error> {
+        let locales = ffi::CString::new(locales)?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_setAllowedLocales)(
+                self.rep.as_ptr(),
+                locales.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Returns the comma-separated list of allowed locales.
+    ///
+    /// Implements `uspoof_getAllowedLocales`
+    pub fn allowed_locales(&self) -> Result<String, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let locales = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_getAllowedLocales)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: ICU returns a NUL-terminated string owned by the checker.
+        let locales = unsafe { ffi::CStr::from_ptr(locales) };
+        Ok(locales.to_str()?.to_string())
+    }
+
+    /// Limits the allowed characters to `chars`.  Also enables the [Checks::CHAR_LIMIT]
+    /// check.
+    ///
+    /// Implements `uspoof_setAllowedChars`
+    pub fn set_allowed_chars(&mut self, chars: &uset::USet) -> Result<(), common::Error> {
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_setAllowedChars)(
+                self.rep.as_ptr(),
+                chars.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)
+    }
+
+    /// Returns a copy of the set of allowed characters.
+    ///
+    /// Implements `uspoof_getAllowedChars`
+    pub fn allowed_chars(&self) -> Result<uset::USet, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let set = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_getAllowedChars)(self.rep.as_ptr(), &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        // Unsafety note: the set is owned by the checker, which outlives this call.
+        Ok(unsafe { uset::USet::clone_from_raw(set) })
+    }
+
+    /// Checks `id` with the enabled checks.
+    ///
+    /// Implements `uspoof_check2UTF8`
+    pub fn check(&self, id: &str) -> Result<CheckResult, common::Error> {
+        let result = RawCheckResult::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_check2UTF8)(
+                self.rep.as_ptr(),
+                id.as_ptr() as *const raw::c_char,
+                id.len() as i32,
+                result.rep.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        result.into_check_result(self.checks()?)
+    }
+
+    /// Implements `uspoof_check2`
+    pub fn check_ustring(&self, id: &ustring::UChar) -> Result<CheckResult, common::Error> {
+        let result = RawCheckResult::try_new()?;
+        let mut status = common::Error::OK_CODE;
+        unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_check2)(
+                self.rep.as_ptr(),
+                id.as_c_ptr(),
+                id.len() as i32,
+                result.rep.as_ptr(),
+                &mut status,
+            );
+        }
+        common::Error::ok_or_warning(status)?;
+        result.into_check_result(self.checks()?)
+    }
+
+    /// Returns the kinds of confusability found between `id1` and `id2`, limited to the
+    /// confusable checks that are enabled.  The result is empty if the identifiers are not
+    /// confusable.
+    ///
+    /// Implements `uspoof_areConfusableUTF8`
+    pub fn are_confusable(&self, id1: &str, id2: &str) -> Result<Checks, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_areConfusableUTF8)(
+                self.rep.as_ptr(),
+                id1.as_ptr() as *const raw::c_char,
+                id1.len() as i32,
+                id2.as_ptr() as *const raw::c_char,
+                id2.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Checks::from_bits(result as u32))
+    }
+
+    /// Implements `uspoof_areConfusable`
+    pub fn are_confusable_ustring(
+        &self,
+        id1: &ustring::UChar,
+        id2: &ustring::UChar,
+    ) -> Result<Checks, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let result = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(uspoof_areConfusable)(
+                self.rep.as_ptr(),
+                id1.as_c_ptr(),
+                id1.len() as i32,
+                id2.as_c_ptr(),
+                id2.len() as i32,
+                &mut status,
+            )
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(Checks::from_bits(result as u32))
+    }
+
+    /// Returns the skeleton of `id`.  Two identifiers are confusable if their skeletons are
+    /// equal, so skeletons can be stored to look up confusable identifiers efficiently.
+    ///
+    /// Implements `uspoof_getSkeletonUTF8`
+    pub fn get_skeleton(&self, id: &str) -> Result<String, common::Error> {
+        const CAPACITY: usize = 200;
+        common::buffered_string_method_with_retry(
+            |buf, len, status| unsafe {
+                versioned_function!(uspoof_getSkeletonUTF8)(
+                    self.rep.as_ptr(),
+                    0,
+                    id.as_ptr() as *const raw::c_char,
+                    id.len() as i32,
+                    buf,
+                    len,
+                    status,
+                )
+            },
+            CAPACITY,
+        )
+    }
+
+    /// Implements `uspoof_getSkeleton`
+    pub fn get_skeleton_ustring(
+        &self,
+        id: &ustring::UChar,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 200;
+        buffered_uchar_method_with_retry!(
+            get_skeleton_raw,
+            CAPACITY,
+            [
+                rep: *const sys::USpoofChecker,
+                skeleton_type: u32,
+                id: *const sys::UChar,
+                id_length: i32,
+            ],
+            []
+        );
+        get_skeleton_raw(
+            versioned_function!(uspoof_getSkeleton),
+            self.rep.as_ptr(),
+            0,
+            id.as_c_ptr(),
+            id.len() as i32,
+        )
+    }
+}
+
+/// Returns the set of characters that UTS #39 allows in identifiers in the Inclusion
+/// identifier type.
+///
+/// Implements `uspoof_getInclusionSet`
+pub fn inclusion_set() -> Result<uset::USet, common::Error> {
+    let mut status = common::Error::OK_CODE;
+    let set = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(uspoof_getInclusionSet)(&mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    // Unsafety note: the set is a static owned by ICU.
+    Ok(unsafe { uset::USet::clone_from_raw(set) })
+}
+
+/// Returns the set of characters that UTS #39 recommends for use in identifiers.
+///
+/// Implements `uspoof_getRecommendedSet`
+pub fn recommended_set() -> Result<uset::USet, common::Error> {
+    let mut status = common::Error::OK_CODE;
+    let set = unsafe {
+        assert!(common::Error::is_ok(status));
+        versioned_function!(uspoof_getRecommendedSet)(&mut status)
+    };
+    common::Error::ok_or_warning(status)?;
+    // Unsafety note: the set is a static owned by ICU.
+    Ok(unsafe { uset::USet::clone_from_raw(set) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    // "paypal", with a Cyrillic "а" in place of the first Latin "a".
+    const SPOOFED: &str = "pаypal";
+
+    #[test]
+    fn checks() {
+        let mut checker = SpoofChecker::try_new().expect("checker");
+        assert!(checker.checks().unwrap().contains(Checks::CONFUSABLE));
+        checker
+            .set_checks(Checks::RESTRICTION_LEVEL | Checks::INVISIBLE | Checks::MIXED_NUMBERS)
+            .unwrap();
+        assert_eq!(
+            Checks::RESTRICTION_LEVEL | Checks::INVISIBLE | Checks::MIXED_NUMBERS,
+            checker.checks().unwrap()
+        );
+
+        let result = checker.check("paypal").unwrap();
+        assert!(result.checks.is_empty());
+        assert_eq!(
+            Some(sys::URestrictionLevel::USPOOF_ASCII),
+            result.restriction_level
+        );
+
+        let result = checker.check(SPOOFED).unwrap();
+        assert_eq!(Checks::RESTRICTION_LEVEL, result.checks);
+        assert_eq!(
+            Some(sys::URestrictionLevel::USPOOF_MINIMALLY_RESTRICTIVE),
+            result.restriction_level
+        );
+
+        // A combining acute accent, twice.
+        let result = checker.check("a\u{0301}\u{0301}").unwrap();
+        assert_eq!(Checks::INVISIBLE, result.checks);
+
+        // An ASCII digit and a Bengali digit.
+        let result = checker
+            .check_ustring(&ustring::UChar::try_from("1\u{09e8}").unwrap())
+            .unwrap();
+        assert!(result.checks.contains(Checks::MIXED_NUMBERS));
+        let numerics = result.numerics.expect("numerics");
+        assert_eq!(2, numerics.size());
+        assert!(numerics.contains('0' as sys::UChar32));
+        assert!(numerics.contains('\u{09e6}' as sys::UChar32));
+    }
+
+    #[test]
+    fn restriction_level() {
+        let mut checker = SpoofChecker::try_new().expect("checker");
+        checker.set_restriction_level(sys::URestrictionLevel::USPOOF_ASCII);
+        assert_eq!(
+            sys::URestrictionLevel::USPOOF_ASCII,
+            checker.restriction_level()
+        );
+        checker.set_checks(Checks::RESTRICTION_LEVEL).unwrap();
+        assert!(checker.check("abc").unwrap().checks.is_empty());
+        assert_eq!(
+            Checks::RESTRICTION_LEVEL,
+            checker.check("Straße").unwrap().checks
+        );
+        checker.set_restriction_level(sys::URestrictionLevel::USPOOF_HIGHLY_RESTRICTIVE);
+        assert!(checker.check("Straße").unwrap().checks.is_empty());
+        // Latin and Japanese scripts are allowed together.
+        assert!(checker.check("abcひらがな").unwrap().checks.is_empty());
+        assert!(!checker.check("abcΩ").unwrap().checks.is_empty());
+    }
+
+    #[test]
+    fn allowed_locales_and_chars() {
+        let mut checker = SpoofChecker::try_new().expect("checker");
+        checker.set_allowed_locales("en, ru").unwrap();
+        assert_eq!("en, ru", checker.allowed_locales().unwrap());
+        assert!(checker.checks().unwrap().contains(Checks::CHAR_LIMIT));
+        checker.set_checks(Checks::CHAR_LIMIT).unwrap();
+        assert!(checker.check("привет").unwrap().checks.is_empty());
+        assert_eq!(Checks::CHAR_LIMIT, checker.check("Ωμέγα").unwrap().checks);
+
+        let chars = uset::USet::try_from("[a-z]").unwrap();
+        checker.set_allowed_chars(&chars).unwrap();
+        assert_eq!(chars, checker.allowed_chars().unwrap());
+        assert!(checker.check("abc").unwrap().checks.is_empty());
+        assert_eq!(Checks::CHAR_LIMIT, checker.check("ABC").unwrap().checks);
+    }
+
+    #[test]
+    fn confusable() {
+        let mut checker = SpoofChecker::try_new().expect("checker");
+        checker.set_checks(Checks::CONFUSABLE).unwrap();
+        assert_eq!(
+            Checks::MIXED_SCRIPT_CONFUSABLE,
+            checker.are_confusable(SPOOFED, "paypal").unwrap()
+        );
+        assert_eq!(
+            Checks::SINGLE_SCRIPT_CONFUSABLE,
+            checker.are_confusable("rn", "m").unwrap()
+        );
+        assert!(checker.are_confusable("paypal", "ebay").unwrap().is_empty());
+
+        let a = ustring::UChar::try_from("scope").unwrap();
+        // "ѕсоре", entirely in Cyrillic.
+        let b = ustring::UChar::try_from("ѕсоре").unwrap();
+        assert!(checker
+            .are_confusable_ustring(&a, &b)
+            .unwrap()
+            .contains(Checks::WHOLE_SCRIPT_CONFUSABLE));
+
+        let copy = checker.try_clone().expect("clone");
+        assert_eq!(Checks::CONFUSABLE, copy.checks().unwrap());
+    }
+
+    #[test]
+    fn skeleton() {
+        let checker = SpoofChecker::try_new().expect("checker");
+        assert_eq!("paypal", checker.get_skeleton(SPOOFED).unwrap());
+        assert_eq!("rn", checker.get_skeleton("m").unwrap());
+        let long = "m".repeat(150);
+        assert_eq!("rn".repeat(150), checker.get_skeleton(&long).unwrap());
+
+        let skeleton = checker
+            .get_skeleton_ustring(&ustring::UChar::try_from(SPOOFED).unwrap())
+            .unwrap();
+        assert_eq!("paypal", String::try_from(&skeleton).unwrap());
+    }
+
+    #[test]
+    fn identifier_sets() {
+        let inclusion = inclusion_set().unwrap();
+        assert!(inclusion.contains('-' as sys::UChar32));
+        assert!(!inclusion.contains('a' as sys::UChar32));
+
+        let recommended = recommended_set().unwrap();
+        assert!(recommended.contains('a' as sys::UChar32));
+        assert!(!recommended.contains('-' as sys::UChar32));
+        assert!(recommended
+            .ranges()
+            .any(|r| r.unwrap().contains(&('0' as sys::UChar32))));
+        assert!(recommended
+            .to_pattern(false)
+            .unwrap()
+            .starts_with("[0-9A-Z_a-z"));
+    }
+
+    #[test]
+    fn flag_set_debug() {
+        assert_eq!(
+            "Checks(SINGLE_SCRIPT_CONFUSABLE | MIXED_SCRIPT_CONFUSABLE | WHOLE_SCRIPT_CONFUSABLE)",
+            format!("{:?}", Checks::CONFUSABLE)
+        );
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICU StringPrep for rust
+//!
+//! This crate prepares strings for comparison in network protocols, following [RFC
+//! 3454](https://www.rfc-editor.org/rfc/rfc3454) and the profiles built on it, such as SASLprep
+//! for user names and passwords, and the nodeprep and resourceprep profiles of XMPP.  It is a
+//! wrapper around the ICU library, specifically the functionality exposed through its C API,
+//! as available in the [header
+//! `usprep.h`](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/usprep_8h.html).
+//!
+//! > Are you missing some features from this crate?  Consider [reporting an
+//! > issue](https://github.com/google/rust_icu/issues) or even [contributing the
+//! > functionality](https://github.com/google/rust_icu/pulls).
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_icu_sys as sys;
+//! use rust_icu_usprep::{self as usprep, Options};
+//!
+//! let saslprep =
+//!     usprep::UStringPrepProfile::try_new(sys::UStringPrepProfileType::USPREP_RFC4013_SASLPREP)
+//!         .unwrap();
+//! // The soft hyphen is removed, and the Roman numeral nine is normalized.
+//! assert_eq!("IX", saslprep.prepare("I\u{ad}X", Options::default()).unwrap());
+//! assert_eq!("IX", saslprep.prepare("\u{2168}", Options::default()).unwrap());
+//! // Control characters are prohibited.
+//! assert!(saslprep.prepare("a\u{7}", Options::default()).is_err());
+//! ```
+
+use {
+    rust_icu_common as common,
+    rust_icu_common::{flag_set, simple_drop_impl},
+    rust_icu_sys as sys,
+    rust_icu_sys::versioned_function,
+    rust_icu_ustring as ustring,
+    rust_icu_ustring::buffered_uchar_method_with_retry,
+    std::{
+        convert::{TryFrom, TryInto},
+        fmt, ptr,
+    },
+};
+
+flag_set! {
+    /// Options for preparing a string, from the `USPREP_*` constants of `usprep.h`.  The empty
+    /// set is the default behavior, which rejects code points that are unassigned in Unicode
+    /// 3.2, as required for stored strings.
+    Options {
+        /// Let unassigned code points through unchanged, as allowed for queries.
+        ALLOW_UNASSIGNED = 0x1,
+    }
+}
+
+/// The reason that a string could not be prepared, and where in the string that happened.
+///
+/// [UStringPrepProfile::prepare] returns it inside `common::Error::Wrapper`, from which it can
+/// be recovered with `downcast_ref`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrepareError {
+    /// The ICU error code, such as `U_STRINGPREP_PROHIBITED_ERROR`,
+    /// `U_STRINGPREP_UNASSIGNED_ERROR` or `U_STRINGPREP_CHECK_BIDI_ERROR`.
+    pub status: sys::UErrorCode,
+    /// The offset of the offending code point in the UTF-16 form of the string, as ICU
+    /// reports it.
+    pub offset: i32,
+    /// The text just before the offending code point.
+    pub pre_context: String,
+    /// The offending code point and the text just after it.
+    pub post_context: String,
+}
+
+impl fmt::Display for PrepareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at offset {}: {:?} before {:?}",
+            self.status, self.offset, self.pre_context, self.post_context
+        )
+    }
+}
+
+impl std::error::Error for PrepareError {}
+
+// Converts the NUL-terminated context of a parse error to a string.
+fn context(units: &[sys::UChar]) -> String {
+    let len = units.iter().position(|&u| u == 0).unwrap_or(units.len());
+    String::from_utf16_lossy(&units[..len])
+}
+
+/// A StringPrep profile, which defines how strings are mapped, normalized and checked.
+#[derive(Debug)]
+pub struct UStringPrepProfile {
+    rep: ptr::NonNull<sys::UStringPrepProfile>,
+}
+
+// Implements `usprep_close`.
+simple_drop_impl!(UStringPrepProfile, usprep_close);
+
+impl UStringPrepProfile {
+    /// Opens one of the profiles that are built into ICU, such as
+    /// `USPREP_RFC4013_SASLPREP` or `USPREP_RFC3920_RESOURCEPREP`.
+    ///
+    /// Implements `usprep_openByType`.
+    pub fn try_new(profile_type: sys::UStringPrepProfileType) -> Result<Self, common::Error> {
+        let mut status = common::Error::OK_CODE;
+        let rep = unsafe {
+            assert!(common::Error::is_ok(status));
+            versioned_function!(usprep_openByType)(profile_type, &mut status)
+        };
+        common::Error::ok_or_warning(status)?;
+        Ok(UStringPrepProfile {
+            rep: ptr::NonNull::new(rep).unwrap(),
+        })
+    }
+
+    /// Prepares `text` with this profile.
+    ///
+    /// If `text` contains a prohibited or, without [Options::ALLOW_UNASSIGNED], an unassigned
+    /// code point, or fails the bidi check of the profile, the error wraps a [PrepareError]
+    /// that tells where.
+    ///
+    /// Implements `usprep_prepare`.
+    pub fn prepare(&self, text: &str, options: Options) -> Result<String, common::Error> {
+        let text = ustring::UChar::try_from(text)?;
+        let result = self.prepare_ustring(&text, options)?;
+        String::try_from(&result)
+    }
+
+    /// Prepares `text` with this profile, as [UStringPrepProfile::prepare] does for UTF-8
+    /// text.
+    ///
+    /// Implements `usprep_prepare`.
+    pub fn prepare_ustring(
+        &self,
+        text: &ustring::UChar,
+        options: Options,
+    ) -> Result<ustring::UChar, common::Error> {
+        const CAPACITY: usize = 200;
+        buffered_uchar_method_with_retry!(
+            prepare_impl,
+            CAPACITY,
+            [
+                rep: *const sys::UStringPrepProfile,
+                src: *const sys::UChar,
+                src_length: i32,
+            ],
+            [options: i32, parse_error: *mut sys::UParseError,]
+        );
+        // ICU only sets the offset for errors in the text, so -1 tells the others apart.
+        let mut parse_error = sys::UParseError {
+            offset: -1,
+            ..common::NO_PARSE_ERROR
+        };
+        let result = prepare_impl(
+            versioned_function!(usprep_prepare),
+            self.rep.as_ptr(),
+            text.as_c_ptr(),
+            text.len() as i32,
+            options.bits() as i32,
+            &mut parse_error,
+        );
+        match result {
+            Err(common::Error::Sys(status)) if parse_error.offset >= 0 => {
+                Err(common::Error::Wrapper(anyhow::Error::new(PrepareError {
+                    status,
+                    offset: parse_error.offset,
+                    pre_context: context(&parse_error.preContext),
+                    post_context: context(&parse_error.postContext),
+                })))
+            }
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, sys::UStringPrepProfileType::*};
+
+    fn prepare_error(result: Result<String, common::Error>) -> PrepareError {
+        match result {
+            Err(common::Error::Wrapper(e)) => e.downcast_ref::<PrepareError>().unwrap().clone(),
+            other => panic!("not a prepare error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn prepare() {
+        struct TestCase {
+            profile_type: sys::UStringPrepProfileType,
+            source: &'static str,
+            expected: &'static str,
+        }
+        let tests = vec![
+            // The examples of RFC 4013.
+            TestCase {
+                profile_type: USPREP_RFC4013_SASLPREP,
+                source: "I\u{ad}X",
+                expected: "IX",
+            },
+            TestCase {
+                profile_type: USPREP_RFC4013_SASLPREP,
+                source: "user",
+                expected: "user",
+            },
+            TestCase {
+                profile_type: USPREP_RFC4013_SASLPREP,
+                source: "\u{aa}",
+                expected: "a",
+            },
+            TestCase {
+                profile_type: USPREP_RFC4013_SASLPREP,
+                source: "\u{2168}",
+                expected: "IX",
+            },
+            // Non-ASCII spaces become ASCII spaces.
+            TestCase {
+                profile_type: USPREP_RFC4013_SASLPREP,
+                source: "pass\u{a0}word",
+                expected: "pass word",
+            },
+            TestCase {
+                profile_type: USPREP_RFC3920_NODEPREP,
+                source: "JuliET",
+                expected: "juliet",
+            },
+            TestCase {
+                profile_type: USPREP_RFC3920_RESOURCEPREP,
+                source: "Home Office",
+                expected: "Home Office",
+            },
+            TestCase {
+                profile_type: USPREP_RFC3491_NAMEPREP,
+                source: "ExAmple.COM",
+                expected: "example.com",
+            },
+            TestCase {
+                profile_type: USPREP_RFC4518_LDAP_CI,
+                source: "Hello",
+                expected: "hello",
+            },
+            TestCase {
+                profile_type: USPREP_RFC3722_ISCSI,
+                source: "IQN.2026-10.com.Example",
+                expected: "iqn.2026-10.com.example",
+            },
+            TestCase {
+                profile_type: USPREP_RFC4011_MIB,
+                source: "ifIndex",
+                expected: "ifIndex",
+            },
+        ];
+        for test in tests {
+            let profile = UStringPrepProfile::try_new(test.profile_type).unwrap();
+            assert_eq!(
+                test.expected,
+                profile.prepare(test.source, Options::default()).unwrap(),
+                "profile: {:?}, source: {:?}",
+                test.profile_type,
+                test.source
+            );
+        }
+    }
+
+    #[test]
+    fn prohibited() {
+        let profile = UStringPrepProfile::try_new(USPREP_RFC3920_NODEPREP).unwrap();
+        let error = prepare_error(profile.prepare("juliet@example", Options::default()));
+        assert_eq!(sys::UErrorCode::U_STRINGPREP_PROHIBITED_ERROR, error.status);
+        assert_eq!(6, error.offset);
+        assert_eq!("juliet", error.pre_context);
+    }
+
+    #[test]
+    fn bidi() {
+        let profile = UStringPrepProfile::try_new(USPREP_RFC4013_SASLPREP).unwrap();
+        let error = prepare_error(profile.prepare("\u{627}1", Options::default()));
+        assert_eq!(sys::UErrorCode::U_STRINGPREP_CHECK_BIDI_ERROR, error.status);
+    }
+
+    #[test]
+    fn unassigned() {
+        // U+0221 was unassigned in Unicode 3.2, on which StringPrep is based.
+        let profile = UStringPrepProfile::try_new(USPREP_RFC4013_SASLPREP).unwrap();
+        let error = prepare_error(profile.prepare("a\u{221}", Options::default()));
+        assert_eq!(sys::UErrorCode::U_STRINGPREP_UNASSIGNED_ERROR, error.status);
+        assert_eq!(1, error.offset);
+        assert_eq!(
+            "a\u{221}",
+            profile
+                .prepare("a\u{221}", Options::ALLOW_UNASSIGNED)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ustring() {
+        let profile = UStringPrepProfile::try_new(USPREP_RFC3920_NODEPREP).unwrap();
+        let text = ustring::UChar::try_from("Romeo").unwrap();
+        let prepared = profile.prepare_ustring(&text, Options::default()).unwrap();
+        assert_eq!("romeo", String::try_from(&prepared).unwrap());
+    }
+}